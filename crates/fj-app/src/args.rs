@@ -10,16 +10,32 @@ use fj_math::Scalar;
 #[command(version = fj::version::VERSION_FULL.to_string())]
 pub struct Args {
     /// The model to open
+    ///
+    /// Either the directory of a model crate, or a `.json` or `.ron` file
+    /// that contains a serialized shape.
     pub model: Option<PathBuf>,
 
+    /// The name of the model to open, if the crate contains several
+    #[arg(short, long, value_name = "NAME")]
+    pub model_name: Option<String>,
+
     /// Export model to this path
     #[arg(short, long, value_name = "PATH")]
     pub export: Option<PathBuf>,
 
     /// Parameters for the model, each in the form `key=value`
+    ///
+    /// Parameters are separated by commas. Vectors are written in brackets,
+    /// like `offset=[1, 2, 3]`.
+    ///
+    /// These override the parameters of the preset, if one is selected.
     #[arg(short, long, value_parser = parse_parameters)]
     pub parameters: Option<Parameters>,
 
+    /// The name of a parameter preset, from `presets.toml` next to the model
+    #[arg(long, value_name = "NAME")]
+    pub preset: Option<String>,
+
     /// Model deviation tolerance
     #[arg(short, long, value_parser = parse_tolerance)]
     pub tolerance: Option<Tolerance>,
@@ -38,7 +54,7 @@ impl Args {
 fn parse_parameters(input: &str) -> anyhow::Result<Parameters> {
     let mut parameters = Parameters::empty();
 
-    for parameter in input.split(',') {
+    for parameter in split_parameters(input)? {
         let mut parameter = parameter.splitn(2, '=');
 
         let key = parameter
@@ -58,6 +74,35 @@ fn parse_parameters(input: &str) -> anyhow::Result<Parameters> {
     Ok(parameters)
 }
 
+/// Split the input at commas, except those within brackets
+fn split_parameters(input: &str) -> anyhow::Result<Vec<&str>> {
+    let mut parameters = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("Unexpected `]` in parameters"))?;
+            }
+            ',' if depth == 0 => {
+                parameters.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        return Err(anyhow!("Missing `]` in parameters"));
+    }
+
+    parameters.push(&input[start..]);
+    Ok(parameters)
+}
+
 fn parse_tolerance(input: &str) -> anyhow::Result<Tolerance> {
     let tolerance = f64::from_str(input)?;
     let tolerance = Scalar::from_f64(tolerance);
@@ -65,3 +110,22 @@ fn parse_tolerance(input: &str) -> anyhow::Result<Tolerance> {
 
     Ok(tolerance)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_parameters;
+
+    #[test]
+    fn parse_vector_parameters() -> anyhow::Result<()> {
+        let parameters = parse_parameters("offset=[1, 2,3],radius=1.5")?;
+
+        assert_eq!(parameters.0.len(), 2);
+        assert_eq!(parameters.0["offset"], "[1, 2,3]");
+        assert_eq!(parameters.0["radius"], "1.5");
+
+        assert!(parse_parameters("offset=[1,2").is_err());
+        assert!(parse_parameters("offset=1,2]").is_err());
+
+        Ok(())
+    }
+}
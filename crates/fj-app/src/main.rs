@@ -15,12 +15,12 @@
 mod args;
 mod config;
 mod path;
+mod preset;
 
 use std::{env, error::Error};
 
 use anyhow::{anyhow, Context};
 use fj_export::export;
-use fj_host::Parameters;
 use fj_operations::shape_processor::ShapeProcessor;
 use fj_window::run::run;
 use path::ModelPath;
@@ -39,12 +39,11 @@ fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let config = Config::load()?;
     let model_path = ModelPath::from_args_and_config(&args, &config);
-    let parameters = args.parameters.unwrap_or_else(Parameters::empty);
     let shape_processor = ShapeProcessor {
         tolerance: args.tolerance,
     };
 
-    let model = model_path.map(|m| m.load_model(parameters)).transpose()?;
+    let model = model_path.map(|m| m.load_model(&args)).transpose()?;
 
     if let Some(export_path) = args.export {
         // export only mode. just load model, process, export and exit
@@ -52,7 +51,7 @@ fn main() -> anyhow::Result<()> {
         let evaluation = model.with_context(no_model_error)?.evaluate()?;
         let shape = shape_processor.process(&evaluation.shape)?;
 
-        export(&shape.mesh, &export_path)?;
+        export(&shape, &export_path)?;
 
         return Ok(());
     }
@@ -6,7 +6,7 @@ use std::{
 use anyhow::Context;
 use fj_host::{Model, Parameters};
 
-use crate::{args::Args, config::Config};
+use crate::{args::Args, config::Config, preset::load_preset};
 
 pub struct ModelPath {
     default_path: Option<PathBuf>,
@@ -33,7 +33,7 @@ impl ModelPath {
         })
     }
 
-    pub fn load_model(&self, parameters: Parameters) -> anyhow::Result<Model> {
+    pub fn load_model(&self, args: &Args) -> anyhow::Result<Model> {
         let default_path = self
             .default_path
             .as_ref()
@@ -56,13 +56,34 @@ impl ModelPath {
             .unwrap_or_else(PathBuf::new)
             .join(self.model_path.path());
 
+        let parameters = parameters(args, &path)?;
+
         let model = Model::new(&path, parameters).with_context(|| {
             load_error_context(default_path, &self.model_path, path)
         })?;
+        let model = match &args.model_name {
+            Some(model_name) => model.with_model_name(model_name),
+            None => model,
+        };
         Ok(model)
     }
 }
 
+/// Collect the parameters for the model
+///
+/// Explicit parameters override those from the preset.
+fn parameters(args: &Args, model_path: &Path) -> anyhow::Result<Parameters> {
+    let mut parameters = match &args.preset {
+        Some(preset) => load_preset(model_path, preset)?,
+        None => Parameters::empty(),
+    };
+    if let Some(overrides) = &args.parameters {
+        parameters.extend(overrides.0.clone());
+    }
+
+    Ok(parameters)
+}
+
 enum ModelPathSource {
     Args(PathBuf),
     Config(PathBuf),
@@ -137,3 +158,85 @@ fn load_error_context_inner(
 
     Ok(context)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use clap::Parser as _;
+
+    use crate::{args::Args, preset::PRESET_FILE};
+
+    use super::parameters;
+
+    /// Create a model directory that contains a preset file
+    fn model_dir(name: &str) -> anyhow::Result<PathBuf> {
+        let dir = std::env::temp_dir()
+            .join(format!("fj-app-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(
+            dir.join(PRESET_FILE),
+            "[presets.small]\nradius = 1.5\nheight = 2\n",
+        )?;
+        Ok(dir)
+    }
+
+    #[test]
+    fn explicit_parameters_override_preset() -> anyhow::Result<()> {
+        let dir = model_dir("override")?;
+
+        let args = Args::try_parse_from([
+            "fj-app",
+            dir.to_str().unwrap(),
+            "--preset",
+            "small",
+            "--parameters",
+            "radius=3,inner=1",
+            "--model-name",
+            "washer",
+        ])?;
+        let parameters = parameters(&args, &dir)?;
+
+        assert_eq!(parameters.len(), 3);
+        assert_eq!(parameters["radius"], "3");
+        assert_eq!(parameters["height"], "2");
+        assert_eq!(parameters["inner"], "1");
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn preset_without_explicit_parameters() -> anyhow::Result<()> {
+        let dir = model_dir("preset")?;
+
+        let args = Args::try_parse_from([
+            "fj-app",
+            dir.to_str().unwrap(),
+            "--preset",
+            "small",
+        ])?;
+        let parameters = parameters(&args, &dir)?;
+
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters["radius"], "1.5");
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn missing_preset_file() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join("fj-app-no-presets");
+
+        let args = Args::try_parse_from([
+            "fj-app",
+            dir.to_str().unwrap(),
+            "--preset",
+            "small",
+        ])?;
+        assert!(parameters(&args, &dir).is_err());
+
+        Ok(())
+    }
+}
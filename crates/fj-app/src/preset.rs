@@ -0,0 +1,169 @@
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use anyhow::{anyhow, Context as _};
+use figment::{
+    providers::{Format as _, Toml},
+    Figment,
+};
+use fj_host::Parameters;
+use serde::Deserialize;
+
+/// The name of the file that contains a model's parameter presets
+///
+/// It is expected next to the model, that is in the model's crate directory,
+/// or in the directory that contains the model's shape file.
+pub const PRESET_FILE: &str = "presets.toml";
+
+#[derive(Debug, Deserialize)]
+struct PresetFile {
+    presets: BTreeMap<String, BTreeMap<String, PresetValue>>,
+}
+
+/// The value of a parameter in a preset
+///
+/// Parameters are passed to models as strings, but TOML values are typed. This
+/// accepts any value that could make sense as a parameter.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PresetValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<PresetValue>),
+}
+
+impl fmt::Display for PresetValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+            Self::Array(values) => {
+                let values = values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "[{values}]")
+            }
+        }
+    }
+}
+
+/// Load the parameters of a preset from the file next to the model
+pub fn load_preset(
+    model_path: &Path,
+    name: &str,
+) -> anyhow::Result<Parameters> {
+    let dir = if model_path.is_file() {
+        model_path.parent().unwrap_or(model_path)
+    } else {
+        model_path
+    };
+    let path = dir.join(PRESET_FILE);
+
+    if !path.is_file() {
+        return Err(anyhow!(
+            "Can't load preset `{name}`: No preset file at `{}`",
+            path.display()
+        ));
+    }
+
+    parse_preset(Figment::from(Toml::file(&path)), &path, name)
+}
+
+/// Extract the parameters of a preset from the contents of a preset file
+///
+/// `path` is only used in error messages.
+fn parse_preset(
+    presets: Figment,
+    path: &Path,
+    name: &str,
+) -> anyhow::Result<Parameters> {
+    let PresetFile { mut presets } = presets.extract().with_context(|| {
+        format!("Error loading presets from `{}`", path.display())
+    })?;
+
+    let preset = presets.remove(name).ok_or_else(|| {
+        anyhow!(
+            "No preset named `{name}` in `{}`. Available presets: {}",
+            path.display(),
+            presets.keys().cloned().collect::<Vec<_>>().join(", "),
+        )
+    })?;
+
+    let mut parameters = Parameters::empty();
+    for (key, value) in preset {
+        parameters.insert(key, value);
+    }
+
+    Ok(parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use figment::{
+        providers::{Format as _, Toml},
+        Figment,
+    };
+
+    use super::parse_preset;
+
+    const PRESETS: &str = r#"
+        [presets.small]
+        radius = 1.5
+        height = 2
+        centered = true
+        name = "small washer"
+        offset = [1.0, -2, 0.5]
+
+        [presets.large]
+        radius = 10.0
+    "#;
+
+    fn preset(name: &str) -> anyhow::Result<fj_host::Parameters> {
+        parse_preset(
+            Figment::from(Toml::string(PRESETS)),
+            Path::new(super::PRESET_FILE),
+            name,
+        )
+    }
+
+    #[test]
+    fn parse_values() -> anyhow::Result<()> {
+        let small = preset("small")?;
+
+        assert_eq!(small.len(), 5);
+        assert_eq!(small["radius"], "1.5");
+        assert_eq!(small["height"], "2");
+        assert_eq!(small["centered"], "true");
+        assert_eq!(small["name"], "small washer");
+        assert_eq!(small["offset"], "[1, -2, 0.5]");
+
+        // Integral floats lose their fractional part, which models that expect
+        // a float still accept.
+        assert_eq!(preset("large")?["radius"], "10");
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_preset() {
+        let err = preset("medium").unwrap_err().to_string();
+        assert!(err.contains("No preset named `medium`"));
+        assert!(err.contains("large, small"));
+    }
+
+    #[test]
+    fn invalid_preset_file() {
+        let presets =
+            Figment::from(Toml::string("[presets.small]\nradius = {}"));
+        assert!(
+            parse_preset(presets, Path::new("presets.toml"), "small").is_err()
+        );
+    }
+}
@@ -14,26 +14,38 @@
 
 #![warn(missing_docs)]
 
-use std::{fs::File, path::Path};
+use std::{
+    fs::File,
+    io::{BufWriter, Seek, Write},
+    path::Path,
+};
 
 use thiserror::Error;
+use zip::{write::FileOptions, ZipWriter};
 
-use fj_interop::mesh::Mesh;
+use fj_interop::{mesh::Mesh, processed_shape::ProcessedShape};
 use fj_math::{Point, Triangle};
 
-/// Export the provided mesh to the file at the given path.
+/// Export the provided shape to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
+/// Currently 3MF, OBJ & STL file types are supported. The case insensitive file extension of
 /// the provided path is used to switch between supported types.
-pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+///
+/// If the shape is an assembly, 3MF files contain one object per part, and OBJ files
+/// one group per part. STL files can't tell objects apart, so they contain the mesh of
+/// the whole shape.
+pub fn export(shape: &ProcessedShape, path: &Path) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
+            export_3mf(shape, path)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
+            export_obj(shape, path)
         }
         Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
+            export_stl(&shape.mesh, path)
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -42,38 +54,174 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
-fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    let vertices = mesh
-        .vertices()
-        .map(|point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        })
-        .collect();
+fn export_3mf(shape: &ProcessedShape, path: &Path) -> Result<(), Error> {
+    write_3mf(shape, File::create(path)?)
+}
 
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
+fn write_3mf(
+    shape: &ProcessedShape,
+    writer: impl Write + Seek,
+) -> Result<(), Error> {
+    let mut archive = ZipWriter::new(writer);
+    let options = FileOptions::default();
 
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
+    archive.start_file("[Content_Types].xml", options)?;
+    archive.write_all(THREEMF_CONTENT_TYPES.as_bytes())?;
+
+    archive.start_file("_rels/.rels", options)?;
+    archive.write_all(THREEMF_RELATIONSHIPS.as_bytes())?;
+
+    archive.start_file("3D/3dmodel.model", options)?;
+    let mut model = BufWriter::new(&mut archive);
+
+    writeln!(model, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        model,
+        r#"<model unit="millimeter" xml:lang="en-US" xmlns="{THREEMF_CORE}">"#
+    )?;
+
+    let objects = if shape.parts.is_empty() {
+        vec![(None, &shape.mesh)]
+    } else {
+        shape
+            .parts
+            .iter()
+            .map(|part| (Some(part.name.as_str()), &part.mesh))
+            .collect()
     };
 
-    threemf::write(path, mesh)?;
+    writeln!(model, "<resources>")?;
+    for (id, (name, mesh)) in (1..).zip(&objects) {
+        write!(model, r#"<object id="{id}" type="model""#)?;
+        if let Some(name) = name {
+            write!(model, r#" name="{}""#, escape_xml(name))?;
+        }
+        writeln!(model, "><mesh>")?;
+
+        writeln!(model, "<vertices>")?;
+        for point in mesh.vertices() {
+            writeln!(
+                model,
+                r#"<vertex x="{}" y="{}" z="{}"/>"#,
+                point.x, point.y, point.z
+            )?;
+        }
+        writeln!(model, "</vertices>")?;
+
+        writeln!(model, "<triangles>")?;
+        let indices: Vec<_> = mesh.indices().collect();
+        for triangle in indices.chunks(3) {
+            writeln!(
+                model,
+                r#"<triangle v1="{}" v2="{}" v3="{}"/>"#,
+                triangle[0], triangle[1], triangle[2]
+            )?;
+        }
+        writeln!(model, "</triangles>")?;
+
+        writeln!(model, "</mesh></object>")?;
+    }
+    writeln!(model, "</resources>")?;
+
+    writeln!(model, "<build>")?;
+    for id in (1..).take(objects.len()) {
+        writeln!(model, r#"<item objectid="{id}"/>"#)?;
+    }
+    writeln!(model, "</build>")?;
+
+    writeln!(model, "</model>")?;
+
+    model.flush()?;
+    drop(model);
+    archive.finish()?;
+
+    Ok(())
+}
+
+/// The namespace of the 3MF core specification
+const THREEMF_CORE: &str =
+    "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+
+const THREEMF_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const THREEMF_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+/// Escape text, so it can be used as the value of an XML attribute
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn export_obj(shape: &ProcessedShape, path: &Path) -> Result<(), Error> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write_obj(shape, &mut file)?;
+    file.flush()?;
+
+    Ok(())
+}
+
+fn write_obj(
+    shape: &ProcessedShape,
+    file: &mut impl Write,
+) -> Result<(), Error> {
+    if shape.parts.is_empty() {
+        write_obj_mesh(file, &shape.mesh, 0)?;
+    } else {
+        // Indices in OBJ files refer to all vertices before them, not just the
+        // ones of the current group.
+        let mut num_vertices = 0;
+
+        for part in &shape.parts {
+            writeln!(file, "g {}", part.name)?;
+            num_vertices += write_obj_mesh(file, &part.mesh, num_vertices)?;
+        }
+    }
 
     Ok(())
 }
 
+/// Write the vertices and faces of a mesh, returning the number of vertices
+fn write_obj_mesh(
+    file: &mut impl Write,
+    mesh: &Mesh<Point<3>>,
+    index_offset: usize,
+) -> Result<usize, Error> {
+    let mut num_vertices = 0;
+    for point in mesh.vertices() {
+        writeln!(file, "v {} {} {}", point.x, point.y, point.z)?;
+        num_vertices += 1;
+    }
+
+    let indices: Vec<_> = mesh.indices().collect();
+    for triangle in indices.chunks(3) {
+        // OBJ indices start at 1.
+        let [a, b, c] = [triangle[0], triangle[1], triangle[2]]
+            .map(|index| index as usize + index_offset + 1);
+        writeln!(file, "f {a} {b} {c}")?;
+    }
+
+    Ok(num_vertices)
+}
+
 fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     let points = mesh
         .triangles()
@@ -138,7 +286,87 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// Zip error whilst exporting to 3MF file
+    #[error("zip error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use fj_interop::{
+        debug::DebugInfo,
+        mesh::{Color, Mesh},
+        processed_shape::{ProcessedPart, ProcessedShape},
+    };
+    use fj_math::{Aabb, Point};
+
+    #[test]
+    fn obj_groups() {
+        let shape = assembly();
+
+        let mut obj = Vec::new();
+        super::write_obj(&shape, &mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+
+        let groups_and_faces = obj
+            .lines()
+            .filter(|line| line.starts_with("g ") || line.starts_with("f "))
+            .collect::<Vec<_>>();
+        assert_eq!(groups_and_faces, ["g a", "f 1 2 3", "g b", "f 4 5 6"]);
+    }
+
+    #[test]
+    fn threemf_objects() {
+        let shape = assembly();
+
+        let mut threemf = Cursor::new(Vec::new());
+        super::write_3mf(&shape, &mut threemf).unwrap();
+
+        let mut archive = zip::ZipArchive::new(threemf).unwrap();
+        let mut model = String::new();
+        archive
+            .by_name("3D/3dmodel.model")
+            .unwrap()
+            .read_to_string(&mut model)
+            .unwrap();
+
+        assert!(model.contains(r#"<object id="1" type="model" name="a">"#));
+        assert!(model.contains(r#"<object id="2" type="model" name="b">"#));
+        assert_eq!(model.matches("<triangle ").count(), 2);
+        assert_eq!(model.matches("<item ").count(), 2);
+    }
+
+    fn assembly() -> ProcessedShape {
+        let part = |name: &str, z: f64| {
+            let mut mesh = Mesh::new();
+            mesh.push_triangle(
+                [[0., 0., z], [1., 0., z], [0., 1., z]],
+                Color::default(),
+            );
+
+            ProcessedPart {
+                name: name.into(),
+                mesh,
+            }
+        };
+
+        let parts = vec![part("a", 0.), part("b", 1.)];
+
+        let mut mesh = Mesh::new();
+        for triangle in parts.iter().flat_map(|part| part.mesh.triangles()) {
+            mesh.push_triangle(triangle.inner, triangle.color);
+        }
+
+        ProcessedShape {
+            aabb: Aabb {
+                min: Point::from([0., 0., 0.]),
+                max: Point::from([1., 1., 1.]),
+            },
+            mesh,
+            parts,
+            debug_info: DebugInfo::new(),
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use std::{
-    io,
+    fs, io,
     path::{Path, PathBuf},
     process::Command,
     str,
@@ -13,23 +13,41 @@ use crate::{platform::HostPlatform, Parameters};
 
 /// Represents a Fornjot model
 pub struct Model {
-    src_path: PathBuf,
-    lib_path: PathBuf,
-    manifest_path: PathBuf,
+    source: Source,
     parameters: Parameters,
+    model_name: Option<String>,
 }
 
 impl Model {
-    /// Initialize the model using the path to its crate
+    /// Initialize the model using the path to its crate or shape file
     ///
-    /// The path expected here is the root directory of the model's Cargo
-    /// package, that is the folder containing `Cargo.toml`.
+    /// The path expected here is either the root directory of the model's
+    /// Cargo package, that is the folder containing `Cargo.toml`, or a file
+    /// that contains a serialized [`fj::Shape`]. Shape files are recognized by
+    /// their extension, which must be `json` or `ron`.
     pub fn new(
         path: impl AsRef<Path>,
         parameters: Parameters,
     ) -> Result<Self, Error> {
         let path = path.as_ref();
 
+        if path.is_file() {
+            let format = ShapeFormat::from_path(path).ok_or_else(|| {
+                Error::UnknownShapeFormat {
+                    path: path.to_path_buf(),
+                }
+            })?;
+
+            return Ok(Self {
+                source: Source::ShapeFile {
+                    path: path.canonicalize()?,
+                    format,
+                },
+                parameters,
+                model_name: None,
+            });
+        }
+
         let crate_dir = path.canonicalize()?;
 
         let metadata = cargo_metadata::MetadataCommand::new()
@@ -48,21 +66,89 @@ impl Model {
         };
 
         Ok(Self {
-            src_path,
-            lib_path,
-            manifest_path: pkg.manifest_path.as_std_path().to_path_buf(),
+            source: Source::Crate {
+                src_path,
+                lib_path,
+                manifest_path: pkg.manifest_path.as_std_path().to_path_buf(),
+            },
             parameters,
+            model_name: None,
         })
     }
 
+    /// Select one of the models that the crate registers, by name
+    ///
+    /// The name is compared to the name in each model's metadata. If no name
+    /// is selected, the first model that the crate registers is used.
+    pub fn with_model_name(self, model_name: impl Into<String>) -> Self {
+        Self {
+            model_name: Some(model_name.into()),
+            ..self
+        }
+    }
+
     /// Access the path that needs to be watched for changes
     pub fn watch_path(&self) -> PathBuf {
-        self.src_path.clone()
+        match &self.source {
+            Source::Crate { src_path, .. } => src_path.clone(),
+            Source::ShapeFile { path, .. } => path.clone(),
+        }
     }
 
     /// Evaluate the model
     pub fn evaluate(&self) -> Result<Evaluation, Error> {
-        let manifest_path = self.manifest_path.display().to_string();
+        match &self.source {
+            Source::Crate {
+                lib_path,
+                manifest_path,
+                ..
+            } => self.evaluate_crate(lib_path, manifest_path),
+            Source::ShapeFile { path, format } => {
+                self.evaluate_shape_file(path, *format)
+            }
+        }
+    }
+
+    fn evaluate_shape_file(
+        &self,
+        path: &Path,
+        format: ShapeFormat,
+    ) -> Result<Evaluation, Error> {
+        let source = fs::read_to_string(path)?;
+
+        let shape = match format {
+            ShapeFormat::Json => serde_json::from_str(&source)?,
+            ShapeFormat::Ron => ron::from_str(&source)?,
+        };
+
+        let mut warnings = Vec::new();
+
+        // A serialized shape is already evaluated, so there's nothing that
+        // parameters could be passed to.
+        if !self.parameters.is_empty() {
+            warnings.push(String::from(
+                "Parameters are ignored when loading a shape file",
+            ));
+        }
+        if self.model_name.is_some() {
+            warnings.push(String::from(
+                "Model names are ignored when loading a shape file",
+            ));
+        }
+
+        Ok(Evaluation {
+            shape,
+            compile_time: None,
+            warning: join_warnings(warnings),
+        })
+    }
+
+    fn evaluate_crate(
+        &self,
+        lib_path: &Path,
+        manifest_path: &Path,
+    ) -> Result<Evaluation, Error> {
+        let manifest_path = manifest_path.display().to_string();
 
         let cargo_output = Command::new("cargo")
             .arg("rustc")
@@ -86,7 +172,7 @@ impl Model {
             .1
             .trim();
 
-        let mut warnings = None;
+        let mut warnings = Vec::new();
 
         // So, strictly speaking this is all unsound:
         // - `Library::new` requires us to abide by the arbitrary requirements
@@ -105,7 +191,7 @@ impl Model {
         // to switch to a better technique:
         // https://github.com/hannobraun/Fornjot/issues/71
         let shape = unsafe {
-            let lib = libloading::Library::new(&self.lib_path)
+            let lib = libloading::Library::new(lib_path)
                 .map_err(Error::LoadingLibrary)?;
 
             let version_pkg_host = fj::version::VERSION_PKG.to_string();
@@ -142,34 +228,122 @@ impl Model {
                         .into_owned();
                 let model = version_full_model;
 
-                warnings =
-                    Some(format!("{}", Error::VersionMismatch { host, model }));
+                warnings.push(format!(
+                    "{}",
+                    Error::VersionMismatch { host, model }
+                ));
             }
 
-            let init: libloading::Symbol<abi::InitFunction> = lib
-                .get(abi::INIT_FUNCTION_NAME.as_bytes())
-                .map_err(Error::LoadingInit)?;
-
             let mut host = Host::new(&self.parameters);
 
-            match init(&mut abi::Host::from(&mut host)) {
-                abi::ffi_safe::Result::Ok(_metadata) => {}
-                abi::ffi_safe::Result::Err(e) => {
-                    return Err(Error::InitializeModel(e.into()));
+            // Models that are defined using `#[fj::model]` are registered by
+            // this function, which is part of the `fj` library.
+            let register: libloading::Symbol<abi::RegisterModelsFunction> = lib
+                .get(abi::REGISTER_MODELS_FUNCTION_NAME.as_bytes())
+                .map_err(Error::LoadingInit)?;
+            register(&mut abi::Host::from(&mut host));
+
+            // Models can also be registered manually, using
+            // `fj::register_model!`.
+            if let Ok(init) =
+                lib.get::<abi::InitFunction>(abi::INIT_FUNCTION_NAME.as_bytes())
+            {
+                match init(&mut abi::Host::from(&mut host)) {
+                    abi::ffi_safe::Result::Ok(_metadata) => {}
+                    abi::ffi_safe::Result::Err(e) => {
+                        return Err(Error::InitializeModel(e.into()));
+                    }
                 }
             }
 
-            let model = host.take_model().ok_or(Error::NoModelRegistered)?;
+            let models = host.take_models();
+            let metadata = models
+                .iter()
+                .map(|model| model.metadata().map_err(Error::Metadata))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let index = self.select_model(&metadata, &mut warnings)?;
+            self.check_parameters(&metadata[index], &mut warnings);
 
-            model.shape(&host).map_err(Error::Shape)?
+            models[index].shape(&host).map_err(Error::Shape)?
         };
 
         Ok(Evaluation {
             shape,
-            compile_time: seconds_taken.into(),
-            warning: warnings,
+            compile_time: Some(seconds_taken.into()),
+            warning: join_warnings(warnings),
         })
     }
+
+    /// Warn about parameters that the model doesn't accept
+    ///
+    /// These are most likely left over in a preset, after the model changed.
+    /// They don't prevent the model from being evaluated.
+    fn check_parameters(
+        &self,
+        metadata: &fj::models::ModelMetadata,
+        warnings: &mut Vec<String>,
+    ) {
+        let available: Vec<_> = metadata
+            .arguments
+            .iter()
+            .map(|argument| argument.name.clone())
+            .collect();
+
+        let mut unknown: Vec<_> = self
+            .parameters
+            .keys()
+            .filter(|name| !available.contains(name))
+            .cloned()
+            .collect();
+
+        if unknown.is_empty() {
+            return;
+        }
+
+        unknown.sort();
+        warnings.push(format!(
+            "Ignoring unknown parameters for model `{}`: {}. Available \
+            parameters: {}",
+            metadata.name,
+            unknown.join(", "),
+            available.join(", "),
+        ));
+    }
+
+    /// Find the index of the model to evaluate, among the registered ones
+    fn select_model(
+        &self,
+        models: &[fj::models::ModelMetadata],
+        warnings: &mut Vec<String>,
+    ) -> Result<usize, Error> {
+        let names = || models.iter().map(|model| model.name.clone()).collect();
+
+        match &self.model_name {
+            Some(name) => models
+                .iter()
+                .position(|model| &model.name == name)
+                .ok_or_else(|| Error::UnknownModel {
+                    name: name.clone(),
+                    available: names(),
+                }),
+            None => {
+                if models.is_empty() {
+                    return Err(Error::NoModelRegistered);
+                }
+
+                if models.len() > 1 {
+                    warnings.push(format!(
+                        "Multiple models registered ({}). Showing `{}`.",
+                        names().join(", "),
+                        models[0].name,
+                    ));
+                }
+
+                Ok(0)
+            }
+        }
+    }
 }
 
 /// The result of evaluating a model
@@ -181,36 +355,72 @@ pub struct Evaluation {
     pub shape: fj::Shape,
 
     /// The time it took to compile the shape, from the Cargo output
-    pub compile_time: String,
+    ///
+    /// `None`, if the model was loaded from a shape file.
+    pub compile_time: Option<String>,
 
     /// Warnings
     pub warning: Option<String>,
 }
 
+/// Where a model is loaded from
+enum Source {
+    /// A crate that is compiled into a dynamic library
+    Crate {
+        src_path: PathBuf,
+        lib_path: PathBuf,
+        manifest_path: PathBuf,
+    },
+
+    /// A file that contains a serialized shape
+    ShapeFile { path: PathBuf, format: ShapeFormat },
+}
+
+/// The format of a shape file
+#[derive(Clone, Copy)]
+enum ShapeFormat {
+    Json,
+    Ron,
+}
+
+impl ShapeFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "ron" => Some(Self::Ron),
+            _ => None,
+        }
+    }
+}
+
 pub struct Host<'a> {
     args: &'a Parameters,
-    model: Option<Box<dyn fj::models::Model>>,
+    models: Vec<Box<dyn fj::models::Model>>,
 }
 
 impl<'a> Host<'a> {
     pub fn new(parameters: &'a Parameters) -> Self {
         Self {
             args: parameters,
-            model: None,
+            models: Vec::new(),
         }
     }
 
-    pub fn take_model(&mut self) -> Option<Box<dyn fj::models::Model>> {
-        self.model.take()
+    pub fn take_models(&mut self) -> Vec<Box<dyn fj::models::Model>> {
+        std::mem::take(&mut self.models)
     }
 }
 
 impl<'a> fj::models::Host for Host<'a> {
     fn register_boxed_model(&mut self, model: Box<dyn fj::models::Model>) {
-        self.model = Some(model);
+        self.models.push(model);
     }
 }
 
+fn join_warnings(warnings: Vec<String>) -> Option<String> {
+    (!warnings.is_empty()).then(|| warnings.join("\n"))
+}
+
 impl<'a> fj::models::Context for Host<'a> {
     fn get_argument(&self, name: &str) -> Option<&str> {
         self.args.get(name).map(String::as_str)
@@ -280,10 +490,10 @@ pub enum Error {
     )]
     LoadingVersion(#[source] libloading::Error),
 
-    /// Error loading the model's `init` function
+    /// Error loading the function that registers the model
     #[error(
-        "Failed to load the model's `init` function\n\
-        - Did you define a model function using `#[fj::model]`?"
+        "Failed to load the function that registers the model\n\
+        - Is your model using the `fj` library? All models must!"
     )]
     LoadingInit(#[source] libloading::Error),
 
@@ -304,6 +514,25 @@ pub enum Error {
         output: String,
     },
 
+    /// Shape file is neither JSON nor RON
+    #[error(
+        "Can't load \"{}\": Expected a crate directory, or a shape file \
+        ending in `.json` or `.ron`",
+        path.display()
+    )]
+    UnknownShapeFormat {
+        /// The path of the file
+        path: PathBuf,
+    },
+
+    /// Error parsing a JSON shape file
+    #[error("Failed to parse JSON shape file")]
+    Json(#[from] serde_json::Error),
+
+    /// Error parsing a RON shape file
+    #[error("Failed to parse RON shape file")]
+    Ron(#[from] ron::error::SpannedError),
+
     /// I/O error while loading the model
     #[error("I/O error while loading model")]
     Io(#[from] io::Error),
@@ -312,11 +541,30 @@ pub enum Error {
     #[error("Unable to initialize the model")]
     InitializeModel(#[source] fj::models::Error),
 
-    /// The user forgot to register a model when calling
-    /// [`fj::register_model!()`].
-    #[error("No model was registered")]
+    /// The user didn't define a model using [`fj::model`], and forgot to
+    /// register one when calling [`fj::register_model!()`].
+    #[error(
+        "No model was registered\n\
+        - Did you define a model function using `#[fj::model]`?"
+    )]
     NoModelRegistered,
 
+    /// No registered model has the name that was selected.
+    #[error(
+        "No model named \"{name}\" was registered. Available models: {}",
+        available.join(", ")
+    )]
+    UnknownModel {
+        /// The name of the model that was selected
+        name: String,
+        /// The names of the models that were registered
+        available: Vec<String>,
+    },
+
+    /// An error was returned from [`fj::models::Model::metadata()`].
+    #[error("Unable to determine the model's metadata")]
+    Metadata(#[source] fj::models::Error),
+
     /// An error was returned from [`fj::models::Model::shape()`].
     #[error("Unable to determine the model's geometry")]
     Shape(#[source] fj::models::Error),
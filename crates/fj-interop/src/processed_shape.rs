@@ -13,6 +13,22 @@ pub struct ProcessedShape {
     /// The triangle mesh that approximates the original shape
     pub mesh: Mesh<Point<3>>,
 
+    /// The parts of the shape, if it is an assembly
+    ///
+    /// Empty, if the shape is not an assembly. Otherwise, the meshes of the
+    /// parts together make up [`ProcessedShape::mesh`].
+    pub parts: Vec<ProcessedPart>,
+
     /// The debug info generated while processing the shape
     pub debug_info: DebugInfo,
 }
+
+/// A named part of a [`ProcessedShape`]
+#[derive(Clone, Debug)]
+pub struct ProcessedPart {
+    /// The name of the part
+    pub name: String,
+
+    /// The triangle mesh that approximates the part
+    pub mesh: Mesh<Point<3>>,
+}
@@ -7,8 +7,13 @@
 
 use std::collections::BTreeMap;
 
+use fj_math::{Line, Point, Scalar};
+
 use crate::{
-    geometry::curve::{Curve, GlobalPath},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
     objects::{GlobalEdge, HalfEdge, Surface},
     storage::{Handle, ObjectId},
 };
@@ -102,13 +107,41 @@ fn approx_edge(
     // This will probably all be unified eventually, as `SurfacePath` and
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
-    let points = match (curve, surface.geometry().u) {
-        (Curve::Circle(_), GlobalPath::Circle(_)) => {
+    let tolerance = tolerance.into();
+
+    let points = match (curve, surface.geometry()) {
+        (
+            Curve::Circle(_),
+            SurfaceGeometry::Swept {
+                u:
+                    GlobalPath::Circle(_)
+                    | GlobalPath::Bezier(_)
+                    | GlobalPath::Ellipse(_),
+                ..
+            },
+        ) => {
             todo!(
                 "Approximating a circle on a curved surface not supported yet."
             )
         }
-        (Curve::Circle(_), GlobalPath::Line(_)) => {
+        (Curve::Circle(_), SurfaceGeometry::Ruled { .. }) => {
+            todo!(
+                "Approximating a circle on a ruled surface not supported yet."
+            )
+        }
+        (Curve::Circle(_), SurfaceGeometry::Revolved { .. }) => {
+            todo!(
+                "Approximating a circle on a revolved surface not supported \
+                yet."
+            )
+        }
+        (
+            Curve::Circle(_) | Curve::Bezier(_) | Curve::Ellipse(_),
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Line(_),
+                ..
+            },
+        ) => {
             (curve, range)
                 .approx_with_cache(tolerance, &mut ())
                 .into_iter()
@@ -135,22 +168,53 @@ fn approx_edge(
                 })
                 .collect()
         }
-        (Curve::Line(line), _) => {
+        (Curve::Bezier(_), _) => {
+            todo!(
+                "Approximating a Bézier curve on a curved surface is not \
+                supported yet."
+            )
+        }
+        (Curve::Ellipse(_), _) => {
+            todo!(
+                "Approximating an ellipse on a curved surface is not supported \
+                yet."
+            )
+        }
+        (Curve::Line(line), geometry) => {
             let range_u =
                 RangeOnPath::from(range.boundary.map(|point_curve| {
                     [curve.point_from_path_coords(point_curve).u]
                 }));
 
-            let approx_u = (surface.geometry().u, range_u)
-                .approx_with_cache(tolerance, &mut ());
+            let t_from_u = |u: Point<1>| {
+                Point::from([(u.t - line.origin().u) / line.direction().u])
+            };
+
+            let approx_t: Vec<_> = match geometry {
+                SurfaceGeometry::Swept { u, .. } => (u, range_u)
+                    .approx_with_cache(tolerance, &mut ())
+                    .into_iter()
+                    .map(|(u, _)| t_from_u(u))
+                    .collect(),
+                SurfaceGeometry::Ruled { a, b } => {
+                    approx_u_on_ruled_surface(line, [a, b], range_u, tolerance)
+                        .into_iter()
+                        .map(t_from_u)
+                        .collect()
+                }
+                SurfaceGeometry::Revolved { path, axis } => {
+                    approx_line_on_revolved_surface(
+                        line, path, axis, range, tolerance,
+                    )
+                }
+            };
 
             let mut points = Vec::new();
-            for (u, _) in approx_u {
-                let t = (u.t - line.origin().u) / line.direction().u;
-                let point_surface = curve.point_from_path_coords([t]);
+            for t in approx_t {
+                let point_surface = curve.point_from_path_coords(t);
                 let point_global =
                     surface.geometry().point_from_surface_coords(point_surface);
-                points.push((u, point_global));
+                points.push((t, point_global));
             }
 
             points
@@ -166,6 +230,125 @@ fn approx_edge(
     GlobalEdgeApprox { points }
 }
 
+/// Approximate the u-coordinates of a line on a ruled surface
+///
+/// A line that runs along one of the paths of the surface is approximated like
+/// that path, which matches the approximation of the same edge in neighboring
+/// faces. Any other line is approximated like both paths together.
+fn approx_u_on_ruled_surface(
+    line: &Line<2>,
+    paths: [GlobalPath; 2],
+    range_u: RangeOnPath,
+    tolerance: Tolerance,
+) -> Vec<Point<1>> {
+    let [a, b] = paths;
+    let runs_along =
+        |v: Scalar| line.direction().v == Scalar::ZERO && line.origin().v == v;
+
+    let paths = if runs_along(Scalar::ZERO) {
+        vec![a]
+    } else if runs_along(Scalar::ONE) {
+        vec![b]
+    } else {
+        vec![a, b]
+    };
+
+    let mut approx_u = paths
+        .into_iter()
+        .flat_map(|path| {
+            (path, range_u)
+                .approx_with_cache(tolerance, &mut ())
+                .into_iter()
+                .map(|(u, _)| u)
+        })
+        .collect::<Vec<_>>();
+
+    approx_u.sort();
+    approx_u.dedup();
+
+    let [start, end] = range_u.boundary;
+    if start > end {
+        approx_u.reverse();
+    }
+
+    approx_u
+}
+
+/// Approximate a line on a revolved surface
+///
+/// Returns the curve coordinates of the approximation. A line that runs along
+/// the v-axis is approximated like the revolved path. A line that runs along
+/// the u-axis is approximated like the circle it forms around the axis.
+fn approx_line_on_revolved_surface(
+    line: &Line<2>,
+    path: GlobalPath,
+    axis: Line<3>,
+    range: RangeOnPath,
+    tolerance: Tolerance,
+) -> Vec<Point<1>> {
+    let tolerance = tolerance_on_revolved_surface(tolerance);
+    let direction = line.direction();
+
+    if direction.u == Scalar::ZERO {
+        let range_v = RangeOnPath::from(
+            range
+                .boundary
+                .map(|point| [line.point_from_line_coords(point).v]),
+        );
+
+        return (path, range_v)
+            .approx_with_cache(tolerance, &mut ())
+            .into_iter()
+            .map(|(v, _)| Point::from([(v.t - line.origin().v) / direction.v]))
+            .collect();
+    }
+    if direction.v != Scalar::ZERO {
+        todo!(
+            "Approximating a line that isn't parallel to the u- or v-axis of a \
+            revolved surface is not supported yet."
+        )
+    }
+
+    let radius =
+        distance_to_axis(path.point_from_path_coords([line.origin().v]), axis);
+    if radius <= tolerance.inner() {
+        // The line is collapsed into a single point, or close enough to one
+        // that its approximation would be within the tolerance anyway. This is
+        // the case at the poles of a sphere, for example.
+        return Vec::new();
+    }
+
+    let range_u = RangeOnPath::from(
+        range
+            .boundary
+            .map(|point| [line.point_from_line_coords(point).u]),
+    );
+
+    (GlobalPath::circle_from_radius(radius), range_u)
+        .approx_with_cache(tolerance, &mut ())
+        .into_iter()
+        .map(|(u, _)| Point::from([(u.t - line.origin().u) / direction.u]))
+        .collect()
+}
+
+/// The tolerance used for approximating geometry on a revolved surface
+///
+/// Revolved surfaces can be curved along both of their axes. Triangles that
+/// approximate them deviate along both axes, so each axis only gets half of
+/// the tolerance.
+pub(super) fn tolerance_on_revolved_surface(tolerance: Tolerance) -> Tolerance {
+    Tolerance::from_scalar(tolerance.inner() / 2.)
+        .expect("Half of a valid tolerance is still valid")
+}
+
+/// Compute the distance of a point to the axis of a revolved surface
+pub(super) fn distance_to_axis(point: Point<3>, axis: Line<3>) -> Scalar {
+    let direction = axis.direction().normalize();
+    let offset = point - axis.origin();
+
+    (offset - direction * direction.dot(&offset)).magnitude()
+}
+
 /// A cache for results of an approximation
 #[derive(Default)]
 pub struct EdgeCache {
@@ -5,14 +5,19 @@
 use std::{collections::BTreeSet, ops::Deref};
 
 use fj_interop::mesh::Color;
+use fj_math::{Aabb, Point, Scalar};
 
 use crate::{
+    geometry::{curve::GlobalPath, surface::SurfaceGeometry},
     objects::{Face, FaceSet, Handedness},
     validate::ValidationConfig,
 };
 
 use super::{
-    cycle::CycleApprox, edge::EdgeCache, Approx, ApproxPoint, Tolerance,
+    cycle::CycleApprox,
+    edge::{distance_to_axis, tolerance_on_revolved_surface, EdgeCache},
+    path::RangeOnPath,
+    Approx, ApproxPoint, Tolerance,
 };
 
 impl Approx for &FaceSet {
@@ -74,21 +79,20 @@ impl Approx for &Face {
     ) -> Self::Approximation {
         let tolerance = tolerance.into();
 
-        // Curved faces whose curvature is not fully defined by their edges
-        // are not supported yet. For that reason, we can fully ignore `face`'s
-        // `surface` field and just pass the edges to `Self::for_edges`.
+        // The curvature of many faces is fully defined by their edges.
         //
-        // An example of a curved face that is supported, is the cylinder. Its
-        // curvature is fully defined be the edges (circles) that border it. The
-        // circle approximations are sufficient to triangulate the surface.
+        // An example of that is the cylinder. Its curvature is fully defined be
+        // the edges (circles) that border it. The circle approximations are
+        // sufficient to triangulate the surface.
         //
-        // An example of a curved face that is currently not supported, and thus
-        // doesn't need to be handled here, is a sphere. A spherical face would
-        // would need to provide its own approximation, as the edges that bound
-        // it have nothing to do with its curvature.
+        // An example of a curved face where this is not the case is a sphere.
+        // The edges that bound a spherical face have nothing to do with its
+        // curvature, so its surface needs to provide its own approximation.
 
         let exterior = (self.exterior().deref(), self.surface().deref())
             .approx_with_cache(tolerance, cache);
+        let surface =
+            approx_surface(&self.surface().geometry(), &exterior, tolerance);
 
         let mut interiors = BTreeSet::new();
         for cycle in self.interiors() {
@@ -100,6 +104,7 @@ impl Approx for &Face {
         FaceApprox {
             exterior,
             interiors,
+            surface,
             color: self.color(),
             coord_handedness: self.coord_handedness(),
         }
@@ -115,6 +120,12 @@ pub struct FaceApprox {
     /// Approximations of the interior cycles
     pub interiors: BTreeSet<CycleApprox>,
 
+    /// Points within the face that approximate the curvature of its surface
+    ///
+    /// Empty, if the curvature of the surface is fully defined by the edges
+    /// that bound the face.
+    pub surface: Vec<ApproxPoint<2>>,
+
     /// The color of the approximated face
     pub color: Color,
 
@@ -133,6 +144,73 @@ impl FaceApprox {
             points.extend(cycle_approx.points());
         }
 
+        points.extend(self.surface.iter().cloned());
+
         points
     }
 }
+
+/// Approximate the curvature of a surface within the bounds of a face
+///
+/// Only revolved surfaces whose revolved path is curved, like spheres and tori,
+/// are curved independently of the edges that bound their faces. Their surface
+/// is approximated using a grid of points, which is made up of the
+/// approximations of the revolved path and of the largest circle it forms
+/// around the axis.
+fn approx_surface(
+    surface: &SurfaceGeometry,
+    exterior: &CycleApprox,
+    tolerance: Tolerance,
+) -> Vec<ApproxPoint<2>> {
+    let SurfaceGeometry::Revolved { path, axis } = *surface else {
+        return Vec::new();
+    };
+    if let GlobalPath::Line(_) = path {
+        // Cones are straight along the v-axis. The approximations of the edges
+        // that bound the face are enough.
+        return Vec::new();
+    }
+
+    let tolerance = tolerance_on_revolved_surface(tolerance);
+
+    let bounds = Aabb::<2>::from_points(
+        exterior.points().into_iter().map(|point| point.local_form),
+    );
+
+    let approx_v = (path, RangeOnPath::from([[bounds.min.v], [bounds.max.v]]))
+        .approx_with_cache(tolerance, &mut ());
+
+    let radius = match path {
+        GlobalPath::Circle(circle) => {
+            distance_to_axis(circle.center(), axis) + circle.radius()
+        }
+        _ => approx_v
+            .iter()
+            .map(|(_, point)| distance_to_axis(*point, axis))
+            .chain([bounds.min.v, bounds.max.v].map(|v| {
+                distance_to_axis(path.point_from_path_coords([v]), axis)
+            }))
+            .max()
+            .unwrap_or(Scalar::ZERO),
+    };
+    if radius <= tolerance.inner() {
+        return Vec::new();
+    }
+
+    let approx_u = (
+        GlobalPath::circle_from_radius(radius),
+        RangeOnPath::from([[bounds.min.u], [bounds.max.u]]),
+    )
+        .approx_with_cache(tolerance, &mut ());
+
+    let mut points = Vec::new();
+    for (v, _) in &approx_v {
+        for (u, _) in &approx_u {
+            let point_surface = Point::from([u.t, v.t]);
+            let point_global = surface.point_from_surface_coords(point_surface);
+            points.push(ApproxPoint::new(point_surface, point_global));
+        }
+    }
+
+    points
+}
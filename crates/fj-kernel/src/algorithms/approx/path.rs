@@ -30,7 +30,7 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, CubicBezier, Ellipse, Point, Scalar, Sign};
 
 use crate::geometry::curve::{Curve, GlobalPath};
 
@@ -52,6 +52,12 @@ impl Approx for (&Curve, RangeOnPath) {
                 approx_circle(circle, range, tolerance.into())
             }
             Curve::Line(_) => vec![],
+            Curve::Bezier(bezier) => {
+                approx_bezier(bezier, range, tolerance.into())
+            }
+            Curve::Ellipse(ellipse) => {
+                approx_ellipse(ellipse, range, tolerance.into())
+            }
         }
     }
 }
@@ -72,6 +78,12 @@ impl Approx for (GlobalPath, RangeOnPath) {
                 approx_circle(&circle, range, tolerance.into())
             }
             GlobalPath::Line(_) => vec![],
+            GlobalPath::Bezier(bezier) => {
+                approx_bezier(&bezier, range, tolerance.into())
+            }
+            GlobalPath::Ellipse(ellipse) => {
+                approx_ellipse(&ellipse, range, tolerance.into())
+            }
         }
     }
 }
@@ -123,6 +135,50 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// Approximate an ellipse
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the ellipse.
+fn approx_ellipse<const D: usize>(
+    ellipse: &Ellipse<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let params = PathApproxParams::for_ellipse(ellipse, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = ellipse.point_from_ellipse_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+/// Approximate a Bézier curve
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the curve.
+fn approx_bezier<const D: usize>(
+    bezier: &CubicBezier<D>,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let range = range.into();
+
+    let params = PathApproxParams::for_bezier(bezier, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = bezier.point_from_bezier_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
 struct PathApproxParams {
     increment: Scalar,
 }
@@ -132,8 +188,21 @@ impl PathApproxParams {
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
-        let radius = circle.a().magnitude();
+        Self::for_radius(circle.a().magnitude(), tolerance)
+    }
+
+    pub fn for_ellipse<const D: usize>(
+        ellipse: &Ellipse<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        // An ellipse is a circle, stretched along its axes. Stretching doesn't
+        // increase the distance between a circle and its approximation beyond
+        // what it would be for a circle with the larger radius.
+        let [major_radius, _] = ellipse.radii();
+        Self::for_radius(major_radius, tolerance)
+    }
 
+    fn for_radius(radius: Scalar, tolerance: impl Into<Tolerance>) -> Self {
         let num_vertices_to_approx_full_circle = Scalar::max(
             Scalar::PI
                 / (Scalar::ONE - (tolerance.into().inner() / radius)).acos(),
@@ -146,6 +215,28 @@ impl PathApproxParams {
         Self { increment }
     }
 
+    pub fn for_bezier<const D: usize>(
+        bezier: &CubicBezier<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        // A straight line between two points on the curve deviates from it by
+        // at most `h^2 / 8` times the maximum of its second derivative, where
+        // `h` is the distance of the points in curve coordinates.
+        let max_second_derivative = bezier.max_second_derivative();
+
+        let num_segments = if max_second_derivative > Scalar::ZERO {
+            let h =
+                (tolerance.into().inner() * 8. / max_second_derivative).sqrt();
+            Scalar::max((Scalar::ONE / h).ceil(), Scalar::ONE)
+        } else {
+            Scalar::ONE
+        };
+
+        let increment = Scalar::ONE / num_segments;
+
+        Self { increment }
+    }
+
     pub fn increment(&self) -> Scalar {
         self.increment
     }
@@ -156,7 +247,18 @@ impl PathApproxParams {
     ) -> impl Iterator<Item = Point<1>> + '_ {
         let range = range.into();
 
-        let [a, b] = range.boundary.map(|point| point.t / self.increment());
+        let [a, b] = range.boundary.map(|point| {
+            let i = point.t / self.increment();
+
+            // A boundary that lies on an increment must not produce a point,
+            // even if floating-point inaccuracies put it slightly beyond.
+            let rounded = i.round();
+            if (i - rounded).abs() <= Scalar::from_f64(1e-9) {
+                rounded
+            } else {
+                i
+            }
+        });
         let direction = (b - a).sign();
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
@@ -193,7 +295,7 @@ impl PathApproxParams {
 mod tests {
     use std::f64::consts::TAU;
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{Circle, CubicBezier, Ellipse, Point, Scalar};
 
     use crate::algorithms::approx::{path::RangeOnPath, Tolerance};
 
@@ -218,6 +320,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn increment_for_ellipse() {
+        let circle = Circle::from_center_and_radius([0., 0.], 2.);
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [1., 2.]);
+
+        for tolerance in [0.5, 0.1, 0.01] {
+            assert_eq!(
+                PathApproxParams::for_ellipse(&ellipse, tolerance).increment(),
+                PathApproxParams::for_circle(&circle, tolerance).increment(),
+            );
+        }
+    }
+
+    #[test]
+    fn increment_for_bezier() {
+        // The second derivative of this curve is `[0., -8.]` everywhere.
+        let bezier =
+            CubicBezier::from_quadratic_points([[0., 0.], [1., 2.], [2., 0.]]);
+
+        let params = PathApproxParams::for_bezier(&bezier, 0.25);
+        assert_eq!(params.increment(), Scalar::ONE / 2.);
+
+        let params = PathApproxParams::for_bezier(&bezier, 0.01);
+        assert_eq!(params.increment(), Scalar::ONE / 10.);
+
+        // Straight curves don't need any points in between their ends.
+        let bezier =
+            CubicBezier::from_quadratic_points([[0., 0.], [1., 1.], [2., 2.]]);
+        let params = PathApproxParams::for_bezier(&bezier, 0.01);
+        assert_eq!(params.increment(), Scalar::ONE);
+    }
+
+    #[test]
+    fn points_for_boundary_on_increment() {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let params = PathApproxParams::for_circle(&circle, 0.375);
+        let increment = params.increment();
+
+        // A boundary that lies on an increment doesn't produce a point, even if
+        // floating-point inaccuracies put it slightly beyond the increment.
+        for error in [0., 1e-12, -1e-12] {
+            let end = increment * 2. + error;
+
+            let points = params.points([[Scalar::ZERO], [end]]);
+            assert_eq!(points.collect::<Vec<_>>(), [Point::from([increment])]);
+        }
+    }
+
     #[test]
     fn points_for_circle() {
         // At the chosen values for radius and tolerance (see below), the
@@ -0,0 +1,592 @@
+//! Blending the edges of objects
+//!
+//! See [`BlendEdges`].
+
+use fj_interop::mesh::Color;
+use fj_math::{Point, Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance,
+        boolean::{Boolean, BooleanError, BooleanOp},
+        sweep::Sweep,
+    },
+    builder::{CycleBuilder, HalfEdgeBuilder},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{Face, GlobalEdge, HalfEdge, Objects, Sketch, Solid, Surface},
+    partial::{Partial, PartialCycle, PartialFace, PartialObject},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// A blend that replaces an edge
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Blend {
+    /// Round the edge off with a cylindrical face of the given radius
+    Fillet {
+        /// The radius of the cylindrical face
+        radius: Scalar,
+    },
+
+    /// Cut the edge off with a planar face
+    Chamfer {
+        /// The distance of the planar face from the edge, along both faces
+        distance: Scalar,
+    },
+}
+
+/// Replace edges of an object with blend faces
+///
+/// Blending an edge requires boolean operations, which rely on approximations
+/// of curved geometry. This is why the trait is implemented for a tuple of the
+/// solid and the tolerance.
+///
+/// # Implementation Note
+///
+/// Only straight edges between two planar faces can be blended. Each edge is
+/// blended by subtracting material from it, or adding material to it, if the
+/// edge is concave. Concave edges can only be chamfered so far. Where multiple
+/// fillets meet, their cylindrical faces intersect each other, which the
+/// boolean operations don't support yet. Blending any of those edges results
+/// in a [`BlendError`].
+pub trait BlendEdges: Sized {
+    /// The object that results from blending the edges
+    type Output;
+
+    /// Blend the given edges of the object
+    fn blend_edges(
+        self,
+        edges: impl IntoIterator<Item = Handle<GlobalEdge>>,
+        blend: Blend,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output;
+}
+
+impl<T> BlendEdges for (Handle<Solid>, T)
+where
+    T: Into<Tolerance>,
+{
+    type Output = Result<Handle<Solid>, BlendError>;
+
+    fn blend_edges(
+        self,
+        edges: impl IntoIterator<Item = Handle<GlobalEdge>>,
+        blend: Blend,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output {
+        let (solid, tolerance) = self;
+        let tolerance = tolerance.into();
+
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        // Neighboring faces don't necessarily refer to the same global edge,
+        // so edges are identified by the positions of their vertices.
+        let mut edges_by_position: Vec<[Point<3>; 2]> = Vec::new();
+        for edge in edges {
+            let edge = edge
+                .vertices()
+                .access_in_normalized_order()
+                .map(|vertex| vertex.position());
+
+            if !edges_by_position
+                .iter()
+                .any(|other| is_same_edge(edge, *other, epsilon))
+            {
+                edges_by_position.push(edge);
+            }
+        }
+
+        if let Blend::Fillet { .. } = blend {
+            let fillets_meet =
+                edges_by_position.iter().enumerate().any(|(i, a)| {
+                    edges_by_position[i + 1..].iter().any(|b| {
+                        a.iter().any(|a| {
+                            b.iter().any(|b| a.distance_to(b) < epsilon)
+                        })
+                    })
+                });
+            if fillets_meet {
+                return Err(BlendError::FilletsMeet);
+            }
+        }
+
+        // All tools are computed from the original solid, so the result
+        // doesn't depend on the order of the edges.
+        let tools = edges_by_position
+            .into_iter()
+            .map(|edge| BlendTool::new(&solid, edge, blend, epsilon, objects))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let solid = tools.into_iter().try_fold(solid, |solid, tool| {
+            solid.boolean(tool.op, tool.solid, tolerance, objects)
+        })?;
+
+        Ok(solid)
+    }
+}
+
+/// An edge that can't be blended
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BlendError {
+    /// The edge is not shared by exactly two faces
+    #[error("Edge must be shared by exactly two faces, found {0}")]
+    NotSharedByTwoFaces(usize),
+
+    /// The edge is curved
+    #[error("Blending curved edges is not supported")]
+    CurvedEdge,
+
+    /// One of the faces that meet at the edge is curved
+    #[error("Blending edges of curved faces is not supported")]
+    CurvedFace,
+
+    /// A fillet was requested for a concave edge
+    #[error("Filleting concave edges is not supported")]
+    ConcaveFillet,
+
+    /// Fillets were requested for edges that meet at a vertex
+    #[error("Filleting edges that meet at a vertex is not supported")]
+    FilletsMeet,
+
+    /// The boolean operation that blends an edge is not supported
+    #[error("Failed to blend edge")]
+    Boolean(#[from] BooleanError),
+}
+
+/// A solid that blends an edge, when combined with the solid the edge is in
+struct BlendTool {
+    solid: Handle<Solid>,
+    op: BooleanOp,
+}
+
+impl BlendTool {
+    fn new(
+        solid: &Solid,
+        edge: [Point<3>; 2],
+        blend: Blend,
+        epsilon: Scalar,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self, BlendError> {
+        let sides = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| {
+                face.all_cycles()
+                    .flat_map(|cycle| cycle.half_edges())
+                    .filter(move |half_edge| {
+                        is_same_edge(positions(half_edge), edge, epsilon)
+                    })
+                    .map(move |half_edge| Side::new(face, half_edge))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let [a, b]: [Side; 2] = sides.try_into().map_err(|sides: Vec<_>| {
+            BlendError::NotSharedByTwoFaces(sides.len())
+        })?;
+
+        let [start, end] = [a.start, a.end];
+        let length = (end - start).magnitude();
+        let direction = (end - start) / length;
+
+        // A convex edge is blended by subtracting the tool, a concave one by
+        // adding it. The tool is built outside of the solid, for convex edges,
+        // and inside of it for concave ones.
+        let is_convex = a.inwards.dot(&b.normal) < Scalar::ZERO;
+        let (op, outwards) = if is_convex {
+            (BooleanOp::Difference, [a.normal, b.normal])
+        } else {
+            (BooleanOp::Union, [-a.normal, -b.normal])
+        };
+
+        // Both faces extend from the edge at this angle.
+        let angle = Scalar::from(a.inwards.dot(&b.inwards).into_f64().acos());
+
+        let (setback, arc) = match blend {
+            Blend::Fillet { .. } if !is_convex => {
+                return Err(BlendError::ConcaveFillet)
+            }
+            Blend::Fillet { radius } => {
+                let setback = radius
+                    * Scalar::from((angle.into_f64() / 2.).tan().recip());
+                (setback, Some(Scalar::PI - angle))
+            }
+            Blend::Chamfer { distance } => (distance, None),
+        };
+        let [on_a, on_b] =
+            [a.inwards, b.inwards].map(|inwards| start + inwards * setback);
+
+        // The tool must reach beyond the solid, where it doesn't blend
+        // anything, so its faces don't coincide with those of the solid. Where
+        // the edge leaves the solid at its ends, the tool must reach beyond
+        // them. Elsewhere, it would cut into neighboring faces.
+        let reach = setback / 2.;
+        let [extend_start, extend_end] =
+            [(start, -direction), (end, direction)].map(
+                |(vertex, direction)| {
+                    let exit = exit_cosine(solid, vertex, direction, epsilon);
+                    if is_convex && exit > epsilon {
+                        reach
+                    } else {
+                        Scalar::ZERO
+                    }
+                },
+            );
+
+        let mut points = vec![
+            on_a,
+            on_a + outwards[0] * reach,
+            start + (outwards[0] + outwards[1]) * reach,
+            on_b + outwards[1] * reach,
+            on_b,
+        ];
+
+        // The tool is built on a plane that is perpendicular to the edge.
+        let origin = start - direction * extend_start;
+        let u = a.inwards;
+        let v = direction.cross(&u);
+        let to_surface = |point: Point<3>| {
+            let point = point - origin;
+            Point::from([point.dot(&u), point.dot(&v)])
+        };
+
+        let is_ccw = {
+            let points = points.iter().copied().map(to_surface);
+            let area = points
+                .clone()
+                .zip(points.cycle().skip(1))
+                .map(|(a, b)| a.u * b.v - b.u * a.v)
+                .fold(Scalar::ZERO, |sum, area| sum + area);
+            area > Scalar::ZERO
+        };
+        if !is_ccw {
+            points.reverse();
+        }
+
+        let surface = Surface::new(SurfaceGeometry::Swept {
+            u: GlobalPath::line_from_points([origin, origin + u]).0,
+            v,
+        })
+        .insert(objects);
+
+        let mut exterior = PartialCycle::default();
+        let mut half_edges = points
+            .into_iter()
+            .map(|point| {
+                exterior.add_half_edge_from_point_to_start(to_surface(point))
+            })
+            .collect::<Vec<_>>();
+
+        // The last edge connects the faces of the solid. If it's an arc, it
+        // curves towards the edge, against the orientation of the cycle.
+        let mut last = half_edges.pop().expect("Tool has corners");
+        for mut half_edge in half_edges {
+            half_edge.write().update_as_line_segment();
+        }
+        match arc {
+            Some(arc) => last.write().update_as_arc(-arc),
+            None => {
+                last.write().update_as_line_segment();
+            }
+        }
+
+        let face = PartialFace {
+            surface: Partial::from(surface),
+            exterior: Partial::from_partial(exterior),
+            color: Some(a.color),
+            ..Default::default()
+        }
+        .build(objects)
+        .insert(objects);
+
+        let path = direction * (extend_start + length + extend_end);
+        let solid = Sketch::new([face]).insert(objects).sweep(path, objects);
+
+        Ok(Self { solid, op })
+    }
+}
+
+/// One of the two faces of the solid that meet at an edge
+struct Side {
+    /// The normal of the face, pointing out of the solid
+    normal: Vector<3>,
+
+    /// The direction from the edge into the face
+    inwards: Vector<3>,
+
+    color: Color,
+    start: Point<3>,
+    end: Point<3>,
+}
+
+impl Side {
+    fn new(face: &Face, half_edge: &HalfEdge) -> Result<Self, BlendError> {
+        let normal = plane_normal(face)?;
+
+        let Curve::Line(_) = half_edge.curve() else {
+            return Err(BlendError::CurvedEdge);
+        };
+        let [start, end] = positions(half_edge);
+
+        // The face is to the left of its half-edges, when looking at its
+        // front.
+        let inwards = normal.cross(&(end - start)).normalize();
+
+        Ok(Self {
+            normal,
+            inwards,
+            color: face.color(),
+            start,
+            end,
+        })
+    }
+}
+
+/// Compute the normal of a planar face, pointing towards its front
+fn plane_normal(face: &Face) -> Result<Vector<3>, BlendError> {
+    let SurfaceGeometry::Swept {
+        u: GlobalPath::Line(line),
+        v,
+    } = face.surface().geometry()
+    else {
+        return Err(BlendError::CurvedFace);
+    };
+
+    let normal = line.direction().cross(&v).normalize();
+    Ok(match face.exterior().winding() {
+        Winding::Ccw => normal,
+        Winding::Cw => -normal,
+    })
+}
+
+/// Find out how directly a line in the given direction leaves the solid
+///
+/// Returns the largest cosine of the angles between the direction and the
+/// normals of the faces at the vertex. The line leaves the solid, if it's
+/// positive.
+fn exit_cosine(
+    solid: &Solid,
+    vertex: Point<3>,
+    direction: Vector<3>,
+    epsilon: Scalar,
+) -> Scalar {
+    solid
+        .shells()
+        .flat_map(|shell| shell.faces())
+        .filter(|face| {
+            face.all_cycles().flat_map(|cycle| cycle.half_edges()).any(
+                |half_edge| {
+                    let [start, _] = positions(half_edge);
+                    start.distance_to(&vertex) < epsilon
+                },
+            )
+        })
+        .filter_map(|face| plane_normal(face).ok())
+        .map(|normal| normal.dot(&direction))
+        .fold(-Scalar::ONE, Scalar::max)
+}
+
+fn positions(half_edge: &HalfEdge) -> [Point<3>; 2] {
+    half_edge
+        .surface_vertices()
+        .map(|vertex| vertex.global_form().position())
+}
+
+fn is_same_edge(a: [Point<3>; 2], b: [Point<3>; 2], epsilon: Scalar) -> bool {
+    let [b0, b1] = b;
+    [[b0, b1], [b1, b0]].into_iter().any(|b| {
+        a.into_iter()
+            .zip(b)
+            .all(|(a, b)| a.distance_to(&b) < epsilon)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, sweep::Sweep},
+        objects::{GlobalEdge, Solid},
+        services::Services,
+        storage::Handle,
+        test_helpers::{assert_volume, polygon, square},
+    };
+
+    use super::{Blend, BlendEdges, BlendError};
+
+    #[test]
+    fn chamfer_vertical_edges() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let edges = edges_parallel_to_z(&solid);
+        let solid = (solid, tolerance())
+            .blend_edges(
+                edges,
+                Blend::Chamfer {
+                    distance: Scalar::from(0.2),
+                },
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // Each chamfer removes a triangular prism.
+        assert_volume(&solid, 8. - 4. * 0.02 * 2., 0.);
+    }
+
+    #[test]
+    fn fillet_vertical_edges() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let edges = edges_parallel_to_z(&solid);
+        let solid = (solid, tolerance())
+            .blend_edges(
+                edges,
+                Blend::Fillet {
+                    radius: Scalar::from(0.2),
+                },
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // Each fillet removes the corner of a square, minus a quarter circle.
+        let corner = 0.2 * 0.2 * (1. - PI / 4.);
+        assert_volume(&solid, 8. - 4. * corner * 2., 0.001);
+    }
+
+    #[test]
+    fn chamfer_all_edges() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let edges = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.global_form().clone())
+            .collect::<Vec<_>>();
+        let solid = (solid, tolerance())
+            .blend_edges(
+                edges,
+                Blend::Chamfer {
+                    distance: Scalar::from(0.2),
+                },
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // The chamfers of the 12 edges overlap at the 8 corners.
+        let d: f64 = 0.2;
+        assert_volume(&solid, 8. - 6. * d * d * 2. + 6. * d.powi(3), 0.);
+    }
+
+    #[test]
+    fn chamfer_concave_edge() {
+        let mut services = Services::new();
+
+        let solid = polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut services.objects,
+        )
+        .sweep([0., 0., 1.], &mut services.objects);
+        let edges = edges_parallel_to_z(&solid).into_iter().filter(|edge| {
+            edge.vertices()
+                .access_in_normalized_order()
+                .iter()
+                .all(|vertex| {
+                    let position = vertex.position();
+                    position.x == Scalar::ONE && position.y == Scalar::ONE
+                })
+        });
+        let solid = (solid, tolerance())
+            .blend_edges(
+                edges,
+                Blend::Chamfer {
+                    distance: Scalar::from(0.2),
+                },
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // The chamfer adds a triangular prism.
+        assert_volume(&solid, 3. + 0.02, 0.);
+    }
+
+    #[test]
+    fn fillet_concave_edge() {
+        let mut services = Services::new();
+
+        let solid = polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut services.objects,
+        )
+        .sweep([0., 0., 1.], &mut services.objects);
+        let edges = edges_parallel_to_z(&solid);
+        let result = (solid, tolerance()).blend_edges(
+            edges,
+            Blend::Fillet {
+                radius: Scalar::from(0.2),
+            },
+            &mut services.objects,
+        );
+
+        assert_eq!(result, Err(BlendError::ConcaveFillet));
+    }
+
+    #[test]
+    fn fillet_edges_that_meet() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let edges = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.global_form().clone())
+            .collect::<Vec<_>>();
+        let result = (solid, tolerance()).blend_edges(
+            edges,
+            Blend::Fillet {
+                radius: Scalar::from(0.2),
+            },
+            &mut services.objects,
+        );
+
+        assert_eq!(result, Err(BlendError::FilletsMeet));
+    }
+
+    fn edges_parallel_to_z(solid: &Solid) -> Vec<Handle<GlobalEdge>> {
+        solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.global_form().clone())
+            .filter(|edge| {
+                let [a, b] = edge
+                    .vertices()
+                    .access_in_normalized_order()
+                    .map(|vertex| vertex.position());
+                (b - a).normalize().cross(&Vector::unit_z()).magnitude()
+                    < Scalar::from(1e-9)
+            })
+            .collect()
+    }
+
+    fn tolerance() -> Tolerance {
+        Tolerance::from_scalar(0.001).unwrap()
+    }
+}
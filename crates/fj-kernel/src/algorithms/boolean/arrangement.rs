@@ -0,0 +1,550 @@
+//! Planar arrangements of profile segments
+//!
+//! See [`Arrangement`].
+
+use fj_math::{Point, Scalar};
+
+use super::profile::{angle_of, Containment, Profile, ProfileSegment};
+
+/// The planar arrangement of a set of segments
+///
+/// Splits all provided segments where they intersect each other, and computes
+/// the regions that the resulting edges bound. This is the central building
+/// block of the boolean operations, as it provides the pieces that faces (or
+/// sketches) are split into.
+pub struct Arrangement<T> {
+    /// The vertices of the arrangement
+    pub vertices: Vec<ArrangementVertex>,
+
+    /// The edges of the arrangement
+    pub edges: Vec<ArrangementEdge<T>>,
+
+    /// The bounded regions of the arrangement
+    pub regions: Vec<Region>,
+}
+
+impl<T: Copy> Arrangement<T> {
+    /// Compute the arrangement of the provided segments
+    ///
+    /// Each segment comes with a tag, which is carried over to all edges that
+    /// are created from the segment. Where two segments overlap, the tag of
+    /// the one that comes first is used.
+    ///
+    /// `points` are additional points where segments are split, if they lie on
+    /// them.
+    ///
+    /// All vertices of the arrangement are passed through `snap`, which must
+    /// return an identifier and the definitive position of the vertex. Points
+    /// that map to the same identifier are considered to be the same vertex.
+    pub fn compute(
+        segments: impl IntoIterator<Item = (ProfileSegment, T)>,
+        points: impl IntoIterator<Item = Point<2>>,
+        epsilon: Scalar,
+        mut snap: impl FnMut(Point<2>) -> (usize, Point<2>),
+    ) -> Self {
+        let mut vertices = Vertices::default();
+        for point in points {
+            vertices.insert(point, &mut snap);
+        }
+
+        let segments = segments
+            .into_iter()
+            .filter_map(|(segment, tag)| {
+                let [a, b] = segment
+                    .points()
+                    .map(|point| vertices.insert(point, &mut snap));
+
+                if a == b && !segment.is_full_circle() {
+                    return None;
+                }
+
+                Some((segment, tag, [a, b]))
+            })
+            .collect::<Vec<_>>();
+
+        // Register the intersections between all segments as vertices.
+        for (i, (a, _, _)) in segments.iter().enumerate() {
+            for (b, _, _) in &segments[i + 1..] {
+                if !bounding_boxes_overlap(a, b, epsilon) {
+                    continue;
+                }
+
+                for (s, _) in a.intersect(b, epsilon) {
+                    vertices.insert(a.point_at(s), &mut snap);
+                }
+            }
+        }
+
+        // Split all segments at the vertices that are on them.
+        let mut edges: Vec<ArrangementEdge<T>> = Vec::new();
+        for (segment, tag, [start, end]) in segments {
+            let mut splits = vec![(Scalar::ZERO, start), (Scalar::ONE, end)];
+            for (index, vertex) in vertices.inner.iter().enumerate() {
+                if index == start || index == end {
+                    continue;
+                }
+                if let Some(s) = segment.locate(vertex.position, epsilon) {
+                    if s > Scalar::ZERO && s < Scalar::ONE {
+                        splits.push((s, index));
+                    }
+                }
+            }
+            splits.sort();
+
+            for window in splits.windows(2) {
+                let [(s_a, a), (s_b, b)] = [window[0], window[1]];
+
+                let is_whole_segment =
+                    s_a == Scalar::ZERO && s_b == Scalar::ONE;
+                if a == b && !(is_whole_segment && segment.is_full_circle()) {
+                    continue;
+                }
+
+                let points = [a, b].map(|index| vertices.inner[index].position);
+                let sub_segment = segment.sub_segment([s_a, s_b], points);
+
+                let is_duplicate = edges.iter().any(|edge| {
+                    let same_vertices =
+                        edge.vertices == [a, b] || edge.vertices == [b, a];
+                    same_vertices
+                        && edge.segment.distance_to(sub_segment.point_at(0.5))
+                            <= epsilon
+                });
+                if is_duplicate {
+                    continue;
+                }
+
+                edges.push(ArrangementEdge {
+                    vertices: [a, b],
+                    segment: sub_segment,
+                    range: [s_a, s_b],
+                    tag,
+                });
+            }
+        }
+
+        let regions = compute_regions(&vertices.inner, &edges, epsilon);
+
+        Self {
+            vertices: vertices.inner,
+            edges,
+            regions,
+        }
+    }
+
+    /// Access the segment of a directed edge
+    pub fn segment(&self, edge: DirectedEdge) -> ProfileSegment {
+        let segment = self.edges[edge.index].segment;
+        if edge.reversed {
+            segment.reverse()
+        } else {
+            segment
+        }
+    }
+
+    /// Access the vertices of a directed edge
+    pub fn edge_vertices(&self, edge: DirectedEdge) -> [&ArrangementVertex; 2] {
+        let [a, b] = self.edges[edge.index].vertices;
+        let [a, b] = if edge.reversed { [b, a] } else { [a, b] };
+        [&self.vertices[a], &self.vertices[b]]
+    }
+}
+
+/// A vertex in an [`Arrangement`]
+#[derive(Clone, Copy, Debug)]
+pub struct ArrangementVertex {
+    /// The identifier, as returned by the `snap` function
+    pub id: usize,
+
+    /// The position of the vertex
+    pub position: Point<2>,
+}
+
+/// An edge in an [`Arrangement`]
+#[derive(Clone, Copy, Debug)]
+pub struct ArrangementEdge<T> {
+    /// The indices of the start and end vertices
+    pub vertices: [usize; 2],
+
+    /// The geometry of the edge
+    pub segment: ProfileSegment,
+
+    /// The range of the edge on the original segment, in segment parameters
+    pub range: [Scalar; 2],
+
+    /// The tag of the original segment
+    pub tag: T,
+}
+
+/// A directed edge in an [`Arrangement`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DirectedEdge {
+    /// The index of the edge
+    pub index: usize,
+
+    /// Indicate whether the edge is traversed in reverse
+    pub reversed: bool,
+}
+
+impl DirectedEdge {
+    fn from_raw(raw: usize) -> Self {
+        Self {
+            index: raw / 2,
+            reversed: raw % 2 == 1,
+        }
+    }
+
+    fn twin(raw: usize) -> usize {
+        raw ^ 1
+    }
+}
+
+/// A bounded region of an [`Arrangement`]
+#[derive(Clone, Debug)]
+pub struct Region {
+    /// The exterior boundary of the region, oriented counter-clockwise
+    pub exterior: Vec<DirectedEdge>,
+
+    /// The interior boundaries of the region, oriented clockwise
+    pub interiors: Vec<Vec<DirectedEdge>>,
+
+    /// A point within the region
+    pub interior_point: Point<2>,
+}
+
+#[derive(Default)]
+struct Vertices {
+    inner: Vec<ArrangementVertex>,
+}
+
+impl Vertices {
+    fn insert(
+        &mut self,
+        point: Point<2>,
+        snap: &mut impl FnMut(Point<2>) -> (usize, Point<2>),
+    ) -> usize {
+        let (id, position) = snap(point);
+
+        if let Some(index) =
+            self.inner.iter().position(|vertex| vertex.id == id)
+        {
+            return index;
+        }
+
+        self.inner.push(ArrangementVertex { id, position });
+        self.inner.len() - 1
+    }
+}
+
+fn bounding_boxes_overlap(
+    a: &ProfileSegment,
+    b: &ProfileSegment,
+    epsilon: Scalar,
+) -> bool {
+    let [a_min, a_max] = a.bounding_box();
+    let [b_min, b_max] = b.bounding_box();
+
+    a_min.u <= b_max.u + epsilon
+        && b_min.u <= a_max.u + epsilon
+        && a_min.v <= b_max.v + epsilon
+        && b_min.v <= a_max.v + epsilon
+}
+
+fn compute_regions<T: Copy>(
+    vertices: &[ArrangementVertex],
+    edges: &[ArrangementEdge<T>],
+    epsilon: Scalar,
+) -> Vec<Region> {
+    let directed_segment = |raw: usize| {
+        let edge = DirectedEdge::from_raw(raw);
+        let segment = edges[edge.index].segment;
+        if edge.reversed {
+            segment.reverse()
+        } else {
+            segment
+        }
+    };
+    let start_vertex = |raw: usize| {
+        let edge = DirectedEdge::from_raw(raw);
+        let [a, b] = edges[edge.index].vertices;
+        if edge.reversed {
+            b
+        } else {
+            a
+        }
+    };
+
+    // Sort the outgoing edges of each vertex counter-clockwise.
+    let mut outgoing = vec![Vec::new(); vertices.len()];
+    for raw in 0..edges.len() * 2 {
+        let segment = directed_segment(raw);
+        let mut angle = angle_of(segment.tangent_at(0.));
+        if angle >= Scalar::PI - Scalar::from_f64(1e-12) {
+            angle -= Scalar::TAU;
+        }
+
+        outgoing[start_vertex(raw)].push((raw, angle, segment.curvature()));
+    }
+    for edges in &mut outgoing {
+        edges.sort_by(
+            |(_, angle_a, curvature_a), (_, angle_b, curvature_b)| {
+                if (*angle_a - *angle_b).abs() <= Scalar::from_f64(1e-9) {
+                    curvature_a.cmp(curvature_b)
+                } else {
+                    angle_a.cmp(angle_b)
+                }
+            },
+        );
+    }
+
+    // The next edge of a boundary is the one that comes right before the
+    // current edge's twin, in counter-clockwise order. This makes sure that
+    // the bounded region is always on the left of the boundary.
+    let next = |raw: usize| {
+        let twin = DirectedEdge::twin(raw);
+        let edges = &outgoing[start_vertex(twin)];
+
+        let index = edges
+            .iter()
+            .position(|(edge, _, _)| *edge == twin)
+            .expect("Twin must be an outgoing edge of its start vertex");
+        let (next, _, _) = edges[(index + edges.len() - 1) % edges.len()];
+
+        next
+    };
+
+    let mut visited = vec![false; edges.len() * 2];
+    let mut exteriors = Vec::new();
+    let mut interiors = Vec::new();
+
+    for start in 0..edges.len() * 2 {
+        if visited[start] {
+            continue;
+        }
+
+        let mut boundary = Vec::new();
+        let mut current = start;
+        while !visited[current] {
+            visited[current] = true;
+            boundary.push(current);
+            current = next(current);
+        }
+
+        remove_spikes(&mut boundary);
+        if boundary.is_empty() {
+            continue;
+        }
+
+        let area = boundary
+            .iter()
+            .map(|&raw| directed_segment(raw).area_contribution())
+            .fold(Scalar::ZERO, |a, b| a + b);
+
+        let boundary = boundary
+            .into_iter()
+            .map(DirectedEdge::from_raw)
+            .collect::<Vec<_>>();
+
+        // Boundaries that enclose no area are degenerate and can be ignored.
+        if area > epsilon * epsilon {
+            exteriors.push((boundary, area));
+        } else if area < -(epsilon * epsilon) {
+            interiors.push(boundary);
+        }
+    }
+
+    let to_loop = |boundary: &[DirectedEdge]| {
+        boundary
+            .iter()
+            .map(|edge| {
+                directed_segment(edge.index * 2 + usize::from(edge.reversed))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut regions = exteriors
+        .iter()
+        .map(|(exterior, _)| Region {
+            exterior: exterior.clone(),
+            interiors: Vec::new(),
+            interior_point: Point::origin(),
+        })
+        .collect::<Vec<_>>();
+
+    // Each interior boundary belongs to the smallest region that contains it.
+    for interior in interiors {
+        let point = {
+            let edge = interior[0];
+            vertices[start_vertex(edge.index * 2 + usize::from(edge.reversed))]
+                .position
+        };
+
+        let containing = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, (exterior, _))| {
+                let profile = Profile {
+                    loops: vec![to_loop(exterior)],
+                };
+                profile.contains(point, epsilon) == Containment::Inside
+            })
+            .min_by_key(|(_, (_, area))| *area);
+
+        if let Some((index, _)) = containing {
+            regions[index].interiors.push(interior);
+        }
+    }
+
+    for region in &mut regions {
+        let profile = Profile {
+            loops: [&region.exterior]
+                .into_iter()
+                .chain(&region.interiors)
+                .map(|boundary| to_loop(boundary))
+                .collect(),
+        };
+        region.interior_point = profile.interior_point(epsilon);
+    }
+
+    regions
+}
+
+/// Remove parts of a boundary that go forth and back along the same edge
+///
+/// Those are left over from edges that only touch a region with one of their
+/// vertices.
+fn remove_spikes(boundary: &mut Vec<usize>) {
+    loop {
+        let len = boundary.len();
+        let spike = (0..len).find(|&i| {
+            let next = (i + 1) % len;
+            i != next && DirectedEdge::twin(boundary[i]) == boundary[next]
+        });
+
+        let Some(i) = spike else {
+            break;
+        };
+
+        let next = (i + 1) % len;
+        let [first, second] = if next > i { [next, i] } else { [i, next] };
+        boundary.remove(first);
+        boundary.remove(second);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::algorithms::boolean::profile::ProfileSegment;
+
+    use super::Arrangement;
+
+    fn snap(
+        points: &mut Vec<Point<2>>,
+    ) -> impl FnMut(Point<2>) -> (usize, Point<2>) + '_ {
+        |point| {
+            if let Some(index) = points
+                .iter()
+                .position(|p| (*p - point).magnitude() < Scalar::from_f64(1e-9))
+            {
+                return (index, points[index]);
+            }
+
+            points.push(point);
+            (points.len() - 1, point)
+        }
+    }
+
+    fn polygon(points: &[[f64; 2]]) -> Vec<ProfileSegment> {
+        (0..points.len())
+            .map(|i| ProfileSegment::Line {
+                points: [points[i], points[(i + 1) % points.len()]]
+                    .map(Point::from),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_square() {
+        let mut points = Vec::new();
+
+        let square = polygon(&[[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        let cut = ProfileSegment::Line {
+            points: [Point::from([1., 0.]), Point::from([1., 2.])],
+        };
+
+        let arrangement = Arrangement::compute(
+            square
+                .into_iter()
+                .map(|segment| (segment, false))
+                .chain([(cut, true)]),
+            [],
+            Scalar::from_f64(1e-9),
+            snap(&mut points),
+        );
+
+        assert_eq!(arrangement.vertices.len(), 6);
+        assert_eq!(arrangement.edges.len(), 7);
+        assert_eq!(arrangement.regions.len(), 2);
+
+        for region in &arrangement.regions {
+            let area = region
+                .exterior
+                .iter()
+                .map(|edge| arrangement.segment(*edge).area_contribution())
+                .fold(Scalar::ZERO, |a, b| a + b);
+
+            assert_eq!(area, Scalar::from(2.));
+            assert!(region.interiors.is_empty());
+        }
+    }
+
+    #[test]
+    fn square_with_hole() {
+        let mut points = Vec::new();
+
+        let outer = polygon(&[[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        let inner = polygon(&[[1., 1.], [1., 3.], [3., 3.], [3., 1.]]);
+
+        let arrangement = Arrangement::compute(
+            outer.into_iter().chain(inner).map(|segment| (segment, ())),
+            [],
+            Scalar::from_f64(1e-9),
+            snap(&mut points),
+        );
+
+        // The square and its hole form one region. The hole itself is another.
+        assert_eq!(arrangement.regions.len(), 2);
+        let with_hole = arrangement
+            .regions
+            .iter()
+            .find(|region| !region.interiors.is_empty())
+            .unwrap();
+        let [u, v] = with_hole.interior_point.coords.components.map(f64::from);
+        assert!(!(u > 1. && u < 3. && v > 1. && v < 3.));
+    }
+
+    #[test]
+    fn circle_and_line() {
+        let mut points = Vec::new();
+
+        let circle = ProfileSegment::arc(
+            Point::from([0., 0.]),
+            Scalar::ONE,
+            [Scalar::ZERO, Scalar::TAU],
+        );
+        let line = ProfileSegment::Line {
+            points: [Point::from([-2., 0.5]), Point::from([2., 0.5])],
+        };
+
+        let arrangement = Arrangement::compute(
+            [(circle, ()), (line, ())],
+            [],
+            Scalar::from_f64(1e-9),
+            snap(&mut points),
+        );
+
+        assert_eq!(arrangement.regions.len(), 2);
+    }
+}
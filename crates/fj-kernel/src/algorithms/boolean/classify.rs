@@ -0,0 +1,135 @@
+//! Classification of points relative to a solid
+//!
+//! See [`PointClassifier`].
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+/// Classifies points as being inside or outside of a closed triangle mesh
+///
+/// Uses ray casting, counting how many times a ray starting at the point
+/// crosses the mesh. Rays that pass too close to a triangle's edge would make
+/// the result ambiguous. In that case, another ray direction is tried.
+pub struct PointClassifier {
+    triangles: Vec<Triangle<3>>,
+}
+
+impl PointClassifier {
+    /// Create a classifier from the triangles of a closed mesh
+    pub fn new(triangles: impl IntoIterator<Item = Triangle<3>>) -> Self {
+        Self {
+            triangles: triangles.into_iter().collect(),
+        }
+    }
+
+    /// Determine whether a point is inside of the mesh
+    pub fn is_inside(&self, point: Point<3>) -> bool {
+        // These directions are arbitrary. They are chosen to be unlikely to
+        // align with any features of typical models.
+        const DIRECTIONS: [[f64; 3]; 5] = [
+            [0.5773, 0.6031, 0.5504],
+            [-0.3127, 0.8379, 0.4471],
+            [0.7123, -0.2357, 0.6614],
+            [-0.6211, -0.4127, 0.6663],
+            [0.1234, 0.3457, -0.9302],
+        ];
+
+        let mut is_inside = false;
+
+        for direction in DIRECTIONS {
+            let direction = Vector::from(direction).normalize();
+
+            let mut num_hits = 0;
+            let mut is_ambiguous = false;
+
+            for triangle in &self.triangles {
+                match cast_ray(point, direction, triangle) {
+                    RayHit::Hit => num_hits += 1,
+                    RayHit::Miss => {}
+                    RayHit::Ambiguous => {
+                        is_ambiguous = true;
+                        break;
+                    }
+                }
+            }
+
+            is_inside = num_hits % 2 == 1;
+            if !is_ambiguous {
+                break;
+            }
+        }
+
+        is_inside
+    }
+}
+
+enum RayHit {
+    Hit,
+    Miss,
+    Ambiguous,
+}
+
+fn cast_ray(
+    origin: Point<3>,
+    direction: Vector<3>,
+    triangle: &Triangle<3>,
+) -> RayHit {
+    // Möller–Trumbore intersection algorithm
+    let [a, b, c] = triangle.points();
+    let ab = b - a;
+    let ac = c - a;
+
+    let h = direction.cross(&ac);
+    let det = ab.dot(&h);
+
+    let scale = ab.magnitude() * ac.magnitude();
+    if det.abs() <= Scalar::from_f64(1e-12) * scale {
+        // The ray is parallel to the triangle.
+        return RayHit::Miss;
+    }
+
+    let s = origin - a;
+    let u = s.dot(&h) / det;
+    let q = s.cross(&ab);
+    let v = direction.dot(&q) / det;
+    let t = ac.dot(&q) / det;
+
+    let margin = Scalar::from_f64(1e-9);
+
+    if t <= margin * scale.sqrt() {
+        return RayHit::Miss;
+    }
+
+    let barycentric_min = u.min(v).min(Scalar::ONE - u - v);
+    if barycentric_min < -margin {
+        return RayHit::Miss;
+    }
+    if barycentric_min <= margin {
+        return RayHit::Ambiguous;
+    }
+
+    RayHit::Hit
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Triangle};
+
+    use super::PointClassifier;
+
+    #[test]
+    fn tetrahedron() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+        let classifier = PointClassifier::new(
+            [[a, c, b], [a, b, d], [a, d, c], [b, c, d]].map(Triangle::from),
+        );
+
+        assert!(classifier.is_inside(Point::from([0.1, 0.1, 0.1])));
+        assert!(!classifier.is_inside(Point::from([1., 1., 1.])));
+
+        // Rays from this point go right through edges and vertices of the
+        // mesh, at least in some directions.
+        assert!(classifier.is_inside(Point::from([0.25, 0.25, 0.25])));
+    }
+}
@@ -0,0 +1,113 @@
+//! Loops of edges, on their way to become cycles
+//!
+//! See [`LoopEdge`].
+
+use fj_math::{Point, Scalar};
+
+use crate::geometry::curve::Curve;
+
+use super::{
+    arrangement::{Arrangement, DirectedEdge},
+    profile::{angle_of, ProfileSegment},
+    BooleanError,
+};
+
+/// An edge of a region, ready to be converted into a half-edge
+#[derive(Clone, Copy)]
+pub struct LoopEdge<T> {
+    /// The start and end vertices, as indices into the caller's vertices
+    pub vertices: [usize; 2],
+
+    /// The segment that describes the edge
+    pub segment: ProfileSegment,
+
+    /// The source of the edge, as tagged in the arrangement
+    pub source: T,
+
+    /// The range of the edge on the source, in segment parameters
+    pub range: [Scalar; 2],
+}
+
+impl<T: Copy> LoopEdge<T> {
+    /// Create a loop edge from an edge of an arrangement
+    pub fn new(arrangement: &Arrangement<T>, edge: DirectedEdge) -> Self {
+        let source = &arrangement.edges[edge.index];
+        let vertices = arrangement.edge_vertices(edge).map(|vertex| vertex.id);
+        let [a, b] = source.range;
+
+        Self {
+            vertices,
+            segment: arrangement.segment(edge),
+            source: source.tag,
+            range: if edge.reversed { [b, a] } else { [a, b] },
+        }
+    }
+
+    /// Reverse the direction of the edge
+    pub fn reverse(self) -> Self {
+        let [a, b] = self.vertices;
+        let [start, end] = self.range;
+
+        Self {
+            vertices: [b, a],
+            segment: self.segment.reverse(),
+            range: [end, start],
+            ..self
+        }
+    }
+
+    /// Indicate whether the edge covers its source completely
+    pub fn is_full(&self) -> bool {
+        self.range == [Scalar::ZERO, Scalar::ONE]
+            || self.range == [Scalar::ONE, Scalar::ZERO]
+    }
+
+    /// Map the range of the edge to a boundary on the source's curve
+    ///
+    /// The ends of the source's boundary are reproduced exactly, as opposed to
+    /// being recomputed from the range.
+    pub fn boundary(&self, source: [Point<1>; 2]) -> [Point<1>; 2] {
+        let [start, end] = source.map(|point| point.t);
+
+        self.range.map(|s| {
+            let t = if s == Scalar::ZERO {
+                start
+            } else if s == Scalar::ONE {
+                end
+            } else {
+                start + (end - start) * s
+            };
+            Point::from([t])
+        })
+    }
+}
+
+/// Create a segment from a curve, within the given range in curve coordinates
+///
+/// Returns an error, if the curve can't be represented by a segment. This is
+/// the case for Bézier curves and ellipses, which callers might approximate
+/// instead.
+pub fn segment_from_curve(
+    curve: &Curve,
+    range: [Scalar; 2],
+) -> Result<ProfileSegment, BooleanError> {
+    let segment = match curve {
+        Curve::Line(_) => ProfileSegment::Line {
+            points: range.map(|t| curve.point_from_path_coords([t])),
+        },
+        Curve::Circle(circle) => {
+            let start = angle_of(circle.a());
+            let direction = circle.a().cross2d(&circle.b()).sign().to_scalar();
+
+            ProfileSegment::arc(
+                circle.center(),
+                circle.radius(),
+                range.map(|t| start + direction * t),
+            )
+        }
+        Curve::Bezier(_) => return Err(BooleanError::BezierCurve),
+        Curve::Ellipse(_) => return Err(BooleanError::Ellipse),
+    };
+
+    Ok(segment)
+}
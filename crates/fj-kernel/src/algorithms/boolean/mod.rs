@@ -0,0 +1,606 @@
+//! Boolean operations on objects
+//!
+//! See [`Boolean`].
+
+mod arrangement;
+mod classify;
+mod loops;
+mod profile;
+mod sketch;
+mod solid;
+
+use crate::{
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    services::Service,
+    storage::Handle,
+};
+
+use super::approx::Tolerance;
+
+/// A boolean operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum BooleanOp {
+    /// The union of both objects
+    Union,
+
+    /// The first object, with the second one subtracted from it
+    Difference,
+
+    /// The intersection of both objects
+    Intersection,
+}
+
+/// Compute a boolean operation between two objects
+///
+/// Sketches with Bézier curves or ellipses are approximated, while solids with
+/// Bézier curves, ellipses, or faces that aren't planes or cylinders result in
+/// a [`BooleanError`].
+pub trait Boolean: Sized {
+    /// The object that results from the boolean operation
+    type Output;
+
+    /// Compute the boolean operation between this object and another
+    ///
+    /// The tolerance is used to approximate curved geometry, where the
+    /// algorithm relies on approximations.
+    fn boolean(
+        self,
+        op: BooleanOp,
+        other: Self,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output;
+}
+
+impl Boolean for Handle<Solid> {
+    type Output = Result<Handle<Solid>, BooleanError>;
+
+    fn boolean(
+        self,
+        op: BooleanOp,
+        other: Self,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output {
+        let solid = solid::solid_boolean(
+            op,
+            [&self, &other],
+            tolerance.into(),
+            objects,
+        )?;
+        Ok(solid.insert(objects))
+    }
+}
+
+impl Boolean for Handle<Sketch> {
+    type Output = Result<Handle<Sketch>, BooleanError>;
+
+    fn boolean(
+        self,
+        op: BooleanOp,
+        other: Self,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output {
+        let sketch = sketch::sketch_boolean(
+            op,
+            [&self, &other],
+            tolerance.into(),
+            objects,
+        )?;
+        Ok(sketch.insert(objects))
+    }
+}
+
+/// A boolean operation between objects that is not supported
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BooleanError {
+    /// A face of a solid is bounded by a Bézier curve
+    #[error(
+        "Boolean operations on solids with Bézier curves are not supported"
+    )]
+    BezierCurve,
+
+    /// A face of a solid is bounded by an ellipse, or is an elliptic cylinder
+    #[error("Boolean operations on solids with ellipses are not supported")]
+    Ellipse,
+
+    /// A face of a solid is a free-form surface
+    #[error("Boolean operations on free-form surfaces are not supported")]
+    FreeFormSurface,
+
+    /// The faces of sketches are not all in the same surface
+    #[error(
+        "Boolean operations on sketches in different surfaces are not \
+        supported"
+    )]
+    DifferentSurfaces,
+
+    /// A face is in a ruled surface, like the side of a loft
+    #[error("Boolean operations on ruled surfaces are not supported")]
+    RuledSurface,
+
+    /// A face is in a revolved surface, like the side of a cone
+    #[error(
+        "Boolean operations on revolved surfaces, like those of revolves, \
+        cones, spheres, and tori, are not supported"
+    )]
+    RevolvedSurface,
+
+    /// Faces of both solids cross each other, in a way that can't be computed
+    #[error(
+        "Boolean operations between these kinds of intersecting surfaces are \
+        not supported"
+    )]
+    UnsupportedIntersection,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, sweep::Sweep, transform::TransformObject,
+            triangulate::Triangulate,
+        },
+        builder::{CycleBuilder, HalfEdgeBuilder, SolidBuilder},
+        insert::Insert,
+        objects::{Objects, Sketch, Solid},
+        partial::{
+            Partial, PartialCycle, PartialFace, PartialObject, PartialSolid,
+        },
+        services::{Service, Services},
+        storage::Handle,
+        test_helpers::{circle, polygon, volume},
+    };
+
+    use super::{Boolean, BooleanError, BooleanOp};
+
+    #[test]
+    fn overlapping_cubes() {
+        let mut services = Services::new();
+
+        let expected = [
+            (BooleanOp::Union, 1.875),
+            (BooleanOp::Difference, 0.875),
+            (BooleanOp::Intersection, 0.125),
+        ];
+        for (op, volume) in expected {
+            let a = cuboid([0., 0., 0.], [1., 1., 1.], &mut services.objects);
+            let b =
+                cuboid([0.5, 0.5, 0.5], [1., 1., 1.], &mut services.objects);
+
+            let result =
+                a.boolean(op, b, 0.001, &mut services.objects).unwrap();
+            assert_eq!(mesh_volume(&result, 0.001), Scalar::from(volume));
+        }
+    }
+
+    #[test]
+    fn cubes_sharing_face() {
+        let mut services = Services::new();
+
+        let a = cuboid([0., 0., 0.], [1., 1., 1.], &mut services.objects);
+        let b = cuboid([1., 0., 0.], [1., 1., 1.], &mut services.objects);
+
+        let union = a
+            .boolean(BooleanOp::Union, b, 0.001, &mut services.objects)
+            .unwrap();
+        assert_eq!(mesh_volume(&union, 0.001), Scalar::from(2.));
+    }
+
+    #[test]
+    fn cube_with_cylindrical_hole() {
+        let mut services = Services::new();
+
+        let cube = cuboid([-1., -1., 0.], [2., 2., 1.], &mut services.objects);
+        let cylinder = cylinder([0., 0., -0.5], 0.5, 2., &mut services.objects);
+
+        let tolerance = 0.0001;
+        let difference = cube
+            .boolean(
+                BooleanOp::Difference,
+                cylinder,
+                tolerance,
+                &mut services.objects,
+            )
+            .unwrap();
+
+        let volume = mesh_volume(&difference, tolerance);
+        let expected = Scalar::from(4.) - Scalar::PI * 0.25;
+        assert!((volume - expected).abs() < Scalar::from(0.001));
+    }
+
+    #[test]
+    fn cylinder_crossing_side_of_cube() {
+        let mut services = Services::new();
+
+        let cube = cuboid([-1., -1., 0.], [2., 2., 1.], &mut services.objects);
+        let cylinder =
+            cylinder([1., 0., 0.25], 0.5, 0.5, &mut services.objects);
+
+        let tolerance = 0.0001;
+        let union = cube
+            .boolean(
+                BooleanOp::Union,
+                cylinder,
+                tolerance,
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // Half of the cylinder sticks out of the cube.
+        let volume = mesh_volume(&union, tolerance);
+        let expected = Scalar::from(4.) + Scalar::PI * 0.25 * 0.5 / 2.;
+        assert!((volume - expected).abs() < Scalar::from(0.001));
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let mut services = Services::new();
+
+        let expected = [
+            (BooleanOp::Union, 1.75),
+            (BooleanOp::Difference, 0.75),
+            (BooleanOp::Intersection, 0.25),
+        ];
+        for (op, area) in expected {
+            let a = square([0., 0.], [1., 1.], &mut services.objects);
+            let b = square([0.5, 0.5], [1., 1.], &mut services.objects);
+
+            let result =
+                a.boolean(op, b, 0.001, &mut services.objects).unwrap();
+            assert_eq!(result.faces().into_iter().count(), 1);
+            assert_eq!(mesh_area(&result, 0.001), Scalar::from(area));
+        }
+    }
+
+    #[test]
+    fn disjoint_squares() {
+        let mut services = Services::new();
+
+        let a = square([0., 0.], [1., 1.], &mut services.objects);
+        let b = square([2., 0.], [1., 1.], &mut services.objects);
+
+        let union = a
+            .clone()
+            .boolean(BooleanOp::Union, b.clone(), 0.001, &mut services.objects)
+            .unwrap();
+        assert_eq!(union.faces().into_iter().count(), 2);
+        assert_eq!(mesh_area(&union, 0.001), Scalar::from(2.));
+
+        let difference = a
+            .clone()
+            .boolean(
+                BooleanOp::Difference,
+                b.clone(),
+                0.001,
+                &mut services.objects,
+            )
+            .unwrap();
+        assert_eq!(difference.faces(), a.faces());
+
+        let intersection = a
+            .boolean(BooleanOp::Intersection, b, 0.001, &mut services.objects)
+            .unwrap();
+        assert_eq!(intersection.faces().into_iter().count(), 0);
+    }
+
+    #[test]
+    fn squares_in_different_surfaces() {
+        let mut services = Services::new();
+
+        let a = square([0., 0.], [1., 1.], &mut services.objects);
+        let b = square([0., 0.], [1., 1.], &mut services.objects)
+            .translate([0., 0., 1.], &mut services.objects);
+
+        let result =
+            a.boolean(BooleanOp::Union, b, 0.001, &mut services.objects);
+        assert_eq!(result, Err(BooleanError::DifferentSurfaces));
+    }
+
+    #[test]
+    fn difference_with_multiple_exteriors() {
+        let mut services = Services::new();
+
+        let a = square([0., 0.], [3., 1.], &mut services.objects);
+        let b = square([1., -1.], [1., 3.], &mut services.objects);
+
+        let difference = a
+            .boolean(BooleanOp::Difference, b, 0.001, &mut services.objects)
+            .unwrap();
+        assert_eq!(difference.faces().into_iter().count(), 2);
+        assert_eq!(mesh_area(&difference, 0.001), Scalar::from(2.));
+    }
+
+    #[test]
+    fn square_with_circular_hole() {
+        let mut services = Services::new();
+
+        let square = square([-1., -1.], [2., 2.], &mut services.objects);
+        let circle = circle(0.5, &mut services.objects);
+
+        let tolerance = 0.0001;
+        let difference = square
+            .boolean(
+                BooleanOp::Difference,
+                circle,
+                tolerance,
+                &mut services.objects,
+            )
+            .unwrap();
+        assert_eq!(difference.faces().into_iter().count(), 1);
+
+        let face = difference.faces().into_iter().next().unwrap();
+        assert_eq!(face.interiors().count(), 1);
+
+        let area = mesh_area(&difference, tolerance);
+        let expected = Scalar::from(4.) - Scalar::PI * 0.25;
+        assert!((area - expected).abs() < Scalar::from(0.001));
+    }
+
+    #[test]
+    fn circle_crossing_side_of_square() {
+        let mut services = Services::new();
+
+        // The square covers half of the circle.
+        let half_circle = Scalar::PI * 0.25 / 2.;
+        let expected = [
+            (BooleanOp::Union, Scalar::from(4.) + half_circle),
+            (BooleanOp::Difference, half_circle),
+            (BooleanOp::Intersection, half_circle),
+        ];
+        for (op, area) in expected {
+            let circle = circle(0.5, &mut services.objects);
+            let square = square([0., -1.], [2., 2.], &mut services.objects);
+
+            let tolerance = 0.0001;
+            let result = circle
+                .boolean(op, square, tolerance, &mut services.objects)
+                .unwrap();
+            assert_eq!(result.faces().into_iter().count(), 1);
+
+            let difference = mesh_area(&result, tolerance) - area;
+            assert!(difference.abs() < Scalar::from(0.001));
+        }
+    }
+
+    #[test]
+    fn bezier_crossing_side_of_square() {
+        let mut services = Services::new();
+
+        let tolerance = 0.0001;
+        let area = |sketch: &Handle<Sketch>| mesh_area(sketch, tolerance);
+
+        let [bezier, square] = [
+            bezier(&mut services.objects),
+            square([1., -1.], [4., 4.], &mut services.objects),
+        ];
+        let [union, difference, intersection] = [
+            BooleanOp::Union,
+            BooleanOp::Difference,
+            BooleanOp::Intersection,
+        ]
+        .map(|op| {
+            bezier
+                .clone()
+                .boolean(op, square.clone(), tolerance, &mut services.objects)
+                .unwrap()
+        });
+
+        // The area that is enclosed by the curve is 4.8.
+        assert!(
+            (area(&bezier) - Scalar::from(4.8)).abs() < Scalar::from(0.001)
+        );
+
+        let [bezier, square] = [area(&bezier), area(&square)];
+        let epsilon = Scalar::from(0.001);
+        assert!(
+            (area(&union) + area(&intersection) - bezier - square).abs()
+                < epsilon
+        );
+        assert!(
+            (area(&difference) + area(&intersection) - bezier).abs() < epsilon
+        );
+    }
+
+    #[test]
+    fn solid_with_bezier_curves() {
+        let mut services = Services::new();
+
+        let cube = cuboid([0., 0., 0.], [1., 1., 1.], &mut services.objects);
+        let swept_bezier = bezier(&mut services.objects)
+            .sweep([0., 0., 1.], &mut services.objects);
+
+        let result = cube.boolean(
+            BooleanOp::Difference,
+            swept_bezier,
+            0.001,
+            &mut services.objects,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn ellipse_overlapping_square() {
+        let mut services = Services::new();
+
+        let tolerance = 0.0001;
+        let area = |sketch: &Handle<Sketch>| mesh_area(sketch, tolerance);
+
+        // The square covers the right half of the ellipse.
+        let [ellipse, square] = [
+            ellipse([2., 1.], &mut services.objects),
+            square([0., -2.], [4., 4.], &mut services.objects),
+        ];
+        let [union, difference, intersection] = [
+            BooleanOp::Union,
+            BooleanOp::Difference,
+            BooleanOp::Intersection,
+        ]
+        .map(|op| {
+            ellipse
+                .clone()
+                .boolean(op, square.clone(), tolerance, &mut services.objects)
+                .unwrap()
+        });
+
+        let epsilon = Scalar::from(0.001);
+        let [ellipse, square] = [area(&ellipse), area(&square)];
+        assert!((area(&intersection) - Scalar::PI).abs() < epsilon);
+        assert!((area(&difference) - Scalar::PI).abs() < epsilon);
+        assert!(
+            (area(&union) + area(&intersection) - ellipse - square).abs()
+                < epsilon
+        );
+    }
+
+    #[test]
+    fn solid_with_ellipses() {
+        let mut services = Services::new();
+
+        let cube = cuboid([0., 0., 0.], [1., 1., 1.], &mut services.objects);
+        let elliptic_cylinder = ellipse([2., 1.], &mut services.objects)
+            .sweep([0., 0., 1.], &mut services.objects);
+
+        let result = cube.boolean(
+            BooleanOp::Difference,
+            elliptic_cylinder,
+            0.001,
+            &mut services.objects,
+        );
+        assert_eq!(result, Err(BooleanError::Ellipse));
+    }
+
+    #[test]
+    fn solid_with_revolved_surfaces() {
+        let mut services = Services::new();
+
+        let cube = cuboid([0., 0., 0.], [1., 1., 1.], &mut services.objects);
+
+        let mut sphere = PartialSolid::default();
+        sphere.update_as_sphere(1.);
+        let sphere = sphere
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let result = cube.boolean(
+            BooleanOp::Union,
+            sphere,
+            0.001,
+            &mut services.objects,
+        );
+        assert_eq!(result, Err(BooleanError::RevolvedSurface));
+    }
+
+    fn cuboid(
+        min: [f64; 3],
+        size: [f64; 3],
+        objects: &mut Service<Objects>,
+    ) -> Handle<Solid> {
+        let [x, y, z] = size;
+
+        polygon([[0., 0.], [x, 0.], [x, y], [0., y]], objects)
+            .sweep([0., 0., z], objects)
+            .translate(min, objects)
+    }
+
+    fn cylinder(
+        bottom: [f64; 3],
+        radius: f64,
+        height: f64,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Solid> {
+        circle(radius, objects)
+            .sweep([0., 0., height], objects)
+            .translate(bottom, objects)
+    }
+
+    fn square(
+        min: [f64; 2],
+        size: [f64; 2],
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        let [x, y] = min;
+        let [w, h] = size;
+
+        let mut face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        face.exterior.write().update_as_polygon_from_points([
+            [x, y],
+            [x + w, y],
+            [x + w, y + h],
+            [x, y + h],
+        ]);
+        let face = face.build(objects).insert(objects);
+
+        Sketch::new([face]).insert(objects)
+    }
+
+    fn ellipse(
+        radii: [f64; 2],
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        let mut cycle = PartialCycle::default();
+        let mut half_edge = cycle.add_half_edge();
+        half_edge
+            .write()
+            .update_as_ellipse_from_center_and_radii([0., 0.], radii);
+
+        let face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            exterior: Partial::from_partial(cycle),
+            ..Default::default()
+        };
+        let face = face.build(objects).insert(objects);
+
+        Sketch::new([face]).insert(objects)
+    }
+
+    fn bezier(objects: &mut Service<Objects>) -> Handle<Sketch> {
+        let mut face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        let [mut curve, _] = face
+            .exterior
+            .write()
+            .update_as_polygon_from_points([[0., 0.], [0., 2.]]);
+        curve.write().update_as_bezier([[4., 0.], [4., 2.]]);
+        let face = face.build(objects).insert(objects);
+
+        Sketch::new([face]).insert(objects)
+    }
+
+    fn mesh_area(
+        sketch: &Handle<Sketch>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Scalar {
+        let mesh = (&**sketch, tolerance.into()).triangulate();
+
+        let area = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).z
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+
+        // Rounding hides numerical noise from the triangulation.
+        (area / 2. * 1e9).round() / 1e9
+    }
+
+    fn mesh_volume(
+        solid: &Handle<Solid>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Scalar {
+        let volume = volume(solid, tolerance);
+
+        // Rounding hides numerical noise from the triangulation.
+        (volume * 1e9).round() / 1e9
+    }
+}
@@ -0,0 +1,850 @@
+//! Boundary geometry in surface coordinates
+//!
+//! The boolean operations split faces in the 2-dimensional coordinate system of
+//! their surfaces. This module provides the geometry that is required for
+//! that: line segments and circular arcs, and the closed profiles that are
+//! made up of them.
+
+use fj_math::{Line, Point, Scalar, Vector};
+
+/// A segment of a profile, in surface coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProfileSegment {
+    /// A straight line segment
+    Line {
+        /// The start and end points of the segment
+        points: [Point<2>; 2],
+    },
+
+    /// A circular arc
+    Arc {
+        /// The start and end points of the arc
+        points: [Point<2>; 2],
+
+        /// The center of the circle the arc is on
+        center: Point<2>,
+
+        /// The radius of the circle the arc is on
+        radius: Scalar,
+
+        /// The angles of the start and end points, relative to the center
+        ///
+        /// The arc goes counter-clockwise, if the end angle is larger than the
+        /// start angle, clockwise otherwise.
+        angles: [Scalar; 2],
+    },
+}
+
+impl ProfileSegment {
+    /// Create an arc from its center, radius, and start and end angles
+    pub fn arc(center: Point<2>, radius: Scalar, angles: [Scalar; 2]) -> Self {
+        let points = angles.map(|angle| point_on_circle(center, radius, angle));
+        Self::Arc {
+            points,
+            center,
+            radius,
+            angles,
+        }
+    }
+
+    /// Access the start and end points of the segment
+    pub fn points(&self) -> [Point<2>; 2] {
+        match self {
+            Self::Line { points } | Self::Arc { points, .. } => *points,
+        }
+    }
+
+    /// Indicate whether the segment forms a full circle
+    pub fn is_full_circle(&self) -> bool {
+        match self {
+            Self::Line { .. } => false,
+            Self::Arc { angles: [a, b], .. } => {
+                (*b - *a).abs() >= Scalar::TAU - Scalar::from_f64(1e-12)
+            }
+        }
+    }
+
+    /// Create a reversed version of this segment
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Line { points: [a, b] } => Self::Line { points: [b, a] },
+            Self::Arc {
+                points: [a, b],
+                center,
+                radius,
+                angles: [angle_a, angle_b],
+            } => Self::Arc {
+                points: [b, a],
+                center,
+                radius,
+                angles: [angle_b, angle_a],
+            },
+        }
+    }
+
+    /// Create a segment that is a part of this one
+    ///
+    /// `range` is given in segment parameters (see [`Self::point_at`]),
+    /// `points` are the exact start and end points of the new segment.
+    pub fn sub_segment(
+        &self,
+        range: [Scalar; 2],
+        points: [Point<2>; 2],
+    ) -> Self {
+        match self {
+            Self::Line { .. } => Self::Line { points },
+            Self::Arc {
+                center,
+                radius,
+                angles: [a, b],
+                ..
+            } => {
+                let sweep = *b - *a;
+                Self::Arc {
+                    points,
+                    center: *center,
+                    radius: *radius,
+                    angles: range.map(|s| *a + sweep * s),
+                }
+            }
+        }
+    }
+
+    /// Compute the point at the given segment parameter
+    ///
+    /// The segment parameter is `0` at the start of the segment and `1` at its
+    /// end.
+    pub fn point_at(&self, s: impl Into<Scalar>) -> Point<2> {
+        let s = s.into();
+        let [a, b] = self.points();
+
+        if s == Scalar::ZERO {
+            return a;
+        }
+        if s == Scalar::ONE {
+            return b;
+        }
+
+        match self {
+            Self::Line { .. } => a + (b - a) * s,
+            Self::Arc {
+                center,
+                radius,
+                angles: [angle_a, angle_b],
+                ..
+            } => point_on_circle(
+                *center,
+                *radius,
+                *angle_a + (*angle_b - *angle_a) * s,
+            ),
+        }
+    }
+
+    /// Compute the normalized tangent at the given segment parameter
+    pub fn tangent_at(&self, s: impl Into<Scalar>) -> Vector<2> {
+        match self {
+            Self::Line { points: [a, b] } => (*b - *a).normalize(),
+            Self::Arc {
+                angles: [angle_a, angle_b],
+                ..
+            } => {
+                let sweep = *angle_b - *angle_a;
+                let angle = *angle_a + sweep * s.into();
+                let (sin, cos) = angle.sin_cos();
+
+                Vector::from([-sin, cos]) * sweep.sign().to_scalar()
+            }
+        }
+    }
+
+    /// The signed curvature of the segment
+    ///
+    /// Positive, if the segment turns left. Negative, if it turns right.
+    pub fn curvature(&self) -> Scalar {
+        match self {
+            Self::Line { .. } => Scalar::ZERO,
+            Self::Arc {
+                radius,
+                angles: [a, b],
+                ..
+            } => (*b - *a).sign().to_scalar() / *radius,
+        }
+    }
+
+    /// The length of the segment
+    pub fn length(&self) -> Scalar {
+        match self {
+            Self::Line { points: [a, b] } => (*b - *a).magnitude(),
+            Self::Arc {
+                radius,
+                angles: [a, b],
+                ..
+            } => (*b - *a).abs() * *radius,
+        }
+    }
+
+    /// Compute an axis-aligned bounding box of the segment, as `[min, max]`
+    ///
+    /// The bounding box of an arc is conservative, covering the whole circle.
+    pub fn bounding_box(&self) -> [Point<2>; 2] {
+        match self {
+            Self::Line { points: [a, b] } => [
+                Point::from([a.u.min(b.u), a.v.min(b.v)]),
+                Point::from([a.u.max(b.u), a.v.max(b.v)]),
+            ],
+            Self::Arc { center, radius, .. } => [
+                *center - Vector::from([*radius, *radius]),
+                *center + Vector::from([*radius, *radius]),
+            ],
+        }
+    }
+
+    /// The signed area that this segment contributes to a closed profile
+    ///
+    /// Summing up this value over all segments of a closed profile results in
+    /// the area that the profile encloses. The area is positive, if the
+    /// profile is counter-clockwise.
+    pub fn area_contribution(&self) -> Scalar {
+        let [a, b] = self.points();
+        let chord = (a.u * b.v - b.u * a.v) / 2.;
+
+        match self {
+            Self::Line { .. } => chord,
+            Self::Arc {
+                radius,
+                angles: [angle_a, angle_b],
+                ..
+            } => {
+                let sweep = *angle_b - *angle_a;
+                let (sin, _) = sweep.sin_cos();
+
+                chord + *radius * *radius / 2. * (sweep - sin)
+            }
+        }
+    }
+
+    /// Compute the angle that the segment subtends, as seen from a point
+    ///
+    /// Summing up this value over all segments of a closed profile results in
+    /// `2 * PI` times the winding number of the profile around the point.
+    pub fn winding_angle(&self, point: Point<2>) -> Scalar {
+        let [a, b] = self.points();
+        let chord_angle = {
+            let a = a - point;
+            let b = b - point;
+            a.cross2d(&b).atan2(a.dot(&b))
+        };
+
+        match self {
+            Self::Line { .. } => chord_angle,
+            Self::Arc {
+                center,
+                radius,
+                angles: [angle_a, angle_b],
+                ..
+            } => {
+                // The arc, together with its chord in the reverse direction,
+                // forms a closed profile. If the point is within that profile,
+                // the arc contributes another full turn on top of the angle of
+                // the chord.
+                let sweep = *angle_b - *angle_a;
+
+                if (point - *center).magnitude() >= *radius {
+                    return chord_angle;
+                }
+
+                let is_within_segment = if self.is_full_circle() {
+                    true
+                } else {
+                    let middle = self.point_at(0.5);
+                    let side_of_point = (b - a).cross2d(&(point - a));
+                    let side_of_middle = (b - a).cross2d(&(middle - a));
+
+                    side_of_point.sign() == side_of_middle.sign()
+                };
+
+                if is_within_segment {
+                    chord_angle + Scalar::TAU * sweep.sign().to_scalar()
+                } else {
+                    chord_angle
+                }
+            }
+        }
+    }
+
+    /// Compute the distance between the segment and a point
+    pub fn distance_to(&self, point: Point<2>) -> Scalar {
+        let [a, b] = self.points();
+
+        match self {
+            Self::Line { .. } => {
+                let ab = b - a;
+                let length_squared = ab.dot(&ab);
+                if length_squared == Scalar::ZERO {
+                    return (point - a).magnitude();
+                }
+
+                let t = ((point - a).dot(&ab) / length_squared)
+                    .max(Scalar::ZERO)
+                    .min(Scalar::ONE);
+                (point - (a + ab * t)).magnitude()
+            }
+            Self::Arc { center, radius, .. } => {
+                let angle = angle_of(point - *center);
+                if self.arc_parameter(angle, Scalar::ZERO).is_some() {
+                    ((point - *center).magnitude() - *radius).abs()
+                } else {
+                    (point - a).magnitude().min((point - b).magnitude())
+                }
+            }
+        }
+    }
+
+    /// Compute the parameter of a point that lies on the segment
+    ///
+    /// Returns `None`, if the point is further than `epsilon` from the segment.
+    /// The returned parameter is clamped to the range `[0, 1]`.
+    pub fn locate(&self, point: Point<2>, epsilon: Scalar) -> Option<Scalar> {
+        let [a, b] = self.points();
+
+        match self {
+            Self::Line { .. } => {
+                let ab = b - a;
+                let length = ab.magnitude();
+                let t = (point - a).dot(&ab) / (length * length);
+
+                let tolerance = epsilon / length;
+                if t < -tolerance || t > Scalar::ONE + tolerance {
+                    return None;
+                }
+
+                let t = t.max(Scalar::ZERO).min(Scalar::ONE);
+                if (point - (a + ab * t)).magnitude() > epsilon {
+                    return None;
+                }
+
+                Some(t)
+            }
+            Self::Arc { center, radius, .. } => {
+                if ((point - *center).magnitude() - *radius).abs() > epsilon {
+                    return None;
+                }
+
+                self.arc_parameter(angle_of(point - *center), epsilon)
+            }
+        }
+    }
+
+    /// Compute the intersections between this segment and another
+    ///
+    /// Returns the segment parameters of each intersection, on this segment and
+    /// the other one. Overlapping segments are not detected here. Those need
+    /// to be handled by locating the segments' end points on each other.
+    pub fn intersect(
+        &self,
+        other: &Self,
+        epsilon: Scalar,
+    ) -> Vec<(Scalar, Scalar)> {
+        let points = match (self, other) {
+            (Self::Line { points }, Self::Line { points: other }) => {
+                line_line_intersection(*points, *other)
+            }
+            (Self::Line { points }, Self::Arc { center, radius, .. })
+            | (Self::Arc { center, radius, .. }, Self::Line { points }) => {
+                line_circle_intersection(*points, *center, *radius, epsilon)
+            }
+            (
+                Self::Arc { center, radius, .. },
+                Self::Arc {
+                    center: other_center,
+                    radius: other_radius,
+                    ..
+                },
+            ) => circle_circle_intersection(
+                [*center, *other_center],
+                [*radius, *other_radius],
+                epsilon,
+            ),
+        };
+
+        points
+            .into_iter()
+            .filter_map(|point| {
+                let s = self.locate(point, epsilon)?;
+                let t = other.locate(point, epsilon)?;
+                Some((s, t))
+            })
+            .collect()
+    }
+
+    /// Compute where a line crosses the segment, in line coordinates
+    ///
+    /// If the line is coincident with a line segment, the line coordinates of
+    /// both end points of the segment are returned.
+    pub fn intersect_line(
+        &self,
+        line: &Line<2>,
+        epsilon: Scalar,
+    ) -> Vec<Scalar> {
+        let origin = line.origin();
+        let direction = line.direction();
+        let far = [origin, origin + direction];
+
+        let points = match self {
+            Self::Line { points: [a, b] } => {
+                let ab = *b - *a;
+                let denom = direction.cross2d(&ab);
+
+                if denom.abs()
+                    <= Scalar::from_f64(1e-12)
+                        * direction.magnitude()
+                        * ab.magnitude()
+                {
+                    // The line and the segment are parallel.
+                    let is_coincident = [*a, *b]
+                        .into_iter()
+                        .all(|point| line_distance(far, point) <= epsilon);
+
+                    if is_coincident {
+                        vec![*a, *b]
+                    } else {
+                        vec![]
+                    }
+                } else {
+                    let s = (*a - origin).cross2d(&direction) / denom;
+                    vec![*a + ab * s]
+                }
+            }
+            Self::Arc { center, radius, .. } => {
+                line_circle_intersection(far, *center, *radius, epsilon)
+            }
+        };
+
+        points
+            .into_iter()
+            .filter(|point| self.locate(*point, epsilon).is_some())
+            .map(|point| line.point_to_line_coords(point).t)
+            .collect()
+    }
+
+    /// Convert an angle into a segment parameter
+    ///
+    /// Only valid for arcs. Returns `None`, if the angle is not on the arc.
+    fn arc_parameter(&self, angle: Scalar, epsilon: Scalar) -> Option<Scalar> {
+        let Self::Arc {
+            radius,
+            angles: [a, b],
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let sweep = *b - *a;
+        let magnitude = sweep.abs();
+        let tolerance = epsilon / *radius;
+
+        let mut offset = if sweep > Scalar::ZERO {
+            angle - *a
+        } else {
+            *a - angle
+        };
+        offset %= Scalar::TAU;
+        if offset < Scalar::ZERO {
+            offset += Scalar::TAU;
+        }
+        if offset > magnitude + tolerance && offset > Scalar::TAU - tolerance {
+            offset -= Scalar::TAU;
+        }
+
+        let s = offset / magnitude;
+        let tolerance = tolerance / magnitude;
+        if s < -tolerance || s > Scalar::ONE + tolerance {
+            return None;
+        }
+
+        Some(s.max(Scalar::ZERO).min(Scalar::ONE))
+    }
+}
+
+/// A closed profile made up of one or more loops of segments
+///
+/// The loops are expected to be oriented consistently, meaning the exterior
+/// loop and the interior loops must have opposite orientations.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    /// The loops that make up the profile
+    pub loops: Vec<Vec<ProfileSegment>>,
+}
+
+impl Profile {
+    /// Iterate over all segments of all loops
+    pub fn segments(&self) -> impl Iterator<Item = &ProfileSegment> + '_ {
+        self.loops.iter().flatten()
+    }
+
+    /// Determine whether the profile contains a point
+    pub fn contains(&self, point: Point<2>, epsilon: Scalar) -> Containment {
+        if self
+            .segments()
+            .any(|segment| segment.distance_to(point) <= epsilon)
+        {
+            return Containment::Boundary;
+        }
+
+        let angle = self
+            .segments()
+            .map(|segment| segment.winding_angle(point))
+            .fold(Scalar::ZERO, |a, b| a + b);
+        let winding_number = (angle / Scalar::TAU).round();
+
+        if winding_number == Scalar::ZERO {
+            Containment::Outside
+        } else {
+            Containment::Inside
+        }
+    }
+
+    /// Compute the intervals where a line is within the profile
+    ///
+    /// The intervals are returned in line coordinates, in ascending order. A
+    /// line that runs along the boundary of the profile is considered to be
+    /// within it.
+    pub fn line_intervals(
+        &self,
+        line: &Line<2>,
+        epsilon: Scalar,
+    ) -> Vec<[Scalar; 2]> {
+        let epsilon_on_line = epsilon / line.direction().magnitude();
+
+        let mut crossings = self
+            .segments()
+            .flat_map(|segment| segment.intersect_line(line, epsilon))
+            .collect::<Vec<_>>();
+        crossings.sort();
+        crossings.dedup_by(|b, a| (*b - *a).abs() <= epsilon_on_line);
+
+        let mut intervals: Vec<[Scalar; 2]> = Vec::new();
+        for window in crossings.windows(2) {
+            let [start, end] = [window[0], window[1]];
+
+            let middle = line.point_from_line_coords([(start + end) / 2.]);
+            if self.contains(middle, epsilon) == Containment::Outside {
+                continue;
+            }
+
+            match intervals.last_mut() {
+                Some([_, previous_end]) if *previous_end == start => {
+                    *previous_end = end;
+                }
+                _ => intervals.push([start, end]),
+            }
+        }
+
+        intervals
+    }
+
+    /// Compute the intervals where a segment is within the profile
+    ///
+    /// Works like [`Profile::line_intervals`], but for a bounded segment. The
+    /// intervals are returned in segment parameters.
+    pub fn segment_intervals(
+        &self,
+        probe: &ProfileSegment,
+        epsilon: Scalar,
+    ) -> Vec<[Scalar; 2]> {
+        let epsilon_on_segment = epsilon / probe.length();
+
+        let mut crossings = vec![Scalar::ZERO, Scalar::ONE];
+        for segment in self.segments() {
+            crossings.extend(
+                probe
+                    .intersect(segment, epsilon)
+                    .into_iter()
+                    .map(|(s, _)| s),
+            );
+            crossings.extend(
+                segment
+                    .points()
+                    .into_iter()
+                    .filter_map(|point| probe.locate(point, epsilon)),
+            );
+        }
+        crossings.sort();
+        crossings.dedup_by(|b, a| (*b - *a).abs() <= epsilon_on_segment);
+
+        let mut intervals: Vec<[Scalar; 2]> = Vec::new();
+        for window in crossings.windows(2) {
+            let [start, end] = [window[0], window[1]];
+
+            let middle = probe.point_at((start + end) / 2.);
+            if self.contains(middle, epsilon) == Containment::Outside {
+                continue;
+            }
+
+            match intervals.last_mut() {
+                Some([_, previous_end]) if *previous_end == start => {
+                    *previous_end = end;
+                }
+                _ => intervals.push([start, end]),
+            }
+        }
+
+        intervals
+    }
+
+    /// Find a point that is within the profile
+    ///
+    /// Expects the first loop to be the exterior loop, oriented
+    /// counter-clockwise.
+    pub fn interior_point(&self, epsilon: Scalar) -> Point<2> {
+        let exterior = self
+            .loops
+            .first()
+            .expect("Expected profile to have an exterior");
+
+        let mut candidates = exterior.iter().collect::<Vec<_>>();
+        candidates.sort_by_key(|segment| -segment.length());
+
+        // Start from the middle of a long segment and go inward, until we hit
+        // the boundary again. Then use the point in between.
+        for segment in candidates {
+            let start = segment.point_at(0.5);
+            let tangent = segment.tangent_at(0.5);
+            let normal = Vector::from([-tangent.v, tangent.u]);
+            let line = Line::from_origin_and_direction(start, normal);
+
+            let nearest_hit = self
+                .segments()
+                .flat_map(|segment| segment.intersect_line(&line, epsilon))
+                .filter(|&t| t > epsilon)
+                .min();
+
+            if let Some(t) = nearest_hit {
+                let point = line.point_from_line_coords([t / 2.]);
+                if self.contains(point, epsilon) == Containment::Inside {
+                    return point;
+                }
+            }
+        }
+
+        panic!("Could not find point within profile: {self:#?}");
+    }
+}
+
+/// The result of [`Profile::contains`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Containment {
+    /// The point is within the profile
+    Inside,
+
+    /// The point is on the boundary of the profile
+    Boundary,
+
+    /// The point is outside of the profile
+    Outside,
+}
+
+/// Compute the angle of a vector, relative to the u-axis
+pub fn angle_of(vector: Vector<2>) -> Scalar {
+    vector.v.atan2(vector.u)
+}
+
+fn point_on_circle(
+    center: Point<2>,
+    radius: Scalar,
+    angle: Scalar,
+) -> Point<2> {
+    let (sin, cos) = angle.sin_cos();
+    center + Vector::from([cos, sin]) * radius
+}
+
+fn line_distance([a, b]: [Point<2>; 2], point: Point<2>) -> Scalar {
+    let ab = b - a;
+    ab.cross2d(&(point - a)).abs() / ab.magnitude()
+}
+
+fn line_line_intersection(
+    [a, b]: [Point<2>; 2],
+    [c, d]: [Point<2>; 2],
+) -> Vec<Point<2>> {
+    let ab = b - a;
+    let cd = d - c;
+
+    let denom = ab.cross2d(&cd);
+    if denom.abs() <= Scalar::from_f64(1e-12) * ab.magnitude() * cd.magnitude()
+    {
+        // The lines are parallel. Any overlap is detected by the caller.
+        return vec![];
+    }
+
+    let t = (c - a).cross2d(&cd) / denom;
+    vec![a + ab * t]
+}
+
+fn line_circle_intersection(
+    [a, b]: [Point<2>; 2],
+    center: Point<2>,
+    radius: Scalar,
+    epsilon: Scalar,
+) -> Vec<Point<2>> {
+    let ab = b - a;
+    let length_squared = ab.dot(&ab);
+
+    // Find the point on the line that is closest to the center.
+    let t = (center - a).dot(&ab) / length_squared;
+    let closest = a + ab * t;
+    let distance = (center - closest).magnitude();
+
+    if distance > radius + epsilon {
+        return vec![];
+    }
+    if (distance - radius).abs() <= epsilon {
+        return vec![closest];
+    }
+
+    let half_chord = (radius * radius - distance * distance).sqrt();
+    let offset = ab / length_squared.sqrt() * half_chord;
+
+    vec![closest - offset, closest + offset]
+}
+
+fn circle_circle_intersection(
+    [a, b]: [Point<2>; 2],
+    [radius_a, radius_b]: [Scalar; 2],
+    epsilon: Scalar,
+) -> Vec<Point<2>> {
+    let ab = b - a;
+    let distance = ab.magnitude();
+
+    if distance <= epsilon {
+        // Concentric circles. If they are identical, any overlap is detected
+        // by the caller.
+        return vec![];
+    }
+    if distance > radius_a + radius_b + epsilon
+        || distance < (radius_a - radius_b).abs() - epsilon
+    {
+        return vec![];
+    }
+
+    let along = (radius_a * radius_a - radius_b * radius_b
+        + distance * distance)
+        / (distance * 2.);
+    let across_squared = radius_a * radius_a - along * along;
+
+    let direction = ab / distance;
+    let base = a + direction * along;
+
+    if across_squared <= epsilon * epsilon {
+        return vec![base];
+    }
+
+    let across = across_squared.sqrt();
+    let normal = Vector::from([-direction.v, direction.u]);
+
+    vec![base + normal * across, base - normal * across]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar};
+
+    use super::{Containment, Profile, ProfileSegment};
+
+    fn square() -> Profile {
+        let points = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]].map(Point::from);
+        let segments = (0..points.len())
+            .map(|i| ProfileSegment::Line {
+                points: [points[i], points[(i + 1) % points.len()]],
+            })
+            .collect();
+
+        Profile {
+            loops: vec![segments],
+        }
+    }
+
+    #[test]
+    fn contains() {
+        let epsilon = Scalar::from_f64(1e-9);
+        let square = square();
+
+        assert_eq!(
+            square.contains(Point::from([1., 1.]), epsilon),
+            Containment::Inside
+        );
+        assert_eq!(
+            square.contains(Point::from([2., 1.]), epsilon),
+            Containment::Boundary
+        );
+        assert_eq!(
+            square.contains(Point::from([3., 1.]), epsilon),
+            Containment::Outside
+        );
+
+        let circle = Profile {
+            loops: vec![vec![ProfileSegment::arc(
+                Point::from([0., 0.]),
+                Scalar::ONE,
+                [Scalar::ZERO, Scalar::TAU],
+            )]],
+        };
+        assert_eq!(
+            circle.contains(Point::from([0.5, 0.5]), epsilon),
+            Containment::Inside
+        );
+        assert_eq!(
+            circle.contains(Point::from([0.8, 0.8]), epsilon),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn area() {
+        let area = |profile: &Profile| {
+            profile
+                .segments()
+                .map(ProfileSegment::area_contribution)
+                .fold(Scalar::ZERO, |a, b| a + b)
+        };
+
+        assert_eq!(area(&square()), Scalar::from(4.));
+
+        let half_disc = Profile {
+            loops: vec![vec![
+                ProfileSegment::arc(
+                    Point::from([0., 0.]),
+                    Scalar::ONE,
+                    [Scalar::ZERO, Scalar::PI],
+                ),
+                ProfileSegment::Line {
+                    points: [Point::from([-1., 0.]), Point::from([1., 0.])],
+                },
+            ]],
+        };
+        assert!(
+            (area(&half_disc) - Scalar::PI / 2.).abs() < Scalar::from(1e-12)
+        );
+    }
+
+    #[test]
+    fn line_intervals() {
+        let epsilon = Scalar::from_f64(1e-9);
+        let line = Line::from_points([[-1., 1.], [0., 1.]]).0;
+
+        assert_eq!(
+            square().line_intervals(&line, epsilon),
+            vec![[Scalar::ONE, Scalar::from(3.)]]
+        );
+
+        // Lines through vertices must not confuse the algorithm.
+        let line = Line::from_points([[-1., -1.], [0., 0.]]).0;
+        let intervals = square().line_intervals(&line, epsilon);
+        assert_eq!(intervals.len(), 1);
+        for (actual, expected) in intervals[0].into_iter().zip([1., 3.]) {
+            assert!((actual - Scalar::from(expected)).abs() < epsilon);
+        }
+    }
+}
@@ -0,0 +1,528 @@
+//! Boolean operations on sketches
+//!
+//! See [`sketch_boolean`].
+
+use std::{collections::BTreeMap, iter};
+
+use fj_interop::ext::ArrayExt;
+use fj_math::{Point, Scalar, Winding};
+
+use crate::{
+    algorithms::approx::{path::RangeOnPath, Approx, Tolerance},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects, Sketch,
+        Surface, SurfaceVertex,
+    },
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{
+    arrangement::{Arrangement, DirectedEdge},
+    loops::{segment_from_curve, LoopEdge},
+    profile::{Containment, Profile, ProfileSegment},
+    BooleanError, BooleanOp,
+};
+
+/// Compute a boolean operation between two sketches
+///
+/// All edges of both sketches are split where they intersect each other. The
+/// resulting regions are selected according to the operation, and adjacent
+/// regions are merged into faces.
+///
+/// Bézier curves and ellipses are approximated by line segments, according to
+/// the tolerance. Where the edges of a face are kept unchanged, the original face
+/// is part of the result.
+///
+/// Sketches whose faces are not all in the same surface, or in a surface that
+/// is neither a plane nor a cylinder, result in a [`BooleanError`].
+pub fn sketch_boolean(
+    op: BooleanOp,
+    sketches: [&Sketch; 2],
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> Result<Sketch, BooleanError> {
+    let faces = sketches
+        .into_iter()
+        .enumerate()
+        .flat_map(|(operand, sketch)| {
+            sketch
+                .faces()
+                .into_iter()
+                .map(move |face| FaceInfo::new(face, operand, tolerance))
+        })
+        .collect::<Vec<_>>();
+
+    let Some(first) = faces.first() else {
+        return Ok(Sketch::new([]));
+    };
+    let surface = first.face.surface().clone();
+    if faces
+        .iter()
+        .any(|face| face.face.surface().geometry() != surface.geometry())
+    {
+        return Err(BooleanError::DifferentSurfaces);
+    }
+
+    // The resulting faces have the same orientation as the first one.
+    let flip = first.winding == Winding::Cw;
+
+    let (u, v) = match surface.geometry() {
+        SurfaceGeometry::Swept { u, v } => (u, v),
+        SurfaceGeometry::Ruled { .. } => {
+            return Err(BooleanError::RuledSurface);
+        }
+        SurfaceGeometry::Revolved { .. } => {
+            return Err(BooleanError::RevolvedSurface);
+        }
+    };
+    let scale = match u {
+        GlobalPath::Line(line) => line.direction().magnitude(),
+        GlobalPath::Circle(circle) => circle.radius(),
+        GlobalPath::Bezier(bezier) => {
+            let [start, _, _, end] = bezier.points();
+            start.distance_to(&end)
+        }
+        GlobalPath::Ellipse(ellipse) => {
+            let [major_radius, _] = ellipse.radii();
+            major_radius
+        }
+    }
+    .max(v.magnitude());
+    let epsilon = ValidationConfig::default().distinct_min_distance / scale;
+
+    let mut vertices = Vertices {
+        inner: Vec::new(),
+        epsilon,
+    };
+    for face in &faces {
+        for half_edge in
+            face.face.all_cycles().flat_map(|cycle| cycle.half_edges())
+        {
+            let start = half_edge.start_vertex();
+            let index = vertices.snap(start.position()).0;
+            vertices.inner[index]
+                .global
+                .get_or_insert_with(|| start.global_form().clone());
+        }
+    }
+
+    // Split the edges of both sketches where they intersect each other.
+    let segments = faces.iter().enumerate().flat_map(|(i, face)| {
+        face.segments.iter().enumerate().map(move |(k, segment)| {
+            let source = Piece {
+                face: i,
+                edge: k,
+                range: [Scalar::ZERO, Scalar::ONE],
+            };
+            (*segment, source)
+        })
+    });
+    let arrangement = Arrangement::compute(segments, [], epsilon, |point| {
+        vertices.snap(point)
+    });
+
+    let is_kept = |point: Point<2>| {
+        let [a, b] = [0, 1].map(|operand| {
+            faces.iter().any(|face| {
+                face.operand == operand
+                    && face.profile.contains(point, epsilon)
+                        == Containment::Inside
+            })
+        });
+
+        match op {
+            BooleanOp::Union => a || b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Intersection => a && b,
+        }
+    };
+
+    // Only keep the edges that separate a selected region from one that isn't
+    // selected. Those are oriented, so the selected region is on their left.
+    let mut kept_edges = vec![[false; 2]; arrangement.edges.len()];
+    for region in &arrangement.regions {
+        if !is_kept(region.interior_point) {
+            continue;
+        }
+
+        for edge in region.interiors.iter().flatten().chain(&region.exterior) {
+            kept_edges[edge.index][usize::from(edge.reversed)] = true;
+        }
+    }
+
+    let boundary = kept_edges.into_iter().enumerate().filter_map(
+        |(index, [forward, backward])| {
+            if forward == backward {
+                return None;
+            }
+
+            let edge = LoopEdge::new(
+                &arrangement,
+                DirectedEdge {
+                    index,
+                    reversed: backward,
+                },
+            );
+            let source = Piece {
+                range: edge.range,
+                ..edge.source
+            };
+            Some((edge.segment, source))
+        },
+    );
+
+    // Computing the arrangement of the remaining edges merges the selected
+    // regions with each other.
+    let boundary = boundary.collect::<Vec<_>>();
+    let merged = Arrangement::compute(boundary, [], epsilon, |point| {
+        vertices.snap(point)
+    });
+
+    let mut output = Vec::new();
+    for region in &merged.regions {
+        let point = region.interior_point;
+        if !is_kept(point) {
+            continue;
+        }
+
+        let loops = [&region.exterior]
+            .into_iter()
+            .chain(&region.interiors)
+            .map(|edges| {
+                edges
+                    .iter()
+                    .map(|edge| {
+                        let edge = LoopEdge::new(&merged, *edge);
+                        LoopEdge {
+                            range: edge.source.map_range(edge.range),
+                            ..edge
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(face) = faces
+            .iter()
+            .enumerate()
+            .find(|(i, face)| face.is_unchanged(*i, &loops, flip))
+            .map(|(_, face)| face)
+        {
+            output.push(face.face.clone());
+            continue;
+        }
+
+        let color = faces
+            .iter()
+            .find(|face| {
+                face.profile.contains(point, epsilon) == Containment::Inside
+            })
+            .expect("Selected region must be within a face")
+            .face
+            .color();
+
+        let mut builder = FaceBuilder {
+            surface: &surface,
+            faces: &faces,
+            vertices: &mut vertices,
+            objects,
+        };
+        let exterior_and_interiors = builder.build(loops, flip);
+
+        let mut cycles = exterior_and_interiors.into_iter();
+        let exterior = cycles.next().expect("Region must have exterior");
+        output.push(
+            Face::new(surface.clone(), exterior, cycles, color).insert(objects),
+        );
+    }
+
+    Ok(Sketch::new(output))
+}
+
+struct FaceInfo {
+    face: Handle<Face>,
+    operand: usize,
+    edges: Vec<SourceEdge>,
+    segments: Vec<ProfileSegment>,
+    profile: Profile,
+    winding: Winding,
+}
+
+impl FaceInfo {
+    fn new(face: &Handle<Face>, operand: usize, tolerance: Tolerance) -> Self {
+        let mut edges = Vec::new();
+        let mut segments = Vec::new();
+        let mut loops = Vec::new();
+
+        for cycle in face.all_cycles() {
+            let mut segments_of_loop = Vec::new();
+
+            for (half_edge, next) in
+                cycle.half_edges().zip(cycle.half_edges().cycle().skip(1))
+            {
+                let curve = half_edge.curve();
+                let boundary = half_edge.boundary();
+                let end_points =
+                    [half_edge.start_vertex(), next.start_vertex()]
+                        .map(|vertex| vertex.position());
+
+                let range = boundary.map(|point| point.t);
+                if let Ok(segment) = segment_from_curve(&curve, range) {
+                    let segment = segment
+                        .sub_segment([Scalar::ZERO, Scalar::ONE], end_points);
+
+                    edges.push(SourceEdge {
+                        curve,
+                        boundary,
+                        global_form: Some(half_edge.global_form().clone()),
+                    });
+                    segments.push(segment);
+                    segments_of_loop.push(segment);
+
+                    continue;
+                }
+
+                // The curve can't be represented by a segment. Approximate it
+                // by line segments instead.
+                let [start, end] = end_points;
+                let points = iter::once(start)
+                    .chain(
+                        (&curve, RangeOnPath::from(boundary))
+                            .approx(tolerance)
+                            .into_iter()
+                            .map(|(_, point)| point),
+                    )
+                    .chain(iter::once(end))
+                    .collect::<Vec<_>>();
+
+                for points in points.windows(2) {
+                    let points = [points[0], points[1]];
+                    let (curve, boundary) = Curve::line_from_points(points);
+
+                    let segment = ProfileSegment::Line { points };
+
+                    edges.push(SourceEdge {
+                        curve,
+                        boundary,
+                        global_form: None,
+                    });
+                    segments.push(segment);
+                    segments_of_loop.push(segment);
+                }
+            }
+
+            loops.push(segments_of_loop);
+        }
+
+        Self {
+            face: face.clone(),
+            operand,
+            edges,
+            segments,
+            profile: Profile { loops },
+            winding: face.exterior().winding(),
+        }
+    }
+
+    /// Determine whether a region is identical to this face
+    ///
+    /// `index` is the index of this face. The face can only be reused, if its
+    /// orientation matches the orientation of the result.
+    fn is_unchanged(
+        &self,
+        index: usize,
+        loops: &[Vec<LoopEdge<Piece>>],
+        flip: bool,
+    ) -> bool {
+        let num_edges = loops.iter().map(Vec::len).sum::<usize>();
+        if num_edges != self.edges.len() {
+            return false;
+        }
+
+        let full_range = if flip {
+            [Scalar::ONE, Scalar::ZERO]
+        } else {
+            [Scalar::ZERO, Scalar::ONE]
+        };
+        loops
+            .iter()
+            .flatten()
+            .all(|edge| edge.source.face == index && edge.range == full_range)
+    }
+}
+
+/// An edge of a face, as it is used by the boolean operation
+///
+/// This is either a half-edge of the face, or a line segment that approximates
+/// part of one.
+struct SourceEdge {
+    curve: Curve,
+    boundary: [Point<1>; 2],
+
+    /// The global form of the half-edge, if the edge is not an approximation
+    global_form: Option<Handle<GlobalEdge>>,
+}
+
+/// The part of a source edge that an edge was created from
+#[derive(Clone, Copy)]
+struct Piece {
+    /// The index of the face
+    face: usize,
+
+    /// The index of the source edge within the face
+    edge: usize,
+
+    /// The range on the source edge, in segment parameters
+    range: [Scalar; 2],
+}
+
+impl Piece {
+    /// Map a range relative to this piece into a range on the source edge
+    fn map_range(&self, range: [Scalar; 2]) -> [Scalar; 2] {
+        let [start, end] = self.range;
+
+        range.map(|s| {
+            if s == Scalar::ZERO {
+                start
+            } else if s == Scalar::ONE {
+                end
+            } else {
+                start + (end - start) * s
+            }
+        })
+    }
+}
+
+struct Vertices {
+    inner: Vec<Vertex>,
+    epsilon: Scalar,
+}
+
+impl Vertices {
+    /// Find the vertex at the given position, or create it
+    fn snap(&mut self, position: Point<2>) -> (usize, Point<2>) {
+        if let Some(index) = self.inner.iter().position(|vertex| {
+            vertex.position.distance_to(&position) <= self.epsilon
+        }) {
+            return (index, self.inner[index].position);
+        }
+
+        self.inner.push(Vertex {
+            position,
+            global: None,
+        });
+        (self.inner.len() - 1, position)
+    }
+}
+
+struct Vertex {
+    position: Point<2>,
+
+    /// The global form of the vertex, if it has been created already
+    global: Option<Handle<GlobalVertex>>,
+}
+
+struct FaceBuilder<'r> {
+    surface: &'r Handle<Surface>,
+    faces: &'r [FaceInfo],
+    vertices: &'r mut Vertices,
+    objects: &'r mut Service<Objects>,
+}
+
+impl FaceBuilder<'_> {
+    /// Build the cycles of a face from the loops of a region
+    ///
+    /// The first loop is the exterior, oriented counter-clockwise. All loops
+    /// are reversed, if `flip` is `true`.
+    fn build(
+        &mut self,
+        loops: Vec<Vec<LoopEdge<Piece>>>,
+        flip: bool,
+    ) -> Vec<Handle<Cycle>> {
+        let mut surface_vertices = BTreeMap::new();
+        let mut cycles = Vec::new();
+
+        for mut edges in loops {
+            if flip {
+                edges =
+                    edges.into_iter().rev().map(LoopEdge::reverse).collect();
+            }
+
+            let half_edges = edges
+                .iter()
+                .map(|edge| self.half_edge(edge, &mut surface_vertices))
+                .collect::<Vec<_>>();
+            cycles.push(Cycle::new(half_edges).insert(self.objects));
+        }
+
+        cycles
+    }
+
+    fn half_edge(
+        &mut self,
+        edge: &LoopEdge<Piece>,
+        surface_vertices: &mut BTreeMap<usize, Handle<SurfaceVertex>>,
+    ) -> Handle<HalfEdge> {
+        let source = &self.faces[edge.source.face].edges[edge.source.edge];
+
+        let global_vertices =
+            edge.vertices.map(|index| self.global_vertex(index));
+        let vertices = edge.vertices.zip_ext(global_vertices.clone()).map(
+            |(index, global_vertex)| {
+                surface_vertices
+                    .entry(index)
+                    .or_insert_with(|| {
+                        SurfaceVertex::new(
+                            self.vertices.inner[index].position,
+                            global_vertex,
+                        )
+                        .insert(self.objects)
+                    })
+                    .clone()
+            },
+        );
+
+        let original = source.global_form.as_ref().filter(|global_form| {
+            let mut expected = global_vertices.each_ref_ext().map(|v| v.id());
+            expected.sort();
+            let actual = global_form
+                .vertices()
+                .access_in_normalized_order()
+                .map(|vertex| vertex.id());
+            edge.is_full() && expected == actual
+        });
+        let global_edge = match original {
+            Some(global_form) => global_form.clone(),
+            None => GlobalEdge::new(global_vertices).insert(self.objects),
+        };
+
+        HalfEdge::new(
+            source.curve,
+            edge.boundary(source.boundary),
+            vertices,
+            global_edge,
+        )
+        .insert(self.objects)
+    }
+
+    fn global_vertex(&mut self, index: usize) -> Handle<GlobalVertex> {
+        let vertex = &mut self.vertices.inner[index];
+        vertex
+            .global
+            .get_or_insert_with(|| {
+                let position = self
+                    .surface
+                    .geometry()
+                    .point_from_surface_coords(vertex.position);
+                GlobalVertex::new(position).insert(self.objects)
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,1250 @@
+//! Boolean operations on solids
+//!
+//! See [`solid_boolean`].
+
+use std::collections::BTreeMap;
+
+use fj_interop::ext::ArrayExt;
+use fj_math::{
+    Aabb, Circle, CubicBezier, Ellipse, Line, Point, Scalar, Triangle, Vector,
+    Winding,
+};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        intersect::{
+            CurveFaceIntersection, CurveFaceIntersectionInterval,
+            SurfaceSurfaceIntersection,
+        },
+        triangulate::Triangulate,
+    },
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects, Shell, Solid,
+        SurfaceVertex,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::{
+    arrangement::Arrangement,
+    classify::PointClassifier,
+    loops::{segment_from_curve, LoopEdge},
+    profile::{Containment, Profile, ProfileSegment},
+    BooleanError, BooleanOp,
+};
+
+/// Compute a boolean operation between two solids
+///
+/// The faces of both solids are split where they intersect each other. The
+/// resulting pieces are classified as being inside, outside, or on the surface
+/// of the other solid, and the pieces that make up the result are assembled
+/// into a new solid.
+///
+/// # Implementation Note
+///
+/// Intersections between planar faces are fully supported. Faces that are
+/// curved, like the side walls of a swept circle, are supported where they
+/// intersect planes that are either parallel to the circle, or parallel to the
+/// direction of the sweep. Any other intersection between curved faces results
+/// in a [`BooleanError`].
+///
+/// Solids with free-form faces, ruled or revolved faces, Bézier edges, or
+/// ellipses result in a [`BooleanError`] too.
+pub fn solid_boolean(
+    op: BooleanOp,
+    solids: [&Solid; 2],
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> Result<Solid, BooleanError> {
+    let epsilon = ValidationConfig::default().distinct_min_distance;
+
+    let mut registry = VertexRegistry::new(epsilon);
+    let mut faces = Vec::new();
+    for (operand, solid) in solids.into_iter().enumerate() {
+        for face in solid.shells().flat_map(|shell| shell.faces()) {
+            faces.push(FaceInfo::new(
+                face,
+                operand,
+                tolerance,
+                epsilon,
+                &mut registry,
+            )?);
+        }
+    }
+
+    let classifiers = [0, 1].map(|operand| {
+        PointClassifier::new(
+            faces
+                .iter()
+                .filter(|face| face.operand == operand)
+                .flat_map(|face| face.triangles.iter().copied()),
+        )
+    });
+
+    // Compute where the faces of the two solids intersect each other.
+    let mut cuts = Vec::new();
+    let mut coplanar = vec![Vec::new(); faces.len()];
+    for i in 0..faces.len() {
+        for j in i + 1..faces.len() {
+            let [a, b] = [&faces[i], &faces[j]];
+            if a.operand == b.operand || !aabbs_overlap(&a.aabb, &b.aabb) {
+                continue;
+            }
+
+            match intersect_surfaces(a, b, epsilon) {
+                SurfaceIntersection::None => {}
+                SurfaceIntersection::Coplanar => {
+                    coplanar[i].push(j);
+                    coplanar[j].push(i);
+                }
+                SurfaceIntersection::Curves(curves) => {
+                    for (curves, window) in curves {
+                        let [intervals_a, intervals_b] = [a, b]
+                            .zip_ext(curves)
+                            .try_map_ext(|(face, curve)| {
+                                face.curve_intervals(&curve, window)
+                            })?;
+
+                        for interval in intervals_a.merge(&intervals_b) {
+                            let range = [interval.start.t, interval.end.t];
+
+                            let points = [
+                                range[0],
+                                (range[0] + range[1]) / 2.,
+                                range[1],
+                            ]
+                            .map(|t| {
+                                a.point_from_surface_coords(
+                                    curves[0].point_from_path_coords([t]),
+                                )
+                            });
+                            if points[0].distance_to(&points[1]) <= epsilon
+                                && points[0].distance_to(&points[2]) <= epsilon
+                            {
+                                continue;
+                            }
+
+                            registry.insert(points[0], objects);
+                            registry.insert(points[2], objects);
+
+                            cuts.push(Cut {
+                                faces: [i, j],
+                                curves,
+                                range,
+                            });
+                        }
+                    }
+                }
+                SurfaceIntersection::Unsupported => {
+                    if faces_cross(a, b, epsilon) {
+                        return Err(BooleanError::UnsupportedIntersection);
+                    }
+                }
+            }
+        }
+    }
+
+    // Split all faces along the intersections.
+    let mut arrangements = Vec::new();
+    for (i, face) in faces.iter().enumerate() {
+        let mut segments = face
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(k, segment)| (*segment, EdgeSource::Boundary(k)))
+            .collect::<Vec<_>>();
+        for &j in &coplanar[i] {
+            for (k, half_edge) in faces[j].half_edges.iter().enumerate() {
+                let curve = map_curve(&half_edge.curve(), &faces[j], face);
+                let range = half_edge.boundary().map(|point| point.t);
+                segments.push((
+                    segment_from_curve(&curve, range)?,
+                    EdgeSource::Coplanar {
+                        face: j,
+                        half_edge: k,
+                    },
+                ));
+            }
+        }
+        for (c, cut) in cuts.iter().enumerate() {
+            if let Some(curve) = cut.curve_on(i) {
+                segments.push((
+                    segment_from_curve(curve, cut.range)?,
+                    EdgeSource::Cut(c),
+                ));
+            }
+        }
+
+        // Vertices of other faces might lie on the boundary of this one,
+        // without the boundary being cut there.
+        let points = match face.kind {
+            SurfaceKind::Plane { origin, normal } => registry
+                .positions
+                .iter()
+                .filter(|position| {
+                    normal.dot(&(**position - origin)).abs() <= epsilon
+                        && face.aabb.contains(**position)
+                })
+                .map(|position| face.project(*position))
+                .collect(),
+            SurfaceKind::Cylinder { .. } => Vec::new(),
+        };
+
+        let mut vertices = Vec::new();
+        let arrangement =
+            Arrangement::compute(segments, points, face.epsilon, |point| {
+                face.snap(point, &mut vertices, &mut registry, objects)
+            });
+
+        arrangements.push((arrangement, vertices));
+    }
+
+    // Select the pieces that make up the result.
+    let mut pieces = Vec::new();
+    for (i, face) in faces.iter().enumerate() {
+        let (arrangement, _) = &arrangements[i];
+
+        for region in &arrangement.regions {
+            let point = region.interior_point;
+            if face.profile.contains(point, face.epsilon) != Containment::Inside
+            {
+                continue;
+            }
+
+            let location =
+                face.locate(point, &faces, &coplanar[i]).unwrap_or_else(|| {
+                    let point = face.point_from_surface_coords(point);
+                    if classifiers[1 - face.operand].is_inside(point) {
+                        Location::Inside
+                    } else {
+                        Location::Outside
+                    }
+                });
+
+            let Some(reverse) = op.keep(face.operand, location) else {
+                continue;
+            };
+
+            let loops = [&region.exterior]
+                .into_iter()
+                .chain(&region.interiors)
+                .map(|edges| {
+                    edges
+                        .iter()
+                        .map(|edge| LoopEdge::new(arrangement, *edge))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let is_unchanged = !reverse && face.is_unchanged(&loops, &registry);
+            pieces.push((i, loops, reverse, is_unchanged));
+        }
+    }
+
+    // Faces that are kept unchanged keep their global edges. New faces must
+    // share those, so they are registered before any new face is built.
+    let mut global_edges = GlobalEdges::default();
+    for (i, loops, ..) in pieces.iter().filter(|(.., unchanged)| *unchanged) {
+        let (face, (_, vertices)) = (&faces[*i], &arrangements[*i]);
+
+        for edge in loops.iter().flatten() {
+            let EdgeSource::Boundary(k) = edge.source else {
+                unreachable!("Unchanged faces only have boundary edges");
+            };
+
+            let global_edge = face.half_edges[k].global_form();
+            global_edges.get_or_insert_with(
+                edge.vertices.map(|index| vertices[index].0),
+                face.point_from_surface_coords(edge.segment.point_at(0.5)),
+                registry.epsilon,
+                || global_edge.clone(),
+            );
+        }
+    }
+
+    // Build the faces from the pieces.
+    let mut output = Vec::new();
+    for (i, loops, reverse, is_unchanged) in pieces {
+        let face = &faces[i];
+
+        let new_face = if is_unchanged {
+            face.face.clone()
+        } else {
+            let mut builder = FaceBuilder {
+                index: i,
+                face,
+                faces: &faces,
+                cuts: &cuts,
+                vertices: &mut arrangements[i].1,
+                registry: &mut registry,
+                global_edges: &mut global_edges,
+                objects,
+            };
+            builder.build(loops, reverse)
+        };
+
+        output.push(new_face);
+    }
+
+    Ok(Solid::new(group_into_shells(output, objects)))
+}
+
+impl BooleanOp {
+    /// Decide whether a piece of a face is part of the result
+    ///
+    /// Returns `None`, if it isn't, or whether it needs to be reversed, if it
+    /// is.
+    fn keep(&self, operand: usize, location: Location) -> Option<bool> {
+        use Location::*;
+
+        match (self, operand, location) {
+            (Self::Union, 0, Outside | OnSame)
+            | (Self::Union, 1, Outside)
+            | (Self::Intersection, 0, Inside | OnSame)
+            | (Self::Intersection, 1, Inside)
+            | (Self::Difference, 0, Outside | OnOpposite) => Some(false),
+            (Self::Difference, 1, Inside) => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// The location of a piece of a face, relative to the other solid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Location {
+    Inside,
+    Outside,
+
+    /// On the surface of the other solid, facing the same direction
+    OnSame,
+
+    /// On the surface of the other solid, facing the opposite direction
+    OnOpposite,
+}
+
+struct FaceInfo {
+    face: Handle<Face>,
+    operand: usize,
+    kind: SurfaceKind,
+    half_edges: Vec<Handle<HalfEdge>>,
+    segments: Vec<ProfileSegment>,
+    profile: Profile,
+    winding: Winding,
+    epsilon: Scalar,
+    triangles: Vec<Triangle<3>>,
+    aabb: Aabb<3>,
+
+    /// The surface positions of the face's vertices, by registry index
+    surface_positions: BTreeMap<usize, Point<2>>,
+}
+
+impl FaceInfo {
+    fn new(
+        face: &Handle<Face>,
+        operand: usize,
+        tolerance: Tolerance,
+        epsilon: Scalar,
+        registry: &mut VertexRegistry,
+    ) -> Result<Self, BooleanError> {
+        let (u, v) = match face.surface().geometry() {
+            SurfaceGeometry::Swept { u, v } => (u, v),
+            SurfaceGeometry::Ruled { .. } => {
+                return Err(BooleanError::RuledSurface);
+            }
+            SurfaceGeometry::Revolved { .. } => {
+                return Err(BooleanError::RevolvedSurface);
+            }
+        };
+        let (kind, scale) = match u {
+            GlobalPath::Line(line) => {
+                let normal = line.direction().cross(&v).normalize();
+                let scale = line.direction().magnitude().max(v.magnitude());
+                (
+                    SurfaceKind::Plane {
+                        origin: line.origin(),
+                        normal,
+                    },
+                    scale,
+                )
+            }
+            GlobalPath::Circle(circle) => (
+                SurfaceKind::Cylinder { circle, path: v },
+                circle.radius().max(v.magnitude()),
+            ),
+            GlobalPath::Bezier(_) => {
+                return Err(BooleanError::FreeFormSurface);
+            }
+            GlobalPath::Ellipse(_) => return Err(BooleanError::Ellipse),
+        };
+
+        let mut half_edges = Vec::new();
+        let mut segments = Vec::new();
+        let mut loops = Vec::new();
+        let mut surface_positions = BTreeMap::new();
+
+        for cycle in face.all_cycles() {
+            let mut segments_of_loop = Vec::new();
+
+            for (half_edge, next) in
+                cycle.half_edges().zip(cycle.half_edges().cycle().skip(1))
+            {
+                let start = half_edge.start_vertex();
+                let index = registry.seed(start.global_form());
+                surface_positions.insert(index, start.position());
+
+                let curve = half_edge.curve();
+                let range = half_edge.boundary().map(|point| point.t);
+                let segment = segment_from_curve(&curve, range)?.sub_segment(
+                    [Scalar::ZERO, Scalar::ONE],
+                    [start, next.start_vertex()]
+                        .map(|vertex| vertex.position()),
+                );
+
+                half_edges.push(half_edge.clone());
+                segments.push(segment);
+                segments_of_loop.push(segment);
+            }
+
+            loops.push(segments_of_loop);
+        }
+
+        let triangles = (&**face)
+            .approx(tolerance)
+            .triangulate()
+            .triangles()
+            .map(|triangle| triangle.inner)
+            .collect::<Vec<_>>();
+
+        let margin = Vector::from([tolerance.inner() + epsilon; 3]);
+        let aabb = Aabb::<3>::from_points(
+            triangles.iter().flat_map(|triangle| triangle.points()),
+        );
+        let aabb = Aabb {
+            min: aabb.min - margin,
+            max: aabb.max + margin,
+        };
+
+        Ok(Self {
+            face: face.clone(),
+            operand,
+            kind,
+            half_edges,
+            segments,
+            profile: Profile { loops },
+            winding: face.exterior().winding(),
+            epsilon: epsilon / scale,
+            triangles,
+            aabb,
+            surface_positions,
+        })
+    }
+
+    fn point_from_surface_coords(&self, point: Point<2>) -> Point<3> {
+        self.face
+            .surface()
+            .geometry()
+            .point_from_surface_coords(point)
+    }
+
+    fn project(&self, point: Point<3>) -> Point<2> {
+        self.face.surface().geometry().project_global_point(point)
+    }
+
+    fn project_vector(&self, vector: Vector<3>) -> Vector<2> {
+        let origin = Point::origin();
+        self.project(origin + vector) - self.project(origin)
+    }
+
+    /// The normal of a planar face, pointing away from its front side
+    fn outward_normal(&self) -> Option<Vector<3>> {
+        let SurfaceKind::Plane { normal, .. } = self.kind else {
+            return None;
+        };
+
+        match self.winding {
+            Winding::Ccw => Some(normal),
+            Winding::Cw => Some(-normal),
+        }
+    }
+
+    /// The range of the face along the u-axis of its surface
+    fn u_range(&self) -> [Scalar; 2] {
+        let mut range = [Scalar::MAX, -Scalar::MAX];
+        for segment in self.profile.segments() {
+            let [min, max] = segment.bounding_box();
+            range[0] = range[0].min(min.u);
+            range[1] = range[1].max(max.u);
+        }
+        range
+    }
+
+    /// Compute where a curve on the face's surface is within the face
+    fn curve_intervals(
+        &self,
+        curve: &Curve,
+        window: Option<[Scalar; 2]>,
+    ) -> Result<CurveFaceIntersection, BooleanError> {
+        let intervals = match (curve, window) {
+            (Curve::Line(line), _) => {
+                self.profile.line_intervals(line, self.epsilon)
+            }
+            (Curve::Circle(_), Some([start, end])) => {
+                let probe = segment_from_curve(curve, [start, end])?;
+                self.profile
+                    .segment_intervals(&probe, self.epsilon)
+                    .into_iter()
+                    .map(|range| range.map(|s| start + (end - start) * s))
+                    .collect()
+            }
+            (Curve::Circle(_), None) => {
+                unreachable!("Circular intersection curves must have window")
+            }
+            (Curve::Bezier(_), _) => return Err(BooleanError::BezierCurve),
+            (Curve::Ellipse(_), _) => return Err(BooleanError::Ellipse),
+        };
+
+        Ok(CurveFaceIntersection::from_intervals(
+            intervals.into_iter().map(|[start, end]| {
+                CurveFaceIntersectionInterval {
+                    start: Point::from([start]),
+                    end: Point::from([end]),
+                }
+            }),
+        ))
+    }
+
+    /// Snap a point on the face to a vertex
+    ///
+    /// Returns the index of the vertex within `vertices`, and its position.
+    fn snap(
+        &self,
+        point: Point<2>,
+        vertices: &mut Vec<(usize, Point<2>)>,
+        registry: &mut VertexRegistry,
+        objects: &mut Service<Objects>,
+    ) -> (usize, Point<2>) {
+        match self.kind {
+            SurfaceKind::Plane { .. } => {
+                let index = registry
+                    .insert(self.point_from_surface_coords(point), objects);
+
+                if let Some(local) =
+                    vertices.iter().position(|(other, _)| *other == index)
+                {
+                    return (local, vertices[local].1);
+                }
+
+                let position =
+                    self.surface_positions.get(&index).copied().unwrap_or_else(
+                        || self.project(registry.positions[index]),
+                    );
+                vertices.push((index, position));
+                (vertices.len() - 1, position)
+            }
+            SurfaceKind::Cylinder { .. } => {
+                // On a curved surface, distinct surface positions can map to
+                // the same global position (where the surface wraps around).
+                // Those need to stay distinct.
+                if let Some(local) = vertices.iter().position(|(_, other)| {
+                    other.distance_to(&point) <= self.epsilon
+                }) {
+                    return (local, vertices[local].1);
+                }
+
+                let index = registry
+                    .insert(self.point_from_surface_coords(point), objects);
+                vertices.push((index, point));
+                (vertices.len() - 1, point)
+            }
+        }
+    }
+
+    /// Determine whether a point on the face lies on a coplanar face
+    fn locate(
+        &self,
+        point: Point<2>,
+        faces: &[FaceInfo],
+        coplanar: &[usize],
+    ) -> Option<Location> {
+        let normal = self.outward_normal()?;
+        let point = self.point_from_surface_coords(point);
+
+        coplanar.iter().find_map(|&j| {
+            let other = &faces[j];
+            let containment =
+                other.profile.contains(other.project(point), other.epsilon);
+            if containment != Containment::Inside {
+                return None;
+            }
+
+            let other_normal = other.outward_normal()?;
+            if normal.dot(&other_normal) > Scalar::ZERO {
+                Some(Location::OnSame)
+            } else {
+                Some(Location::OnOpposite)
+            }
+        })
+    }
+
+    /// Determine whether a region is identical to the original face
+    fn is_unchanged(
+        &self,
+        loops: &[Vec<LoopEdge<EdgeSource>>],
+        registry: &VertexRegistry,
+    ) -> bool {
+        let num_edges = loops.iter().map(Vec::len).sum::<usize>();
+        if num_edges != self.half_edges.len() {
+            return false;
+        }
+
+        let all_edges_are_whole = loops.iter().flatten().all(|edge| {
+            matches!(edge.source, EdgeSource::Boundary(_)) && edge.is_full()
+        });
+        let all_vertices_are_canonical =
+            self.half_edges.iter().all(|half_edge| {
+                let vertex = half_edge.start_vertex().global_form();
+                let index = registry.by_id[&vertex.id()];
+                registry.vertices[index].id() == vertex.id()
+            });
+
+        all_edges_are_whole && all_vertices_are_canonical
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SurfaceKind {
+    Plane {
+        origin: Point<3>,
+
+        /// The unit normal of the plane, as defined by its coordinate system
+        normal: Vector<3>,
+    },
+    Cylinder {
+        circle: Circle<3>,
+        path: Vector<3>,
+    },
+}
+
+enum SurfaceIntersection {
+    None,
+    Coplanar,
+
+    /// Intersection curves, with optional windows for circular curves
+    Curves(Vec<([Curve; 2], Option<[Scalar; 2]>)>),
+
+    Unsupported,
+}
+
+fn intersect_surfaces(
+    a: &FaceInfo,
+    b: &FaceInfo,
+    epsilon: Scalar,
+) -> SurfaceIntersection {
+    match (a.kind, b.kind) {
+        (
+            SurfaceKind::Plane {
+                origin: origin_a,
+                normal: normal_a,
+            },
+            SurfaceKind::Plane {
+                normal: normal_b, ..
+            },
+        ) => {
+            if normal_a.cross(&normal_b).magnitude() <= Scalar::from_f64(1e-9) {
+                let SurfaceKind::Plane {
+                    origin: origin_b, ..
+                } = b.kind
+                else {
+                    unreachable!("Just matched both surfaces as planes")
+                };
+
+                if normal_a.dot(&(origin_b - origin_a)).abs() <= epsilon {
+                    return SurfaceIntersection::Coplanar;
+                }
+                return SurfaceIntersection::None;
+            }
+
+            match SurfaceSurfaceIntersection::compute([
+                a.face.surface().clone(),
+                b.face.surface().clone(),
+            ]) {
+                Some(intersection) => SurfaceIntersection::Curves(vec![(
+                    intersection.intersection_curves,
+                    None,
+                )]),
+                None => SurfaceIntersection::None,
+            }
+        }
+        (SurfaceKind::Plane { .. }, SurfaceKind::Cylinder { .. }) => {
+            intersect_plane_cylinder(a, b, epsilon)
+        }
+        (SurfaceKind::Cylinder { .. }, SurfaceKind::Plane { .. }) => {
+            match intersect_plane_cylinder(b, a, epsilon) {
+                SurfaceIntersection::Curves(curves) => {
+                    SurfaceIntersection::Curves(
+                        curves
+                            .into_iter()
+                            .map(|([a, b], window)| ([b, a], window))
+                            .collect(),
+                    )
+                }
+                intersection => intersection,
+            }
+        }
+        (SurfaceKind::Cylinder { .. }, SurfaceKind::Cylinder { .. }) => {
+            SurfaceIntersection::Unsupported
+        }
+    }
+}
+
+fn intersect_plane_cylinder(
+    plane: &FaceInfo,
+    cylinder: &FaceInfo,
+    epsilon: Scalar,
+) -> SurfaceIntersection {
+    let SurfaceKind::Plane { origin, normal } = plane.kind else {
+        unreachable!("Expected first face to be planar")
+    };
+    let SurfaceKind::Cylinder { circle, path } = cylinder.kind else {
+        unreachable!("Expected second face to be curved")
+    };
+
+    let radius = circle.radius();
+    let parallel = Scalar::from_f64(1e-9);
+    let [a, b] = [circle.a(), circle.b()].map(|vector| normal.dot(&vector));
+
+    if a.abs() <= parallel * radius && b.abs() <= parallel * radius {
+        // The plane is parallel to the circle. The intersection is a copy of
+        // the circle, at a specific position along the path.
+        let w = normal.dot(&(origin - circle.center())) / normal.dot(&path);
+
+        let Some(circle_in_plane) = circle_in_plane(
+            plane.project(circle.center() + path * w),
+            plane.project_vector(circle.a()),
+            plane.project_vector(circle.b()),
+        ) else {
+            return SurfaceIntersection::Unsupported;
+        };
+
+        let line = Line::from_origin_and_direction(
+            Point::from([Scalar::ZERO, w]),
+            Vector::from([1., 0.]),
+        );
+
+        return SurfaceIntersection::Curves(vec![(
+            [Curve::Circle(circle_in_plane), Curve::Line(line)],
+            Some(cylinder.u_range()),
+        )]);
+    }
+
+    if normal.dot(&path).abs() <= parallel * path.magnitude() {
+        // The plane is parallel to the path. The intersection is made up of
+        // lines along the path.
+        let distance = normal.dot(&(origin - circle.center()));
+        let length = (a * a + b * b).sqrt();
+        if distance.abs() >= length - epsilon {
+            // The plane misses or touches the surface.
+            return SurfaceIntersection::None;
+        }
+
+        let angle = b.atan2(a);
+        let delta = (distance / length).acos();
+
+        let [u_min, u_max] = cylinder.u_range();
+        let margin = cylinder.epsilon;
+
+        let mut curves = Vec::new();
+        for u in [angle - delta, angle + delta] {
+            // Find the first occurrence of the solution within the face.
+            let mut u =
+                u - ((u - u_min + margin) / Scalar::TAU).floor() * Scalar::TAU;
+
+            while u <= u_max + margin {
+                let on_cylinder = Line::from_origin_and_direction(
+                    Point::from([u, Scalar::ZERO]),
+                    Vector::from([0., 1.]),
+                );
+                let on_plane = Line::from_origin_and_direction(
+                    plane.project(circle.point_from_circle_coords([u])),
+                    plane.project_vector(path),
+                );
+
+                curves.push((
+                    [Curve::Line(on_plane), Curve::Line(on_cylinder)],
+                    None,
+                ));
+
+                u += Scalar::TAU;
+            }
+        }
+
+        return SurfaceIntersection::Curves(curves);
+    }
+
+    SurfaceIntersection::Unsupported
+}
+
+/// Determine whether two faces cross each other
+///
+/// Used to detect intersections that can't be computed, so they can be
+/// rejected, instead of resulting in wrong geometry.
+fn faces_cross(a: &FaceInfo, b: &FaceInfo, epsilon: Scalar) -> bool {
+    match (a.kind, b.kind) {
+        (SurfaceKind::Plane { .. }, _) => {
+            triangles_cross_face(&b.triangles, a, epsilon)
+        }
+        (_, SurfaceKind::Plane { .. }) => {
+            triangles_cross_face(&a.triangles, b, epsilon)
+        }
+        _ => a.triangles.iter().any(|triangle_a| {
+            b.triangles.iter().any(|triangle_b| {
+                triangles_cross(triangle_a, triangle_b)
+                    || triangles_cross(triangle_b, triangle_a)
+            })
+        }),
+    }
+}
+
+fn triangles_cross_face(
+    triangles: &[Triangle<3>],
+    face: &FaceInfo,
+    epsilon: Scalar,
+) -> bool {
+    let SurfaceKind::Plane { origin, normal } = face.kind else {
+        unreachable!("Expected face to be planar")
+    };
+
+    triangles.iter().any(|triangle| {
+        let points = triangle.points();
+        let distances = points.map(|point| normal.dot(&(point - origin)));
+
+        let mut crossings = Vec::new();
+        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+            let [a, b] = [distances[i], distances[j]];
+            if (a > epsilon && b < -epsilon) || (a < -epsilon && b > epsilon) {
+                crossings
+                    .push(points[i] + (points[j] - points[i]) * (a / (a - b)));
+            }
+        }
+
+        let Some(first) = crossings.first() else {
+            return false;
+        };
+        let middle = crossings
+            .iter()
+            .skip(1)
+            .fold(first.coords, |sum, point| sum + point.coords)
+            / crossings.len() as f64;
+
+        crossings
+            .iter()
+            .copied()
+            .chain([Point { coords: middle }])
+            .any(|point| {
+                face.profile.contains(face.project(point), face.epsilon)
+                    == Containment::Inside
+            })
+    })
+}
+
+/// Determine whether an edge of triangle `a` passes through triangle `b`
+fn triangles_cross(a: &Triangle<3>, b: &Triangle<3>) -> bool {
+    let margin = Scalar::from_f64(1e-9);
+    let [p, q, r] = b.points();
+
+    let points = a.points();
+    [(0, 1), (1, 2), (2, 0)].into_iter().any(|(i, j)| {
+        // Möller–Trumbore intersection algorithm, restricted to the edge
+        let origin = points[i];
+        let direction = points[j] - points[i];
+
+        let pq = q - p;
+        let pr = r - p;
+        let h = direction.cross(&pr);
+        let det = pq.dot(&h);
+        if det.abs() <= margin * pq.magnitude() * pr.magnitude() {
+            return false;
+        }
+
+        let s = origin - p;
+        let u = s.dot(&h) / det;
+        let k = s.cross(&pq);
+        let v = direction.dot(&k) / det;
+        let t = pr.dot(&k) / det;
+
+        t > margin
+            && t < Scalar::ONE - margin
+            && u > margin
+            && v > margin
+            && u + v < Scalar::ONE - margin
+    })
+}
+
+fn aabbs_overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    (0..3).all(|i| {
+        a.min.coords.components[i] <= b.max.coords.components[i]
+            && b.min.coords.components[i] <= a.max.coords.components[i]
+    })
+}
+
+/// An intersection between two faces, that splits both of them
+struct Cut {
+    faces: [usize; 2],
+
+    /// The curves on the faces' surfaces, sharing the same curve coordinates
+    curves: [Curve; 2],
+
+    /// The range of the cut, in curve coordinates
+    range: [Scalar; 2],
+}
+
+impl Cut {
+    fn curve_on(&self, face: usize) -> Option<&Curve> {
+        let index = self.faces.iter().position(|&f| f == face)?;
+        Some(&self.curves[index])
+    }
+}
+
+/// The origin of an edge in a face's arrangement
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EdgeSource {
+    /// A half-edge of the face itself, by index
+    Boundary(usize),
+
+    /// A half-edge of a coplanar face
+    Coplanar { face: usize, half_edge: usize },
+
+    /// An intersection with another face, by index of the cut
+    Cut(usize),
+}
+
+struct FaceBuilder<'r> {
+    index: usize,
+    face: &'r FaceInfo,
+    faces: &'r [FaceInfo],
+    cuts: &'r [Cut],
+    vertices: &'r mut Vec<(usize, Point<2>)>,
+    registry: &'r mut VertexRegistry,
+    global_edges: &'r mut GlobalEdges,
+    objects: &'r mut Service<Objects>,
+}
+
+impl FaceBuilder<'_> {
+    /// Build a face from the loops of a region
+    ///
+    /// The first loop is the exterior, oriented counter-clockwise. The face is
+    /// built with the same front side as the original face, or the opposite
+    /// one, if `reverse` is `true`.
+    ///
+    /// Reversing the face is done here, instead of using [`Reverse`], to avoid
+    /// creating the non-reversed face first.
+    ///
+    /// [`Reverse`]: crate::algorithms::reverse::Reverse
+    fn build(
+        &mut self,
+        loops: Vec<Vec<LoopEdge<EdgeSource>>>,
+        reverse: bool,
+    ) -> Handle<Face> {
+        let flip = (self.face.winding == Winding::Cw) != reverse;
+
+        let mut surface_vertices = BTreeMap::new();
+        let mut cycles = Vec::new();
+        for mut edges in loops {
+            if flip {
+                edges =
+                    edges.into_iter().rev().map(LoopEdge::reverse).collect();
+            }
+
+            let half_edges = edges
+                .iter()
+                .map(|edge| self.half_edge(edge, &mut surface_vertices))
+                .collect::<Vec<_>>();
+            cycles.push(Cycle::new(half_edges).insert(self.objects));
+        }
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Region must have exterior");
+
+        Face::new(
+            self.face.face.surface().clone(),
+            exterior,
+            cycles,
+            self.face.face.color(),
+        )
+        .with_origin(self.face.face.origin())
+        .insert(self.objects)
+    }
+
+    fn half_edge(
+        &mut self,
+        edge: &LoopEdge<EdgeSource>,
+        surface_vertices: &mut BTreeMap<usize, Handle<SurfaceVertex>>,
+    ) -> Handle<HalfEdge> {
+        let (curve, boundary, original) = match edge.source {
+            EdgeSource::Boundary(k) => {
+                let half_edge = &self.face.half_edges[k];
+                (
+                    half_edge.curve(),
+                    half_edge.boundary(),
+                    Some(half_edge.global_form()),
+                )
+            }
+            EdgeSource::Coplanar { face, half_edge } => {
+                let other = &self.faces[face];
+                let half_edge = &other.half_edges[half_edge];
+                (
+                    map_curve(&half_edge.curve(), other, self.face),
+                    half_edge.boundary(),
+                    Some(half_edge.global_form()),
+                )
+            }
+            EdgeSource::Cut(c) => {
+                let cut = &self.cuts[c];
+                let curve =
+                    *cut.curve_on(self.index).expect("Cut must be on face");
+                (curve, cut.range.map(|t| Point::from([t])), None)
+            }
+        };
+
+        let boundary = edge.boundary(boundary);
+
+        let vertices = edge.vertices.map(|index| {
+            surface_vertices
+                .entry(index)
+                .or_insert_with(|| {
+                    let (registry_index, position) = self.vertices[index];
+                    SurfaceVertex::new(
+                        position,
+                        self.registry.vertices[registry_index].clone(),
+                    )
+                    .insert(self.objects)
+                })
+                .clone()
+        });
+
+        let global_vertices = edge.vertices.map(|index| self.vertices[index].0);
+        let original = original.filter(|global_edge| {
+            let mut expected =
+                global_vertices.map(|index| self.registry.vertices[index].id());
+            expected.sort();
+            let actual = global_edge
+                .vertices()
+                .access_in_normalized_order()
+                .map(|vertex| vertex.id());
+            edge.is_full() && expected == actual
+        });
+
+        // Edges at the same position are the same edge, even if they were
+        // represented by different global edges in the operands.
+        let middle = self
+            .face
+            .point_from_surface_coords(edge.segment.point_at(0.5));
+        let global_edge = self.global_edges.get_or_insert_with(
+            global_vertices,
+            middle,
+            self.registry.epsilon,
+            || match original {
+                Some(global_edge) => global_edge.clone(),
+                None => GlobalEdge::new(
+                    global_vertices
+                        .map(|index| self.registry.vertices[index].clone()),
+                )
+                .insert(self.objects),
+            },
+        );
+
+        HalfEdge::new(curve, boundary, vertices, global_edge)
+            .insert(self.objects)
+    }
+}
+
+/// Global edges that are part of the result, to share them between faces
+#[derive(Default)]
+struct GlobalEdges {
+    /// The global edges, with their middle points, by vertices
+    inner: BTreeMap<[usize; 2], Vec<(Point<3>, Handle<GlobalEdge>)>>,
+}
+
+impl GlobalEdges {
+    fn get_or_insert_with(
+        &mut self,
+        mut vertices: [usize; 2],
+        middle: Point<3>,
+        epsilon: Scalar,
+        f: impl FnOnce() -> Handle<GlobalEdge>,
+    ) -> Handle<GlobalEdge> {
+        vertices.sort();
+
+        // Several edges can connect the same vertices, for example two halves
+        // of a circle. The middle point distinguishes between those.
+        let candidates = self.inner.entry(vertices).or_default();
+        if let Some((_, global_edge)) = candidates
+            .iter()
+            .find(|(other, _)| other.distance_to(&middle) <= epsilon)
+        {
+            return global_edge.clone();
+        }
+
+        let global_edge = f();
+        candidates.push((middle, global_edge.clone()));
+        global_edge
+    }
+}
+
+/// The global vertices that are part of the operation
+///
+/// Makes sure that vertices that are at the same position are represented by
+/// the same [`GlobalVertex`].
+struct VertexRegistry {
+    vertices: Vec<Handle<GlobalVertex>>,
+    positions: Vec<Point<3>>,
+    by_id: BTreeMap<ObjectId, usize>,
+    epsilon: Scalar,
+}
+
+impl VertexRegistry {
+    fn new(epsilon: Scalar) -> Self {
+        Self {
+            vertices: Vec::new(),
+            positions: Vec::new(),
+            by_id: BTreeMap::new(),
+            epsilon,
+        }
+    }
+
+    /// Register an existing vertex
+    fn seed(&mut self, vertex: &Handle<GlobalVertex>) -> usize {
+        if let Some(index) = self.by_id.get(&vertex.id()) {
+            return *index;
+        }
+
+        let index = self.find(vertex.position()).unwrap_or_else(|| {
+            self.vertices.push(vertex.clone());
+            self.positions.push(vertex.position());
+            self.vertices.len() - 1
+        });
+        self.by_id.insert(vertex.id(), index);
+
+        index
+    }
+
+    /// Return the vertex at the given position, creating it, if necessary
+    fn insert(
+        &mut self,
+        position: Point<3>,
+        objects: &mut Service<Objects>,
+    ) -> usize {
+        self.find(position).unwrap_or_else(|| {
+            self.vertices
+                .push(GlobalVertex::new(position).insert(objects));
+            self.positions.push(position);
+            self.vertices.len() - 1
+        })
+    }
+
+    fn find(&self, position: Point<3>) -> Option<usize> {
+        self.positions
+            .iter()
+            .position(|other| other.distance_to(&position) <= self.epsilon)
+    }
+}
+
+/// Map a curve from the surface of one planar face to another's
+fn map_curve(curve: &Curve, from: &FaceInfo, to: &FaceInfo) -> Curve {
+    let point = |point| to.project(from.point_from_surface_coords(point));
+    let vector =
+        |vector| point(Point::origin() + vector) - point(Point::origin());
+
+    match curve {
+        Curve::Line(line) => Curve::Line(Line::from_origin_and_direction(
+            point(line.origin()),
+            vector(line.direction()),
+        )),
+        Curve::Circle(circle) => {
+            let center = point(circle.center());
+            let [a, b] = [circle.a(), circle.b()].map(vector);
+
+            // A distorting coordinate system turns the circle into an ellipse.
+            match circle_in_plane(center, a, b) {
+                Some(circle) => Curve::Circle(circle),
+                None => Curve::Ellipse(Ellipse::new(center, a, b)),
+            }
+        }
+        Curve::Bezier(bezier) => {
+            Curve::Bezier(CubicBezier::from_points(bezier.points().map(point)))
+        }
+        Curve::Ellipse(ellipse) => Curve::Ellipse(Ellipse::new(
+            point(ellipse.center()),
+            vector(ellipse.a()),
+            vector(ellipse.b()),
+        )),
+    }
+}
+
+/// Create a circle, if the vectors describe one without distortion
+fn circle_in_plane(
+    center: Point<2>,
+    a: Vector<2>,
+    b: Vector<2>,
+) -> Option<Circle<2>> {
+    let radius = a.magnitude();
+    let tolerance = Scalar::from_f64(1e-9);
+
+    if (b.magnitude() - radius).abs() > tolerance * radius
+        || a.dot(&b).abs() > tolerance * radius * radius
+    {
+        return None;
+    }
+
+    // `Circle` requires `a` and `b` to be of precisely the same length.
+    let b = Vector::from([-a.v, a.u]) * a.cross2d(&b).sign().to_scalar();
+
+    Some(Circle::new(center, a, b))
+}
+
+/// Group faces into shells, according to the vertices they share
+fn group_into_shells(
+    faces: Vec<Handle<Face>>,
+    objects: &mut Service<Objects>,
+) -> Vec<Handle<Shell>> {
+    let mut parents = (0..faces.len()).collect::<Vec<_>>();
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let mut owners = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        let vertices = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.start_vertex().global_form().id());
+
+        for vertex in vertices {
+            let owner = *owners.entry(vertex).or_insert(i);
+            let [a, b] = [root(&mut parents, owner), root(&mut parents, i)];
+            parents[a] = b;
+        }
+    }
+
+    let mut shells = BTreeMap::<usize, Vec<Handle<Face>>>::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        shells.entry(root(&mut parents, i)).or_default().push(face);
+    }
+
+    shells
+        .into_values()
+        .map(|faces| Shell::new(faces).insert(objects))
+        .collect()
+}
@@ -0,0 +1,364 @@
+//! Hollowing out objects
+//!
+//! See [`Hollow`].
+
+use std::collections::BTreeSet;
+
+use fj_interop::ext::ArrayExt;
+use fj_math::{Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance,
+        boolean::{Boolean, BooleanError, BooleanOp},
+        offset::{Join, Offset, OffsetError},
+        reverse::Reverse,
+        sweep::Sweep,
+        transform::TransformObject,
+    },
+    geometry::{curve::GlobalPath, surface::SurfaceGeometry},
+    insert::Insert,
+    objects::{Face, Objects, Sketch, Solid},
+    services::Service,
+    storage::Handle,
+};
+
+/// Hollow out an object, leaving walls of a given thickness
+///
+/// Hollowing out a solid requires boolean operations, which rely on
+/// approximations of curved geometry. This is why the trait is implemented for
+/// a tuple of the solid and the tolerance.
+///
+/// # Implementation Note
+///
+/// Only prisms can be hollowed out so far. Those are solids whose faces are
+/// either parallel or perpendicular to one direction, like solids that are
+/// created by sweeping a sketch along a straight path. Their bottom faces must
+/// all be at the same height, as must their top faces. Only bottom and top
+/// faces can be left open.
+///
+/// The inside of the walls is an offset of the outside, with sharp corners.
+/// Arcs that curve around the inside of the solid must have a radius larger
+/// than the thickness of the walls.
+///
+/// Solids that don't meet these requirements result in a [`HollowError`].
+pub trait Hollow: Sized {
+    /// The object that results from hollowing out the object
+    type Output;
+
+    /// Hollow out the object, leaving the given faces open
+    fn hollow(
+        self,
+        thickness: impl Into<Scalar>,
+        open: impl IntoIterator<Item = Handle<Face>>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output;
+}
+
+impl<T> Hollow for (Handle<Solid>, T)
+where
+    T: Into<Tolerance>,
+{
+    type Output = Result<Handle<Solid>, HollowError>;
+
+    fn hollow(
+        self,
+        thickness: impl Into<Scalar>,
+        open: impl IntoIterator<Item = Handle<Face>>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Output {
+        let (solid, tolerance) = self;
+        let thickness = thickness.into();
+        let open = open
+            .into_iter()
+            .map(|face| face.id())
+            .collect::<BTreeSet<_>>();
+
+        let faces = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Prefer the direction of the open faces, as only bottom and top faces
+        // can be left open.
+        let candidates = faces
+            .iter()
+            .filter(|face| open.contains(&face.id()))
+            .chain(&faces)
+            .filter_map(|face| plane_normal(face));
+        let axis = candidates
+            .into_iter()
+            .find(|&axis| faces.iter().all(|face| is_prism_face(face, axis)))
+            .ok_or(HollowError::NotAPrism)?;
+
+        let mut bottom = Vec::new();
+        let mut top = Vec::new();
+        for face in &faces {
+            match plane_normal(face).map(|normal| normal.dot(&axis)) {
+                Some(cosine) if cosine < -Scalar::from(0.5) => {
+                    bottom.push(face)
+                }
+                Some(cosine) if cosine > Scalar::from(0.5) => top.push(face),
+                _ => {
+                    if open.contains(&face.id()) {
+                        return Err(HollowError::OpenSideFace);
+                    }
+                }
+            }
+        }
+
+        let [bottom_height, top_height] =
+            [&bottom, &top].try_map_ext(|faces| height(faces, axis))?;
+        let [bottom_is_open, top_is_open] = [&bottom, &top]
+            .map(|faces| faces.iter().any(|face| open.contains(&face.id())));
+
+        // The inside reaches beyond open faces, so its faces don't coincide
+        // with those of the solid.
+        let start = if bottom_is_open {
+            bottom_height - thickness
+        } else {
+            bottom_height + thickness
+        };
+        let end = if top_is_open {
+            top_height + thickness
+        } else {
+            top_height - thickness
+        };
+        if start >= end {
+            return Err(HollowError::WallsTooThick);
+        }
+
+        // Sweeping treats the side of a face that its surface's normal points
+        // to as its front, which requires a counter-clockwise exterior.
+        let inside = bottom
+            .into_iter()
+            .map(|face| {
+                let face = match face.exterior().winding() {
+                    Winding::Ccw => face.clone(),
+                    Winding::Cw => face.clone().reverse(objects),
+                };
+                let face = face
+                    .offset(-thickness, Join::Miter, objects)?
+                    .ok_or(HollowError::WallsTooThick)?;
+                Ok(face.translate(axis * (start - bottom_height), objects))
+            })
+            .collect::<Result<Vec<_>, HollowError>>()?;
+        let inside = Sketch::new(inside)
+            .insert(objects)
+            .sweep(axis * (end - start), objects);
+
+        let solid =
+            solid.boolean(BooleanOp::Difference, inside, tolerance, objects)?;
+
+        Ok(solid)
+    }
+}
+
+/// A solid that can't be hollowed out
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum HollowError {
+    /// The solid is not a prism
+    #[error("Hollowing out solids that aren't prisms is not supported")]
+    NotAPrism,
+
+    /// The bottom or top faces of the prism are not at the same height
+    #[error("Hollowing out prisms with steps is not supported")]
+    Steps,
+
+    /// A face that is neither a bottom nor a top face was left open
+    #[error("Only bottom and top faces can be left open")]
+    OpenSideFace,
+
+    /// The walls leave no space inside of the solid
+    #[error("Walls are too thick for the solid")]
+    WallsTooThick,
+
+    /// The bottom faces can't be offset to the inside of the walls
+    #[error("Failed to offset the bottom of the solid")]
+    Offset(#[from] OffsetError),
+
+    /// The boolean operation that removes the inside is not supported
+    #[error("Failed to remove the inside of the solid")]
+    Boolean(#[from] BooleanError),
+}
+
+/// Check whether a face is part of a prism along the given axis
+fn is_prism_face(face: &Face, axis: Vector<3>) -> bool {
+    let epsilon = Scalar::from(1e-9);
+
+    match face.surface().geometry() {
+        SurfaceGeometry::Swept {
+            u: GlobalPath::Line(_),
+            ..
+        } => {
+            let cosine =
+                plane_normal(face).expect("Face is planar").dot(&axis).abs();
+            cosine < epsilon || cosine > Scalar::ONE - epsilon
+        }
+        SurfaceGeometry::Swept {
+            u:
+                GlobalPath::Circle(_)
+                | GlobalPath::Bezier(_)
+                | GlobalPath::Ellipse(_),
+            v,
+        } => v.normalize().dot(&axis).abs() > Scalar::ONE - epsilon,
+        SurfaceGeometry::Ruled { .. } | SurfaceGeometry::Revolved { .. } => {
+            false
+        }
+    }
+}
+
+/// Compute the height of coplanar faces along the given axis
+fn height(
+    faces: &[&Handle<Face>],
+    axis: Vector<3>,
+) -> Result<Scalar, HollowError> {
+    let epsilon = Scalar::from(1e-9);
+
+    let heights = faces
+        .iter()
+        .map(|face| {
+            let origin = face
+                .surface()
+                .geometry()
+                .point_from_surface_coords([0., 0.]);
+            axis.dot(&origin.coords)
+        })
+        .collect::<Vec<_>>();
+
+    let Some(&height) = heights.first() else {
+        return Err(HollowError::NotAPrism);
+    };
+    if heights
+        .iter()
+        .any(|other| (*other - height).abs() > epsilon)
+    {
+        return Err(HollowError::Steps);
+    }
+
+    Ok(height)
+}
+
+/// The normal of a planar face, pointing out of the solid
+fn plane_normal(face: &Face) -> Option<Vector<3>> {
+    let SurfaceGeometry::Swept {
+        u: GlobalPath::Line(line),
+        v,
+    } = face.surface().geometry()
+    else {
+        return None;
+    };
+
+    let normal = line.direction().cross(&v).normalize();
+    match face.exterior().winding() {
+        Winding::Ccw => Some(normal),
+        Winding::Cw => Some(-normal),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, sweep::Sweep},
+        objects::{Face, Solid},
+        services::Services,
+        storage::Handle,
+        test_helpers::{assert_volume, circle, polygon, square},
+    };
+
+    use super::{plane_normal, Hollow, HollowError};
+
+    #[test]
+    fn hollow_closed_box() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let solid = (solid, tolerance())
+            .hollow(0.2, [], &mut services.objects)
+            .unwrap();
+
+        // The inside is enclosed by its own shell.
+        assert_eq!(solid.shells().count(), 2);
+        assert_volume(&solid, 8. - 1.6 * 1.6 * 1.6, 0.);
+    }
+
+    #[test]
+    fn hollow_box_with_open_top() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let top = faces_facing(&solid, Vector::unit_z());
+        let solid = (solid, tolerance())
+            .hollow(0.2, top, &mut services.objects)
+            .unwrap();
+
+        assert_eq!(solid.shells().count(), 1);
+        assert_volume(&solid, 8. - 1.6 * 1.6 * 1.8, 0.);
+    }
+
+    #[test]
+    fn hollow_cylinder_with_open_bottom() {
+        let mut services = Services::new();
+
+        let solid = circle(1., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let bottom = faces_facing(&solid, -Vector::unit_z());
+        let solid = (solid, tolerance())
+            .hollow(0.2, bottom, &mut services.objects)
+            .unwrap();
+
+        assert_eq!(solid.shells().count(), 1);
+        assert_volume(&solid, PI * 2. - PI * 0.8 * 0.8 * 1.8, 0.005);
+    }
+
+    #[test]
+    fn hollow_box_with_walls_that_are_too_thick() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep([0., 0., 2.], &mut services.objects);
+        let result = (solid, tolerance()).hollow(1., [], &mut services.objects);
+
+        assert_eq!(result, Err(HollowError::WallsTooThick));
+    }
+
+    #[test]
+    fn hollow_triangular_prism_with_open_side() {
+        let mut services = Services::new();
+
+        // A box is a prism along any of its axes, so any of its faces can be
+        // left open. This prism is only one along the z-axis.
+        let solid =
+            polygon([[0., 0.], [2., 0.], [1., 2.]], &mut services.objects)
+                .sweep([0., 0., 2.], &mut services.objects);
+        let side = faces_facing(&solid, -Vector::unit_y());
+        let result =
+            (solid, tolerance()).hollow(0.2, side, &mut services.objects);
+
+        assert_eq!(result, Err(HollowError::OpenSideFace));
+    }
+
+    fn faces_facing(solid: &Solid, direction: Vector<3>) -> Vec<Handle<Face>> {
+        solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .filter(|face| {
+                plane_normal(face).map_or(false, |normal| {
+                    normal.dot(&direction) > Scalar::from(0.5)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn tolerance() -> Tolerance {
+        Tolerance::from_scalar(0.001).unwrap()
+    }
+}
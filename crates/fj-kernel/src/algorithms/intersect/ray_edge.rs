@@ -1,12 +1,16 @@
 //! Intersection between a ray and an edge in 2D
 
-use fj_math::Segment;
+use fj_math::{Point, Segment};
 
 use crate::{
-    algorithms::intersect::{HorizontalRayToTheRight, Intersect},
+    algorithms::{
+        approx::{path::RangeOnPath, Approx, Tolerance},
+        intersect::{HorizontalRayToTheRight, Intersect},
+    },
     geometry::curve::Curve,
     objects::HalfEdge,
     storage::Handle,
+    validate::ValidationConfig,
 };
 
 use super::ray_segment::RaySegmentIntersection;
@@ -17,18 +21,240 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Handle<HalfEdge>) {
     fn intersect(self) -> Option<Self::Intersection> {
         let (ray, edge) = self;
 
-        let line = match edge.curve() {
-            Curve::Line(line) => line,
-            Curve::Circle(_) => {
-                todo!("Casting rays against circles is not supported yet")
+        let curve = edge.curve();
+        let [start, end] = edge
+            .boundary()
+            .map(|point| curve.point_from_path_coords(point));
+
+        match curve {
+            Curve::Line(_) => {
+                let segment = Segment::from_points([start, end]);
+                (ray, &segment).intersect()
+            }
+            Curve::Circle(_) | Curve::Bezier(_) | Curve::Ellipse(_) => {
+                // Curves are approximated so finely, that the approximation is
+                // indistinguishable from the curve itself.
+                let tolerance = Tolerance::from_scalar(
+                    ValidationConfig::default().distinct_min_distance,
+                )
+                .expect("Minimum distance between points is positive");
+
+                let mut points = vec![start];
+                points.extend(
+                    (&curve, RangeOnPath::from(edge.boundary()))
+                        .approx(tolerance)
+                        .into_iter()
+                        .map(|(_, point)| point),
+                );
+                points.push(end);
+
+                intersect_chain(ray, &points)
+            }
+        }
+    }
+}
+
+/// Intersect a ray with a chain of line segments
+///
+/// The result describes the chain in the same terms as a single segment from
+/// its first to its last point, as far as counting the ray's passages through
+/// a polygon is concerned.
+///
+/// A chain that starts and ends on the ray is reported as parallel to the ray,
+/// which is only correct if the chain doesn't loop around the ray's origin.
+fn intersect_chain(
+    ray: &HorizontalRayToTheRight<2>,
+    points: &[Point<2>],
+) -> Option<RaySegmentIntersection> {
+    let segments = points
+        .windows(2)
+        .map(|points| Segment::from_points([points[0], points[1]]))
+        .collect::<Vec<_>>();
+    let last = segments.len() - 1;
+
+    for (i, segment) in segments.iter().enumerate() {
+        match (ray, segment).intersect() {
+            Some(RaySegmentIntersection::RayStartsOnOnFirstVertex)
+                if i == 0 =>
+            {
+                return Some(RaySegmentIntersection::RayStartsOnOnFirstVertex);
+            }
+            Some(RaySegmentIntersection::RayStartsOnSecondVertex)
+                if i == last =>
+            {
+                return Some(RaySegmentIntersection::RayStartsOnSecondVertex);
+            }
+            Some(
+                RaySegmentIntersection::RayStartsOnSegment
+                | RaySegmentIntersection::RayStartsOnOnFirstVertex
+                | RaySegmentIntersection::RayStartsOnSecondVertex,
+            ) => {
+                return Some(RaySegmentIntersection::RayStartsOnSegment);
+            }
+            _ => {}
+        }
+    }
+
+    // Count how often the chain passes the ray. Points on the ray count as
+    // being below it, so passing the ray through a point is only counted once.
+    let origin = ray.origin;
+    let is_above = |point: Point<2>| point.v > origin.v;
+    let num_passes = segments
+        .iter()
+        .filter(|segment| {
+            let [a, b] = segment.points();
+            if is_above(a) == is_above(b) {
+                return false;
+            }
+
+            let [lower, upper] = if a.v < b.v { [a, b] } else { [b, a] };
+            let orient2d = robust::orient2d(
+                robust::Coord {
+                    x: lower.u,
+                    y: lower.v,
+                },
+                robust::Coord {
+                    x: upper.u,
+                    y: upper.v,
+                },
+                robust::Coord {
+                    x: origin.u,
+                    y: origin.v,
+                },
+            );
+
+            // The ray passes the segment, if it starts left of it.
+            orient2d > 0.
+        })
+        .count();
+
+    let is_on_ray = |point: Point<2>| point.v == origin.v && point.u > origin.u;
+    let [start, end] = [points[0], points[points.len() - 1]];
+
+    match (is_on_ray(start), is_on_ray(end)) {
+        (false, false) => (num_passes % 2 == 1)
+            .then_some(RaySegmentIntersection::RayHitsSegment),
+        (true, true) => {
+            Some(RaySegmentIntersection::RayHitsSegmentAndAreParallel)
+        }
+        _ => {
+            // If the chain passes the ray an even number of times, it is below
+            // the ray next to the vertex that is on it. That makes the vertex
+            // the upper one.
+            if num_passes % 2 == 0 {
+                Some(RaySegmentIntersection::RayHitsUpperVertex)
+            } else {
+                Some(RaySegmentIntersection::RayHitsLowerVertex)
             }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        algorithms::intersect::{
+            ray_segment::RaySegmentIntersection, HorizontalRayToTheRight,
+            Intersect,
+        },
+        builder::{CycleBuilder, HalfEdgeBuilder},
+        objects::{HalfEdge, Objects},
+        partial::{Partial, PartialFace, PartialHalfEdge, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    #[test]
+    fn ray_and_bezier() {
+        let mut services = Services::new();
+
+        // The curve bulges to the right of its end points, up to `u = 3`.
+        let half_edge =
+            curved_edge([[0., 0.], [0., 2.]], &mut services.objects, |edge| {
+                edge.update_as_bezier([[4., 0.], [4., 2.]])
+            });
+
+        let expected = [
+            ([1., 1.], Some(RaySegmentIntersection::RayHitsSegment)),
+            ([-1., 1.], Some(RaySegmentIntersection::RayHitsSegment)),
+            ([4., 1.], None),
+            ([-1., 3.], None),
+            (
+                [0., 0.],
+                Some(RaySegmentIntersection::RayStartsOnOnFirstVertex),
+            ),
+            ([-1., 2.], Some(RaySegmentIntersection::RayHitsUpperVertex)),
+        ];
+        for (origin, intersection) in expected {
+            let ray = HorizontalRayToTheRight::from(origin);
+            assert_eq!((&ray, &half_edge).intersect(), intersection);
+        }
+    }
+
+    #[test]
+    fn ray_and_arc() {
+        let mut services = Services::new();
+
+        // A half circle that bulges to the left of its end points.
+        let half_edge =
+            curved_edge([[0., 1.], [0., -1.]], &mut services.objects, |edge| {
+                edge.update_as_arc(PI)
+            });
+
+        let expected = [
+            ([-2., 0.], Some(RaySegmentIntersection::RayHitsSegment)),
+            ([-0.5, 0.], None),
+            ([-1., 2.], None),
+        ];
+        for (origin, intersection) in expected {
+            let ray = HorizontalRayToTheRight::from(origin);
+            assert_eq!((&ray, &half_edge).intersect(), intersection);
+        }
+    }
+
+    #[test]
+    fn ray_and_ellipse() {
+        let mut services = Services::new();
+
+        let mut face = PartialFace {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
         };
+        let mut half_edge = face.exterior.write().add_half_edge();
+        half_edge
+            .write()
+            .update_as_ellipse_from_center_and_radii([0., 0.], [2., 1.]);
+        let face = face.build(&mut services.objects);
+        let half_edge = face.exterior().half_edges().next().unwrap();
 
-        let points = edge
-            .boundary()
-            .map(|point| line.point_from_line_coords(point));
-        let segment = Segment::from_points(points);
+        let expected = [
+            ([0., 0.5], Some(RaySegmentIntersection::RayHitsSegment)),
+            ([-3., 0.5], None),
+            ([0., 2.], None),
+        ];
+        for (origin, intersection) in expected {
+            let ray = HorizontalRayToTheRight::from(origin);
+            assert_eq!((&ray, half_edge).intersect(), intersection);
+        }
+    }
+
+    fn curved_edge(
+        points: [[f64; 2]; 2],
+        objects: &mut Service<Objects>,
+        update: impl FnOnce(&mut PartialHalfEdge),
+    ) -> Handle<HalfEdge> {
+        let mut face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        let [mut edge, _] =
+            face.exterior.write().update_as_polygon_from_points(points);
+        update(&mut edge.write());
 
-        (ray, &segment).intersect()
+        let face = face.build(objects);
+        let half_edge = face.exterior().half_edges().next().unwrap();
+        half_edge.clone()
     }
 }
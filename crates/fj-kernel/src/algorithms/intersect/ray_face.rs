@@ -4,7 +4,7 @@ use fj_math::{Plane, Point, Scalar};
 
 use crate::{
     algorithms::intersect::face_point::FacePointIntersection,
-    geometry::curve::GlobalPath,
+    geometry::{curve::GlobalPath, surface::SurfaceGeometry},
     objects::{Face, HalfEdge, SurfaceVertex},
     storage::Handle,
 };
@@ -17,14 +17,35 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Handle<Face>) {
     fn intersect(self) -> Option<Self::Intersection> {
         let (ray, face) = self;
 
-        let plane = match face.surface().geometry().u {
-            GlobalPath::Circle(_) => todo!(
+        let plane = match face.surface().geometry() {
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Circle(_),
+                ..
+            } => todo!(
                 "Casting a ray against a swept circle is not supported yet"
             ),
-            GlobalPath::Line(line) => Plane::from_parametric(
-                line.origin(),
-                line.direction(),
-                face.surface().geometry().v,
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Bezier(_),
+                ..
+            } => todo!(
+                "Casting a ray against a swept Bézier curve is not supported \
+                yet"
+            ),
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Ellipse(_),
+                ..
+            } => todo!(
+                "Casting a ray against a swept ellipse is not supported yet"
+            ),
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Line(line),
+                v,
+            } => Plane::from_parametric(line.origin(), line.direction(), v),
+            SurfaceGeometry::Ruled { .. } => todo!(
+                "Casting a ray against a ruled surface is not supported yet"
+            ),
+            SurfaceGeometry::Revolved { .. } => todo!(
+                "Casting a ray against a revolved surface is not supported yet"
             ),
         };
 
@@ -1,7 +1,10 @@
 use fj_math::{Line, Plane, Point, Scalar};
 
 use crate::{
-    geometry::curve::{Curve, GlobalPath},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
     objects::Surface,
     storage::Handle,
 };
@@ -57,13 +60,12 @@ impl SurfaceSurfaceIntersection {
 }
 
 fn plane_from_surface(surface: &Surface) -> Plane {
-    let (line, path) = {
-        let line = match surface.geometry().u {
-            GlobalPath::Line(line) => line,
-            _ => todo!("Only plane-plane intersection is currently supported."),
-        };
-
-        (line, surface.geometry().v)
+    let (line, path) = match surface.geometry() {
+        SurfaceGeometry::Swept {
+            u: GlobalPath::Line(line),
+            v,
+        } => (line, v),
+        _ => todo!("Only plane-plane intersection is currently supported."),
     };
 
     Plane::from_parametric(line.origin(), line.direction(), path)
@@ -0,0 +1,459 @@
+use std::collections::BTreeMap;
+
+use fj_interop::{ext::ArrayExt, mesh::Color};
+use fj_math::{Line, Point, Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::{
+        reverse::Reverse,
+        revolve::{cycle_from_corners, EdgeCurve},
+    },
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects, Shell,
+        Surface,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::{Loft, LoftError};
+
+impl Loft for Vec<Handle<Face>> {
+    type Lofted = Result<Handle<Shell>, LoftError>;
+
+    fn loft(self, objects: &mut Service<Objects>) -> Self::Lofted {
+        if self.len() < 2 {
+            return Err(LoftError::TooFewSections);
+        }
+        let [first, last] = [&self[0], &self[self.len() - 1]];
+        let normals = self.iter().map(normal).collect::<Result<Vec<_>, _>>()?;
+
+        // The sections must face the direction in which the loft advances, or
+        // all face against it. This decides the orientation of the faces.
+        let direction = start_of(last) - start_of(first);
+        let is_upward = normals[0].dot(&direction) > Scalar::ZERO;
+        if !normals
+            .iter()
+            .all(|normal| (normal.dot(&direction) > Scalar::ZERO) == is_upward)
+        {
+            return Err(LoftError::DifferentFacing);
+        }
+
+        // Each section must be located further along than the previous one.
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+        for (i, sections) in self.windows(2).enumerate() {
+            let distance = normals[i]
+                .dot(&(start_of(&sections[1]) - start_of(&sections[0])));
+            let distance = if is_upward { distance } else { -distance };
+            if distance <= epsilon {
+                return Err(LoftError::NotAdvancing);
+            }
+        }
+
+        let num_cycles = first.all_cycles().count();
+        if !self
+            .iter()
+            .all(|section| section.all_cycles().count() == num_cycles)
+        {
+            return Err(LoftError::DifferentHoles);
+        }
+
+        // Split matching cycles into the same number of pieces, so the pieces
+        // can be connected one by one.
+        let mut pieces = vec![Vec::new(); self.len()];
+        for i in 0..num_cycles {
+            let cycles = self
+                .iter()
+                .map(|section| {
+                    let cycle = section
+                        .all_cycles()
+                        .nth(i)
+                        .expect("Number of cycles has been checked");
+                    (cycle, section.surface().geometry())
+                })
+                .collect::<Vec<_>>();
+
+            for (pieces, cycle) in
+                pieces.iter_mut().zip(split_cycles(&cycles, objects))
+            {
+                pieces.push(cycle);
+            }
+        }
+
+        let mut faces = Vec::new();
+
+        let bottom_face = cap(first, &pieces[0], objects);
+        faces.push(if is_upward {
+            bottom_face.reverse(objects)
+        } else {
+            bottom_face
+        });
+
+        for (i, sections) in self.windows(2).enumerate() {
+            let [lower, upper] = [&pieces[i], &pieces[i + 1]];
+            let [from, to] = if is_upward {
+                [lower, upper]
+            } else {
+                [upper, lower]
+            };
+
+            let mut rulings = BTreeMap::new();
+            for (from, to) in from.iter().zip(to) {
+                for (a, b) in from.iter().zip(to) {
+                    let face = ruled_face(
+                        a,
+                        b,
+                        sections[0].color(),
+                        &mut rulings,
+                        objects,
+                    )?;
+                    faces.push(face);
+                }
+            }
+        }
+
+        let top_face = cap(last, &pieces[pieces.len() - 1], objects);
+        faces.push(if is_upward {
+            top_face
+        } else {
+            top_face.reverse(objects)
+        });
+
+        Ok(Shell::new(faces).insert(objects))
+    }
+}
+
+/// A piece of a cycle that is connected to a piece of a neighboring section
+#[derive(Clone)]
+struct Piece {
+    curve: Curve,
+    boundary: [Point<1>; 2],
+    path: GlobalPath,
+    vertices: [(Point<2>, Handle<GlobalVertex>); 2],
+    global_edge: Handle<GlobalEdge>,
+}
+
+/// Split each cycle into pieces, at the same fractions of their lengths
+///
+/// The fractions are measured from the first vertex of each cycle. Each cycle
+/// is split wherever any of the cycles has a vertex.
+fn split_cycles(
+    cycles: &[(&Handle<Cycle>, SurfaceGeometry)],
+    objects: &mut Service<Objects>,
+) -> Vec<Vec<Piece>> {
+    let epsilon = Scalar::from_f64(1e-9);
+
+    let fractions = cycles
+        .iter()
+        .map(|(cycle, surface)| {
+            let lengths = cycle
+                .half_edges()
+                .map(|half_edge| {
+                    let path =
+                        surface.path_from_surface_curve(half_edge.curve());
+                    length(path, half_edge.boundary())
+                })
+                .collect::<Vec<_>>();
+            let total = lengths.iter().fold(Scalar::ZERO, |sum, l| sum + *l);
+
+            let mut position = Scalar::ZERO;
+            lengths
+                .into_iter()
+                .map(|length| {
+                    let fraction = position / total;
+                    position += length;
+                    fraction
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut splits = fractions.iter().flatten().copied().collect::<Vec<_>>();
+    splits.sort();
+    splits.dedup_by(|a, b| *a - *b <= epsilon);
+
+    cycles
+        .iter()
+        .zip(&fractions)
+        .map(|((cycle, surface), fractions)| {
+            let half_edges = cycle.half_edges().collect::<Vec<_>>();
+
+            // Find out where each split lies on the cycle. That's either at
+            // the start of an edge, or somewhere within it.
+            let points = splits
+                .iter()
+                .map(|&split| {
+                    let i = fractions
+                        .iter()
+                        .rposition(|&fraction| fraction <= split + epsilon)
+                        .expect("First fraction is zero");
+                    let half_edge = half_edges[i];
+
+                    if split - fractions[i] <= epsilon {
+                        let vertex = half_edge.start_vertex();
+                        let point = SplitPoint {
+                            half_edge: i,
+                            t: half_edge.boundary()[0],
+                            is_vertex: true,
+                        };
+                        let vertex =
+                            (vertex.position(), vertex.global_form().clone());
+                        return (point, vertex);
+                    }
+
+                    let end =
+                        fractions.get(i + 1).copied().unwrap_or(Scalar::ONE);
+                    let ratio = (split - fractions[i]) / (end - fractions[i]);
+                    let [a, b] = half_edge.boundary();
+                    let t = a + (b - a) * ratio;
+
+                    let position = half_edge.curve().point_from_path_coords(t);
+                    let global_form = GlobalVertex::new(
+                        surface.point_from_surface_coords(position),
+                    )
+                    .insert(objects);
+
+                    let point = SplitPoint {
+                        half_edge: i,
+                        t,
+                        is_vertex: false,
+                    };
+                    (point, (position, global_form))
+                })
+                .collect::<Vec<_>>();
+
+            (0..points.len())
+                .map(|i| {
+                    let (start, start_vertex) = &points[i];
+                    let (end, end_vertex) = &points[(i + 1) % points.len()];
+                    let half_edge: &Handle<HalfEdge> =
+                        half_edges[start.half_edge];
+
+                    let boundary = [
+                        start.t,
+                        if end.is_vertex {
+                            half_edge.boundary()[1]
+                        } else {
+                            end.t
+                        },
+                    ];
+
+                    let global_edge = if start.is_vertex && end.is_vertex {
+                        half_edge.global_form().clone()
+                    } else {
+                        GlobalEdge::new([
+                            start_vertex.1.clone(),
+                            end_vertex.1.clone(),
+                        ])
+                        .insert(objects)
+                    };
+
+                    Piece {
+                        curve: half_edge.curve(),
+                        boundary,
+                        path: surface
+                            .path_from_surface_curve(half_edge.curve()),
+                        vertices: [start_vertex.clone(), end_vertex.clone()],
+                        global_edge,
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Where a cycle is split
+struct SplitPoint {
+    half_edge: usize,
+    t: Point<1>,
+    is_vertex: bool,
+}
+
+/// Create a copy of the section, made from the pieces of its cycles
+fn cap(
+    section: &Face,
+    pieces: &[Vec<Piece>],
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    let mut cycles = pieces.iter().map(|pieces| {
+        let corners = pieces
+            .iter()
+            .map(|piece| piece.vertices[0].clone())
+            .collect();
+        let edges = pieces
+            .iter()
+            .map(|piece| {
+                let curve: EdgeCurve = Some((piece.curve, piece.boundary));
+                (curve, piece.global_edge.clone())
+            })
+            .collect();
+
+        cycle_from_corners(corners, edges, objects)
+    });
+
+    let exterior = cycles.next().expect("Face has an exterior");
+    let interiors = cycles.collect::<Vec<_>>();
+
+    Face::new(
+        section.surface().clone(),
+        exterior,
+        interiors,
+        section.color(),
+    )
+    .insert(objects)
+}
+
+/// Create the ruled face that connects two pieces
+///
+/// The face is oriented, as if `a` was below `b`, and the pieces ran
+/// counter-clockwise when looking from above.
+fn ruled_face(
+    a: &Piece,
+    b: &Piece,
+    color: Color,
+    rulings: &mut BTreeMap<(ObjectId, ObjectId), Handle<GlobalEdge>>,
+    objects: &mut Service<Objects>,
+) -> Result<Handle<Face>, LoftError> {
+    // Both paths must share their u-coordinates. The coordinates of circles
+    // and Bézier curves can't be changed, as they must match the approximation
+    // of the same curves in other faces. Lines are adapted to them.
+    let [start, end] = match (a.path, b.path) {
+        (GlobalPath::Line(_), GlobalPath::Line(_)) => {
+            [Scalar::ZERO, Scalar::ONE]
+        }
+        (_, GlobalPath::Line(_)) => a.boundary.map(|point| point.t),
+        (GlobalPath::Line(_), _) => b.boundary.map(|point| point.t),
+        _ => {
+            let epsilon = Scalar::from_f64(1e-9);
+            let matches = a
+                .boundary
+                .zip_ext(b.boundary)
+                .into_iter()
+                .all(|(a, b)| (a.t - b.t).abs() <= epsilon);
+            if !matches {
+                return Err(LoftError::DifferentCurveRanges);
+            }
+
+            a.boundary.map(|point| point.t)
+        }
+    };
+    let path = |piece: &Piece| match piece.path {
+        GlobalPath::Circle(_)
+        | GlobalPath::Bezier(_)
+        | GlobalPath::Ellipse(_) => piece.path,
+        GlobalPath::Line(_) => {
+            let points = [start, end]
+                .zip_ext(piece.boundary)
+                .map(|(u, t)| ([u], piece.path.point_from_path_coords(t)));
+            GlobalPath::Line(Line::from_points_with_line_coords(points))
+        }
+    };
+
+    let surface = Surface::new(SurfaceGeometry::Ruled {
+        a: path(a),
+        b: path(b),
+    })
+    .insert(objects);
+
+    let mut ruling = |[a, b]: [&Handle<GlobalVertex>; 2]| {
+        rulings
+            .entry((a.id(), b.id()))
+            .or_insert_with(|| {
+                GlobalEdge::new([a.clone(), b.clone()]).insert(objects)
+            })
+            .clone()
+    };
+    let rulings = [0, 1].map(|i| ruling([&a.vertices[i].1, &b.vertices[i].1]));
+
+    // The edges along the pieces keep the boundaries they have in the
+    // sections, which makes sure they share their approximations.
+    let line = |v: Scalar, boundary: [Point<1>; 2], u: [Scalar; 2]| {
+        let points = boundary.zip_ext(u).map(|(t, u)| (t, Point::from([u, v])));
+        let curve: EdgeCurve =
+            Some((Curve::from_points_with_line_coords(points), boundary));
+        curve
+    };
+    let [b_start, b_end] = b.boundary;
+
+    let corners = vec![
+        (Point::from([start, Scalar::ZERO]), a.vertices[0].1.clone()),
+        (Point::from([end, Scalar::ZERO]), a.vertices[1].1.clone()),
+        (Point::from([end, Scalar::ONE]), b.vertices[1].1.clone()),
+        (Point::from([start, Scalar::ONE]), b.vertices[0].1.clone()),
+    ];
+    let [start_ruling, end_ruling] = rulings;
+    let edges = vec![
+        (
+            line(Scalar::ZERO, a.boundary, [start, end]),
+            a.global_edge.clone(),
+        ),
+        (None, end_ruling),
+        (
+            line(Scalar::ONE, [b_end, b_start], [end, start]),
+            b.global_edge.clone(),
+        ),
+        (None, start_ruling),
+    ];
+
+    let exterior = cycle_from_corners(corners, edges, objects);
+    Ok(Face::new(surface, exterior, [], color).insert(objects))
+}
+
+/// Compute the length of the path between the given boundary
+fn length(path: GlobalPath, [a, b]: [Point<1>; 2]) -> Scalar {
+    match path {
+        GlobalPath::Circle(circle) => circle.radius() * (b.t - a.t).abs(),
+        GlobalPath::Line(_) => (path.point_from_path_coords(b)
+            - path.point_from_path_coords(a))
+        .magnitude(),
+        GlobalPath::Bezier(_) | GlobalPath::Ellipse(_) => {
+            // Neither Bézier curves nor ellipses have a closed-form length. A
+            // polyline through points on the curve is close enough to split
+            // the cycles evenly.
+            let num_segments = 16;
+            let points = (0..=num_segments).map(|i| {
+                let t = a.t
+                    + (b.t - a.t) * (f64::from(i) / f64::from(num_segments));
+                path.point_from_path_coords([t])
+            });
+
+            points
+                .clone()
+                .zip(points.skip(1))
+                .fold(Scalar::ZERO, |sum, (a, b)| sum + a.distance_to(&b))
+        }
+    }
+}
+
+fn start_of(section: &Face) -> Point<3> {
+    section
+        .exterior()
+        .half_edges()
+        .next()
+        .expect("Cycle has at least one half-edge")
+        .start_vertex()
+        .global_form()
+        .position()
+}
+
+fn normal(section: &Handle<Face>) -> Result<Vector<3>, LoftError> {
+    let SurfaceGeometry::Swept {
+        u: GlobalPath::Line(line),
+        v,
+    } = section.surface().geometry()
+    else {
+        return Err(LoftError::CurvedSurface);
+    };
+
+    let normal = line.direction().cross(&v).normalize();
+    Ok(match section.exterior().winding() {
+        Winding::Ccw => normal,
+        Winding::Cw => -normal,
+    })
+}
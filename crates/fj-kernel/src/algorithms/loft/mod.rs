@@ -0,0 +1,290 @@
+//! Lofting between objects to create new objects
+
+mod face;
+mod sketch;
+
+use crate::objects::Objects;
+use crate::services::Service;
+
+/// Loft between a number of objects to create another object
+pub trait Loft {
+    /// The object that is created by lofting between the implementing objects
+    type Lofted;
+
+    /// Loft between the objects, in the order they are provided in
+    ///
+    /// Each object is a section of the result. Matching cycles of neighboring
+    /// sections are connected by ruled faces. To find out what matches, cycles
+    /// are split at the same fractions of their lengths, starting at their
+    /// first vertex.
+    ///
+    /// All sections must be planar, face the same way, and must have the same
+    /// number of holes. Each section must be offset from the previous one, in
+    /// the direction the loft advances. Otherwise, the result is a
+    /// [`LoftError`].
+    fn loft(self, objects: &mut Service<Objects>) -> Self::Lofted;
+}
+
+/// Sections that can't be lofted between
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LoftError {
+    /// There are fewer than two sections
+    #[error("Lofting requires at least two sections")]
+    TooFewSections,
+
+    /// A section is not located in a plane
+    #[error("Lofting between faces in curved surfaces is not supported")]
+    CurvedSurface,
+
+    /// The sections don't all face the same way
+    #[error("All sections of a loft must face the same way")]
+    DifferentFacing,
+
+    /// A section is not offset from the previous one
+    #[error(
+        "Each section of a loft must be offset from the previous one, in the \
+        direction the loft advances"
+    )]
+    NotAdvancing,
+
+    /// The sections have different numbers of holes
+    #[error("All sections of a loft must have the same number of holes")]
+    DifferentHoles,
+
+    /// The sections are made of different numbers of faces
+    #[error("All sections of a loft must have the same number of faces")]
+    DifferentFaceCount,
+
+    /// Two matching arcs cover different ranges of angles
+    #[error(
+        "Lofting between curves that don't cover the same range of \
+        coordinates is not supported"
+    )]
+    DifferentCurveRanges,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        builder::{CycleBuilder, HalfEdgeBuilder},
+        insert::Insert,
+        objects::{Objects, Sketch},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+        test_helpers::{self, assert_volume, volume},
+    };
+
+    use super::{Loft, LoftError};
+
+    #[test]
+    fn frustum_between_squares() {
+        let mut services = Services::new();
+
+        let sections = vec![
+            polygon(&square(1.), 0., &mut services.objects),
+            polygon(&square(0.5), 1., &mut services.objects),
+        ];
+        let solid = sections.loft(&mut services.objects).unwrap();
+
+        assert_volume(&solid, 7. / 3., 0.);
+    }
+
+    #[test]
+    fn more_than_two_sections() {
+        let mut services = Services::new();
+
+        let sections = vec![
+            polygon(&square(1.), 0., &mut services.objects),
+            polygon(&square(0.5), 1., &mut services.objects),
+            polygon(&square(1.), 2., &mut services.objects),
+        ];
+        let solid = sections.loft(&mut services.objects).unwrap();
+
+        assert_volume(&solid, 14. / 3., 0.);
+    }
+
+    #[test]
+    fn frustum_between_circles() {
+        let mut services = Services::new();
+
+        let sections = vec![
+            circle(1., 0., &mut services.objects),
+            circle(0.5, 1., &mut services.objects),
+        ];
+        let solid = sections.loft(&mut services.objects).unwrap();
+
+        assert_volume(&solid, PI / 3. * (1. + 0.5 + 0.25), 0.005);
+    }
+
+    #[test]
+    fn circle_to_square() {
+        let mut services = Services::new();
+
+        // The square starts where the circle starts, so the sections line up.
+        let sections = vec![
+            circle(1., 0., &mut services.objects),
+            polygon(
+                &[[1., 0.], [1., 1.], [-1., 1.], [-1., -1.], [1., -1.]],
+                1.,
+                &mut services.objects,
+            ),
+        ];
+        let solid = sections.loft(&mut services.objects).unwrap();
+
+        // Each side face bends outwards from the circle to the square. The
+        // volume is somewhere between the volume of the cylinder and that of
+        // the cuboid.
+        let volume = volume(&solid, 0.001);
+        assert!(volume > Scalar::from(PI), "{volume}");
+        assert!(volume < Scalar::from(4.), "{volume}");
+    }
+
+    #[test]
+    fn sections_facing_downwards() {
+        let mut services = Services::new();
+
+        let sections = vec![
+            polygon(&square(1.), 1., &mut services.objects),
+            polygon(&square(0.5), 0., &mut services.objects),
+        ];
+        let solid = sections.loft(&mut services.objects).unwrap();
+
+        assert_volume(&solid, 7. / 3., 0.);
+    }
+
+    #[test]
+    fn invalid_sections() {
+        let mut services = Services::new();
+        let objects = &mut services.objects;
+
+        let sections = vec![polygon(&square(1.), 0., objects)];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::TooFewSections)
+        );
+
+        let sections = vec![
+            polygon(&square(1.), 0., objects),
+            polygon(&square(1.), 0., objects),
+        ];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::NotAdvancing)
+        );
+
+        let sections = vec![
+            polygon(&square(1.), 0., objects),
+            polygon(&square(1.), 2., objects),
+            polygon(&square(1.), 1., objects),
+        ];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::NotAdvancing)
+        );
+
+        let mut reversed = square(1.);
+        reversed.reverse();
+        let sections = vec![
+            polygon(&square(1.), 0., objects),
+            polygon(&reversed, 1., objects),
+        ];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::DifferentFacing)
+        );
+
+        let sections = vec![
+            polygon(&square(1.), 0., objects),
+            Sketch::new([])
+                .insert(objects)
+                .translate(Vector::from([0., 0., 1.]), objects),
+        ];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::DifferentFaceCount)
+        );
+
+        // The half circle covers a different range of angles than the full
+        // circle.
+        let sections = vec![circle(1., 0., objects), half_circle(1., objects)];
+        assert_eq!(
+            sections.loft(objects).map(|_| ()),
+            Err(LoftError::DifferentCurveRanges)
+        );
+    }
+
+    #[test]
+    fn faces_matched_by_order() {
+        let mut services = Services::new();
+        let objects = &mut services.objects;
+
+        // The small square is located left of the large one in the first
+        // section, and right of it in the second. Faces are matched by their
+        // order in the sections, not by their location.
+        let mut section = |small_x: f64, z: f64| {
+            [(square(1.), 0.), (square(0.5), small_x)]
+                .into_iter()
+                .map(|(points, x)| {
+                    polygon(&points, z, objects)
+                        .translate(Vector::from([x, 0., 0.]), objects)
+                        .faces()
+                        .into_iter()
+                        .next()
+                        .unwrap()
+                        .clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        let sections = vec![section(-3., 0.), section(3., 1.)];
+        let solid = sections.loft(objects).unwrap();
+
+        // The small square is sheared, which doesn't change its volume.
+        assert_volume(&solid, 4. + 1., 0.);
+    }
+
+    fn square(half_size: f64) -> [[f64; 2]; 4] {
+        let s = half_size;
+        [[-s, -s], [s, -s], [s, s], [-s, s]]
+    }
+
+    fn polygon(
+        points: &[[f64; 2]],
+        z: f64,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        test_helpers::polygon(points.to_vec(), objects)
+            .translate(Vector::from([0., 0., z]), objects)
+    }
+
+    fn circle(
+        radius: f64,
+        z: f64,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        test_helpers::circle(radius, objects)
+            .translate(Vector::from([0., 0., z]), objects)
+    }
+
+    fn half_circle(z: f64, objects: &mut Service<Objects>) -> Handle<Sketch> {
+        let mut face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        let [mut arc, _] = face
+            .exterior
+            .write()
+            .update_as_polygon_from_points([[1., 0.], [-1., 0.]]);
+        arc.write().update_as_arc(PI);
+        let face = face.build(objects).insert(objects);
+
+        Sketch::new([face])
+            .insert(objects)
+            .translate(Vector::from([0., 0., z]), objects)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    insert::Insert,
+    objects::{Face, Objects, Sketch, Solid},
+    services::Service,
+    storage::Handle,
+};
+
+use super::{Loft, LoftError};
+
+// Sections that are made of multiple faces. Faces of different sections are
+// matched by their order within the sections.
+impl Loft for Vec<Vec<Handle<Face>>> {
+    type Lofted = Result<Handle<Solid>, LoftError>;
+
+    fn loft(self, objects: &mut Service<Objects>) -> Self::Lofted {
+        if self.len() < 2 {
+            return Err(LoftError::TooFewSections);
+        }
+
+        let num_faces = self.first().map(Vec::len).unwrap_or_default();
+        if !self.iter().all(|faces| faces.len() == num_faces) {
+            return Err(LoftError::DifferentFaceCount);
+        }
+
+        let mut shells = Vec::new();
+        for i in 0..num_faces {
+            let faces = self
+                .iter()
+                .map(|faces| faces[i].clone())
+                .collect::<Vec<_>>();
+            shells.push(faces.loft(objects)?);
+        }
+
+        Ok(Solid::new(shells).insert(objects))
+    }
+}
+
+// Sketches don't keep their faces in the order they were created in. To control
+// which faces are matched, loft between the faces directly.
+impl Loft for Vec<Handle<Sketch>> {
+    type Lofted = Result<Handle<Solid>, LoftError>;
+
+    fn loft(self, objects: &mut Service<Objects>) -> Self::Lofted {
+        self.iter()
+            .map(|sketch| sketch.faces().into_iter().cloned().collect())
+            .collect::<Vec<Vec<_>>>()
+            .loft(objects)
+    }
+}
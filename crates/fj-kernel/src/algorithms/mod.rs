@@ -4,8 +4,15 @@
 //! on their respective purpose.
 
 pub mod approx;
+pub mod blend;
+pub mod boolean;
+pub mod hollow;
 pub mod intersect;
+pub mod loft;
+pub mod offset;
 pub mod reverse;
+pub mod revolve;
+pub mod select;
 pub mod sweep;
 pub mod transform;
 pub mod triangulate;
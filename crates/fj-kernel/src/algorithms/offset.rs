@@ -0,0 +1,798 @@
+//! Offsetting objects
+//!
+//! See [`Offset`].
+
+use fj_math::{Circle, Line, Point, Scalar, Vector, Winding};
+use itertools::Itertools;
+
+use crate::{
+    algorithms::revolve::{cycle_from_corners, EdgeCurve},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects, Sketch,
+        Surface,
+    },
+    services::Service,
+    storage::Handle,
+};
+
+/// Offset an object, moving its boundary by a distance
+///
+/// Cycles that collapse, because the distance is larger than they are wide, are
+/// dropped. If the exterior of a face collapses, the whole face is dropped.
+///
+/// # Limitations
+///
+/// Faces must be planar, and their edges must be lines or circles. No edge may
+/// vanish, unless its whole cycle collapses. Otherwise, the result is an
+/// [`OffsetError`]. Faces and cycles that grow into each other are not merged.
+pub trait Offset: Sized {
+    /// The object that is created by offsetting the implementing object
+    type Offset;
+
+    /// Offset the object by the given distance
+    ///
+    /// Positive distances grow the object, negative distances shrink it.
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        join: Join,
+        objects: &mut Service<Objects>,
+    ) -> Self::Offset;
+}
+
+/// How offset edges are joined, where they move apart
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Join {
+    /// Extend the edges until they meet, keeping the corner sharp
+    Miter,
+
+    /// Connect the edges with an arc around the original corner
+    Round,
+}
+
+/// An object that can't be offset
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OffsetError {
+    /// A face is not located in a plane
+    #[error("Offsetting faces in curved surfaces is not supported")]
+    CurvedSurface,
+
+    /// The coordinate system of a face's surface is distorted
+    #[error("Offsetting faces in distorted surfaces is not supported")]
+    DistortedSurface,
+
+    /// An edge is neither a line nor a circle
+    #[error("Offsetting Bézier curves and ellipses is not supported")]
+    UnsupportedCurve,
+
+    /// An edge vanished at the given position, without its cycle collapsing
+    #[error("Offset is too large for the edge at {0:?}")]
+    EdgeVanished(Point<3>),
+}
+
+impl Offset for Handle<Face> {
+    /// The offset face, or `None`, if its exterior collapsed
+    type Offset = Result<Option<Handle<Face>>, OffsetError>;
+
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        join: Join,
+        objects: &mut Service<Objects>,
+    ) -> Self::Offset {
+        let distance = distance.into();
+
+        let (u, v) = match self.surface().geometry() {
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Line(line),
+                v,
+            } => (line.direction(), v),
+            _ => return Err(OffsetError::CurvedSurface),
+        };
+
+        // The offset is computed in surface coordinates, which only works,
+        // if they aren't distorted.
+        let epsilon = Scalar::from_f64(1e-9) * u.magnitude();
+        if u.dot(&v).abs() > epsilon * v.magnitude()
+            || (u.magnitude() - v.magnitude()).abs() > epsilon
+        {
+            return Err(OffsetError::DistortedSurface);
+        }
+
+        // Cycles are offset to their left, which is the inside of the face, if
+        // its exterior is counter-clockwise.
+        let offset = -distance / u.magnitude()
+            * match self.exterior().winding() {
+                Winding::Ccw => Scalar::ONE,
+                Winding::Cw => -Scalar::ONE,
+            };
+
+        let surface = self.surface();
+        let mut offset_cycle = |cycle: &Handle<Cycle>| {
+            let offset_cycle = match OffsetCycle::new(cycle, offset, join) {
+                Ok(offset_cycle) => offset_cycle,
+                Err(OffsetCycleError::Collapsed) => return Ok(None),
+                Err(OffsetCycleError::UnsupportedCurve) => {
+                    return Err(OffsetError::UnsupportedCurve)
+                }
+                Err(OffsetCycleError::EdgeVanished(position)) => {
+                    return Err(OffsetError::EdgeVanished(
+                        surface.geometry().point_from_surface_coords(position),
+                    ))
+                }
+            };
+            let vertices = offset_cycle.global_vertices(surface, objects);
+            let global_edges = global_edges_between(&vertices, objects);
+            Ok(Some(offset_cycle.cycle(vertices, global_edges, objects)))
+        };
+
+        let Some(exterior) = offset_cycle(self.exterior())? else {
+            return Ok(None);
+        };
+        let mut interiors = Vec::new();
+        for interior in self.interiors() {
+            interiors.extend(offset_cycle(interior)?);
+        }
+
+        let face =
+            Face::new(surface.clone(), exterior, interiors, self.color())
+                .insert(objects);
+        Ok(Some(face))
+    }
+}
+
+impl Offset for Handle<Sketch> {
+    type Offset = Result<Handle<Sketch>, OffsetError>;
+
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        join: Join,
+        objects: &mut Service<Objects>,
+    ) -> Self::Offset {
+        let distance = distance.into();
+
+        let mut faces = Vec::new();
+        for face in self.faces() {
+            faces.extend(face.clone().offset(distance, join, objects)?);
+        }
+
+        Ok(Sketch::new(faces).insert(objects))
+    }
+}
+
+/// A cycle, offset to its left
+pub(crate) struct OffsetCycle {
+    /// The offset positions of the cycle's vertices
+    pub points: Vec<Point<2>>,
+
+    /// The offset curves of the cycle's edges, and their boundaries
+    pub edges: Vec<(Curve, [Point<1>; 2])>,
+}
+
+impl OffsetCycle {
+    /// Offset a cycle in the given surface
+    ///
+    /// The offset is given in surface coordinates.
+    ///
+    /// With mitered joins, each edge corresponds to the half-edge of the
+    /// original cycle with the same index.
+    pub fn new(
+        cycle: &Cycle,
+        offset: Scalar,
+        join: Join,
+    ) -> Result<Self, OffsetCycleError> {
+        let half_edges = cycle.half_edges().collect::<Vec<_>>();
+        let num_half_edges = half_edges.len();
+        let curves = half_edges
+            .iter()
+            .map(|half_edge| OffsetCurve::new(half_edge, offset))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tangents = half_edges
+            .iter()
+            .map(|half_edge| tangents(half_edge))
+            .collect::<Vec<_>>();
+
+        // Each vertex is moved to where the offset curves of its edges meet,
+        // unless those move apart and are joined by an arc. Then there are two
+        // points: The end of the previous edge, and the start of the next one.
+        let corners = half_edges
+            .iter()
+            .enumerate()
+            .map(|(i, half_edge)| {
+                let previous = (i + num_half_edges - 1) % num_half_edges;
+                let position = half_edge.start_vertex().position();
+
+                let [_, incoming] = tangents[previous];
+                let [outgoing, _] = tangents[i];
+                let move_apart = incoming.cross2d(&outgoing) * offset
+                    < -Scalar::from_f64(1e-9) * offset.abs();
+
+                if join == Join::Round && move_apart {
+                    [incoming, outgoing]
+                        .map(|tangent| position + left_of(tangent) * offset)
+                } else {
+                    let point =
+                        curves[previous].intersect(&curves[i], position);
+                    [point, point]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut points = Vec::new();
+        let mut edges = Vec::new();
+        let mut vanished = Vec::new();
+        for (i, (half_edge, curve)) in
+            half_edges.iter().zip(&curves).enumerate()
+        {
+            let [end_of_previous, start] = corners[i];
+            let [end, _] = corners[(i + 1) % num_half_edges];
+
+            if end_of_previous != start {
+                let center = half_edge.start_vertex().position();
+                points.push(end_of_previous);
+                edges.push(Some(round_join(center, end_of_previous, start)));
+            }
+
+            let [a, b] = half_edge.boundary();
+            let edge = match curve {
+                OffsetCurve::Circle(circle) => {
+                    let boundary = [(start, a), (end, b)]
+                        .map(|(point, t)| circle_coords_near(circle, point, t));
+
+                    ((boundary[1] - boundary[0]).t.sign() == (b - a).t.sign())
+                        .then_some((Curve::Circle(*circle), boundary))
+                }
+                OffsetCurve::Line(line) => {
+                    ((end - start).dot(&line.direction()) > Scalar::ZERO)
+                        .then(|| Curve::line_from_points([start, end]))
+                }
+                OffsetCurve::Point(_) => None,
+            };
+
+            if edge.is_none() {
+                vanished.push(half_edge.start_vertex().position());
+            }
+
+            points.push(start);
+            edges.push(edge);
+        }
+
+        if let Some(&position) = vanished.first() {
+            // If the cycle collapsed, either all of its edges vanished, or it
+            // turned inside out.
+            let area = signed_area(&points, &edges);
+            let has_collapsed = vanished.len() == num_half_edges
+                || match cycle.winding() {
+                    Winding::Ccw => area <= Scalar::ZERO,
+                    Winding::Cw => area >= Scalar::ZERO,
+                };
+
+            if has_collapsed {
+                return Err(OffsetCycleError::Collapsed);
+            }
+
+            return Err(OffsetCycleError::EdgeVanished(position));
+        }
+
+        let edges = edges.into_iter().flatten().collect();
+        Ok(Self { points, edges })
+    }
+
+    /// Create the global vertices at the offset positions
+    pub fn global_vertices(
+        &self,
+        surface: &Surface,
+        objects: &mut Service<Objects>,
+    ) -> Vec<Handle<GlobalVertex>> {
+        self.points
+            .iter()
+            .map(|&point| {
+                GlobalVertex::new(
+                    surface.geometry().point_from_surface_coords(point),
+                )
+                .insert(objects)
+            })
+            .collect()
+    }
+
+    /// Create the cycle, from the global forms of its vertices and edges
+    pub fn cycle(
+        &self,
+        vertices: Vec<Handle<GlobalVertex>>,
+        global_edges: Vec<Handle<GlobalEdge>>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Cycle> {
+        let corners = self.points.iter().copied().zip(vertices).collect();
+        let edges = self
+            .edges
+            .iter()
+            .map(|&(curve, boundary)| -> EdgeCurve {
+                match curve {
+                    Curve::Circle(_) | Curve::Bezier(_) | Curve::Ellipse(_) => {
+                        Some((curve, boundary))
+                    }
+                    Curve::Line(_) => None,
+                }
+            })
+            .zip(global_edges)
+            .collect();
+
+        cycle_from_corners(corners, edges, objects)
+    }
+}
+
+/// The reason a cycle can't be offset
+pub(crate) enum OffsetCycleError {
+    /// The whole cycle collapsed
+    Collapsed,
+
+    /// An edge is neither a line nor a circle
+    UnsupportedCurve,
+
+    /// An edge vanished at the given position, without the cycle collapsing
+    EdgeVanished(Point<2>),
+}
+
+/// Create the global edges that connect a cycle's vertices
+pub(crate) fn global_edges_between(
+    vertices: &[Handle<GlobalVertex>],
+    objects: &mut Service<Objects>,
+) -> Vec<Handle<GlobalEdge>> {
+    vertices
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| GlobalEdge::new([a.clone(), b.clone()]).insert(objects))
+        .collect()
+}
+
+/// The offset curve of an edge
+enum OffsetCurve {
+    Circle(Circle<2>),
+    Line(Line<2>),
+
+    /// An arc that shrank to its center
+    Point(Point<2>),
+}
+
+impl OffsetCurve {
+    fn new(
+        half_edge: &HalfEdge,
+        offset: Scalar,
+    ) -> Result<Self, OffsetCycleError> {
+        let curve = half_edge.curve();
+        let [start, end] = half_edge
+            .boundary()
+            .map(|t| curve.point_from_path_coords(t));
+
+        match curve {
+            Curve::Circle(circle) => {
+                let [tangent, _] = tangents(half_edge);
+                let is_center_left = left_of(tangent)
+                    .dot(&(circle.center() - start))
+                    > Scalar::ZERO;
+
+                let radius = circle.radius();
+                let offset_radius = if is_center_left {
+                    radius - offset
+                } else {
+                    radius + offset
+                };
+                if offset_radius <= Scalar::ZERO {
+                    return Ok(Self::Point(circle.center()));
+                }
+
+                let scale = offset_radius / radius;
+                Ok(Self::Circle(Circle::new(
+                    circle.center(),
+                    circle.a() * scale,
+                    circle.b() * scale,
+                )))
+            }
+            Curve::Line(_) => {
+                let direction = (end - start).normalize();
+                Ok(Self::Line(Line::from_origin_and_direction(
+                    start + left_of(direction) * offset,
+                    direction,
+                )))
+            }
+            // The offset of a Bézier curve or an ellipse is neither, in
+            // general.
+            Curve::Bezier(_) | Curve::Ellipse(_) => {
+                Err(OffsetCycleError::UnsupportedCurve)
+            }
+        }
+    }
+
+    /// Find the point where two offset curves meet, closest to `near`
+    fn intersect(&self, other: &Self, near: Point<2>) -> Point<2> {
+        let candidates = match (self, other) {
+            (Self::Point(point), _) | (_, Self::Point(point)) => vec![*point],
+            (Self::Line(a), Self::Line(b)) => {
+                let denominator = a.direction().cross2d(&b.direction());
+                if denominator.abs() <= Scalar::from_f64(1e-12) {
+                    Vec::new()
+                } else {
+                    let t = (b.origin() - a.origin()).cross2d(&b.direction())
+                        / denominator;
+                    vec![a.point_from_line_coords([t])]
+                }
+            }
+            (Self::Line(line), Self::Circle(circle))
+            | (Self::Circle(circle), Self::Line(line)) => {
+                let direction = line.direction().normalize();
+                let to_center = circle.center() - line.origin();
+                let closest =
+                    line.origin() + direction * to_center.dot(&direction);
+                let distance = (circle.center() - closest).magnitude();
+
+                let squared =
+                    circle.radius() * circle.radius() - distance * distance;
+                if is_tangent(squared, circle.radius()) {
+                    Vec::new()
+                } else {
+                    let half_chord = squared.sqrt();
+                    vec![
+                        closest + direction * half_chord,
+                        closest - direction * half_chord,
+                    ]
+                }
+            }
+            (Self::Circle(a), Self::Circle(b)) => {
+                let between = b.center() - a.center();
+                let distance = between.magnitude();
+                if distance <= Scalar::from_f64(1e-12) {
+                    Vec::new()
+                } else {
+                    let [ra, rb] = [a.radius(), b.radius()];
+                    let along = (ra * ra - rb * rb + distance * distance)
+                        / (distance * 2.);
+                    let squared = ra * ra - along * along;
+
+                    if is_tangent(squared, ra) {
+                        Vec::new()
+                    } else {
+                        let direction = between / distance;
+                        let base = a.center() + direction * along;
+                        let across = left_of(direction) * squared.sqrt();
+                        vec![base + across, base - across]
+                    }
+                }
+            }
+        };
+
+        // If the curves don't meet, or only touch, they are tangent to each
+        // other. Then the vertex just moves along their shared normal.
+        candidates
+            .into_iter()
+            .min_by_key(|candidate| (*candidate - near).magnitude())
+            .unwrap_or_else(|| other.closest_point(near))
+    }
+
+    fn closest_point(&self, point: Point<2>) -> Point<2> {
+        match self {
+            Self::Point(point) => *point,
+            Self::Circle(circle) => {
+                circle.center()
+                    + (point - circle.center()).normalize() * circle.radius()
+            }
+            Self::Line(line) => {
+                let direction = line.direction().normalize();
+                line.origin()
+                    + direction * (point - line.origin()).dot(&direction)
+            }
+        }
+    }
+}
+
+/// Create the arc that joins two offset edges around their original corner
+fn round_join(
+    center: Point<2>,
+    start: Point<2>,
+    end: Point<2>,
+) -> (Curve, [Point<1>; 2]) {
+    let [start, end] = [start, end].map(|point| point - center);
+    let circle = Circle::new(center, start, left_of(start));
+    let angle = Scalar::atan2(start.cross2d(&end), start.dot(&end));
+
+    (
+        Curve::Circle(circle),
+        [Point::from([0.]), Point::from([angle])],
+    )
+}
+
+/// Compute the unit tangents at the start and end of a half-edge
+///
+/// The tangents point in the direction of the half-edge.
+fn tangents(half_edge: &HalfEdge) -> [Vector<2>; 2] {
+    match half_edge.curve() {
+        Curve::Circle(circle) => {
+            let [a, b] = half_edge.boundary();
+            let direction = (b - a).t.sign().to_scalar();
+            [a, b].map(|t| {
+                circle
+                    .vector_from_circle_coords([t.t + Scalar::PI / 2.])
+                    .normalize()
+                    * direction
+            })
+        }
+        Curve::Line(line) => {
+            let [a, b] = half_edge.boundary();
+            let direction =
+                line.direction().normalize() * (b - a).t.sign().to_scalar();
+            [direction, direction]
+        }
+        Curve::Bezier(bezier) => {
+            let [a, b] = half_edge.boundary();
+            let direction = (b - a).t.sign().to_scalar();
+            [a, b].map(|t| bezier.tangent_at(t).normalize() * direction)
+        }
+        Curve::Ellipse(ellipse) => {
+            let [a, b] = half_edge.boundary();
+            let direction = (b - a).t.sign().to_scalar();
+            [a, b].map(|t| {
+                ellipse
+                    .vector_from_ellipse_coords([t.t + Scalar::PI / 2.])
+                    .normalize()
+                    * direction
+            })
+        }
+    }
+}
+
+/// Compute the signed area enclosed by a cycle of points
+///
+/// Edges that are arcs add the area between them and their chord. Positive
+/// areas are enclosed counter-clockwise.
+fn signed_area(
+    points: &[Point<2>],
+    edges: &[Option<(Curve, [Point<1>; 2])>],
+) -> Scalar {
+    points
+        .iter()
+        .circular_tuple_windows()
+        .zip(edges)
+        .map(|((start, end), edge)| {
+            let chord = start.coords.cross2d(&end.coords) / 2.;
+
+            let segment = match edge {
+                Some((Curve::Circle(circle), [a, b])) => {
+                    let orientation =
+                        circle.a().cross2d(&circle.b()).sign().to_scalar();
+                    let angle = (*b - *a).t * orientation;
+                    circle.radius() * circle.radius() / 2.
+                        * (angle - Scalar::from(angle.into_f64().sin()))
+                }
+                _ => Scalar::ZERO,
+            };
+
+            chord + segment
+        })
+        .fold(Scalar::ZERO, |sum, area| sum + area)
+}
+
+/// Compute the coordinate of a point on a circle, closest to `near`
+fn circle_coords_near(
+    circle: &Circle<2>,
+    point: Point<2>,
+    near: Point<1>,
+) -> Point<1> {
+    let vector = point - circle.center();
+    let angle = Scalar::atan2(
+        vector.dot(&circle.b().normalize()),
+        vector.dot(&circle.a().normalize()),
+    );
+
+    let difference = (angle - near.t + Scalar::PI) % Scalar::TAU;
+    let difference = if difference < Scalar::ZERO {
+        difference + Scalar::TAU
+    } else {
+        difference
+    } - Scalar::PI;
+
+    Point::from([near.t + difference])
+}
+
+/// Check whether the squared half-chord of an intersection leaves no chord
+///
+/// Intersections of curves that are tangent to each other are numerically
+/// unstable, so nearly tangent curves are treated as tangent.
+fn is_tangent(squared_half_chord: Scalar, radius: Scalar) -> bool {
+    squared_half_chord < radius * radius * 1e-9
+}
+
+fn left_of(vector: Vector<2>) -> Vector<2> {
+    Vector::from([-vector.v, vector.u])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            reverse::Reverse,
+            triangulate::Triangulate,
+        },
+        builder::{CycleBuilder, FaceBuilder, HalfEdgeBuilder},
+        insert::Insert,
+        objects::{Face, Objects, Sketch},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{Join, Offset, OffsetError};
+
+    #[test]
+    fn shrink_square() {
+        let mut services = Services::new();
+
+        let face = square_with_hole(None, &mut services.objects)
+            .offset(-0.25, Join::Miter, &mut services.objects)
+            .unwrap()
+            .unwrap();
+
+        assert_area(&face, 1.5 * 1.5, 0.);
+    }
+
+    #[test]
+    fn grow_square_with_hole() {
+        let mut services = Services::new();
+
+        let face = square_with_hole(Some(0.5), &mut services.objects)
+            .offset(0.25, Join::Miter, &mut services.objects)
+            .unwrap()
+            .unwrap();
+
+        // The corners stay sharp, and the hole shrinks.
+        assert_area(&face, 2.5 * 2.5 - PI * 0.25 * 0.25, 0.001);
+    }
+
+    #[test]
+    fn grow_square_with_round_joins() {
+        let mut services = Services::new();
+
+        let face = square_with_hole(None, &mut services.objects)
+            .offset(0.25, Join::Round, &mut services.objects)
+            .unwrap()
+            .unwrap();
+
+        // Each side moves outward, and each corner becomes a quarter circle.
+        assert_eq!(face.exterior().half_edges().count(), 8);
+        assert_area(&face, 4. + 4. * 2. * 0.25 + PI * 0.25 * 0.25, 0.001);
+    }
+
+    #[test]
+    fn shrink_square_with_round_joins() {
+        let mut services = Services::new();
+
+        // Shrinking a convex face doesn't move any edges apart.
+        let face = square_with_hole(None, &mut services.objects)
+            .offset(-0.25, Join::Round, &mut services.objects)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(face.exterior().half_edges().count(), 4);
+        assert_area(&face, 1.5 * 1.5, 0.);
+    }
+
+    #[test]
+    fn drop_collapsed_hole() {
+        let mut services = Services::new();
+
+        let face = square_with_hole(Some(0.5), &mut services.objects)
+            .offset(0.5, Join::Round, &mut services.objects)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(face.interiors().count(), 0);
+        assert_area(&face, 4. + 4. * 2. * 0.5 + PI * 0.5 * 0.5, 0.001);
+    }
+
+    #[test]
+    fn drop_collapsed_face() {
+        let mut services = Services::new();
+
+        let face = square_with_hole(None, &mut services.objects);
+        let sketch = Sketch::new([face]).insert(&mut services.objects);
+
+        let offset = sketch
+            .clone()
+            .offset(-0.75, Join::Miter, &mut services.objects)
+            .unwrap();
+        assert_eq!(offset.faces().into_iter().count(), 1);
+
+        let offset = sketch
+            .offset(-1.5, Join::Miter, &mut services.objects)
+            .unwrap();
+        assert_eq!(offset.faces().into_iter().count(), 0);
+    }
+
+    #[test]
+    fn unsupported_curve() {
+        let mut services = Services::new();
+
+        let mut face = PartialFace {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        let mut half_edge = face.exterior.write().add_half_edge();
+        half_edge
+            .write()
+            .update_as_ellipse_from_center_and_radii([0., 0.], [2., 1.]);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        assert_eq!(
+            face.offset(0.25, Join::Miter, &mut services.objects),
+            Err(OffsetError::UnsupportedCurve)
+        );
+    }
+
+    fn square_with_hole(
+        radius: Option<f64>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let mut face = PartialFace {
+            surface: Partial::from(objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        face.exterior.write().update_as_polygon_from_points([
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        if let Some(radius) = radius {
+            let mut interior = face.add_interior();
+            let mut half_edge = interior.write().add_half_edge();
+            half_edge.write().update_as_circle_from_radius(radius);
+        }
+
+        let face = face.build(objects);
+
+        // The hole's cycle must be clockwise.
+        let interiors = face
+            .interiors()
+            .map(|cycle| cycle.clone().reverse(objects))
+            .collect::<Vec<_>>();
+        Face::new(
+            face.surface().clone(),
+            face.exterior().clone(),
+            interiors,
+            face.color(),
+        )
+        .insert(objects)
+    }
+
+    fn assert_area(face: &Handle<Face>, expected: f64, margin: f64) {
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+        let mesh = (&**face).approx(tolerance).triangulate();
+
+        let area = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).magnitude() / 2.
+            })
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+
+        // Approximating arcs changes the area slightly.
+        let expected = Scalar::from(expected);
+        let epsilon = Scalar::from(1e-9);
+        assert!(
+            (area - expected).abs() <= expected * margin + epsilon,
+            "{area} != {expected}"
+        );
+    }
+}
@@ -16,6 +16,7 @@ impl Reverse for Handle<Face> {
             .collect::<Vec<_>>();
 
         Face::new(self.surface().clone(), exterior, interiors, self.color())
+            .with_origin(self.origin())
             .insert(objects)
     }
 }
@@ -0,0 +1,673 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Circle, Line, Point, Scalar, Transform, Vector, Winding};
+use itertools::Itertools;
+
+use crate::{
+    algorithms::reverse::Reverse,
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects, Shell,
+        Surface, SurfaceVertex,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::{Revolve, RevolveCache, RevolveError};
+
+impl Revolve for Handle<Face> {
+    type Revolved = Result<Handle<Shell>, RevolveError>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Self::Revolved {
+        let angle = angle.into();
+        if angle == Scalar::ZERO {
+            return Err(RevolveError::ZeroAngle);
+        }
+
+        let mut revolution = Revolution::new(axis, angle, cache);
+        let epsilon = revolution.epsilon;
+
+        let surface = self.surface().geometry();
+        let normal = {
+            let SurfaceGeometry::Swept {
+                u: GlobalPath::Line(u),
+                v,
+            } = surface
+            else {
+                return Err(RevolveError::CurvedSurface);
+            };
+
+            let normal = u.direction().cross(&v).normalize();
+            if normal.dot(&revolution.direction).abs() > epsilon
+                || normal.dot(&(revolution.origin - u.origin())).abs() > epsilon
+            {
+                return Err(RevolveError::AxisNotInPlane);
+            }
+
+            // The normal of the face's front side depends on the winding of
+            // its exterior.
+            match self.exterior().winding() {
+                Winding::Ccw => normal,
+                Winding::Cw => -normal,
+            }
+        };
+
+        // Each point moves either along the normal of the face's front side,
+        // or against it, depending on which side of the axis it is located.
+        // Curved edges can extend beyond their vertices, so their bounds are
+        // checked too.
+        let side = |point| normal.dot(&revolution.motion(point));
+        let [min, max] = self
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| side_bounds(half_edge, &surface, &side))
+            .fold([Scalar::MAX, -Scalar::MAX], |[min, max], [a, b]| {
+                [min.min(a), max.max(b)]
+            });
+        let is_front = match (max > epsilon, min < -epsilon) {
+            (true, true) => return Err(RevolveError::BothSidesOfAxis),
+            (false, false) => return Err(RevolveError::OnAxis),
+            (is_front, _) => is_front,
+        };
+
+        // The front side of the face is the one facing in the direction of
+        // the revolution.
+        let (front, back) = if is_front {
+            (self.clone(), self.reverse(objects))
+        } else {
+            (self.clone().reverse(objects), self)
+        };
+
+        let mut faces = Vec::new();
+        if !revolution.is_full {
+            faces.push(back);
+        }
+
+        faces.extend(revolution.side_faces(&front, objects));
+
+        if !revolution.is_full {
+            faces.push(revolution.rotated_face(&front, objects));
+        }
+
+        Ok(Shell::new(faces).insert(objects))
+    }
+}
+
+/// Compute the minimum and maximum of a function along a half-edge
+///
+/// The function must be linear in the global coordinates, and the surface must
+/// be a plane. The bounds of Bézier curves are those of their control points.
+pub(crate) fn side_bounds(
+    half_edge: &HalfEdge,
+    surface: &SurfaceGeometry,
+    side: &impl Fn(Point<3>) -> Scalar,
+) -> [Scalar; 2] {
+    let value = |point| side(surface.point_from_surface_coords(point));
+    let boundary = half_edge.boundary().map(|point| point.t);
+
+    // Along circles and ellipses, the function is extreme at both ends of the
+    // arc, and wherever its derivative is zero, which happens every half turn.
+    let arc_values =
+        |center, [a, b]: [Vector<2>; 2], [start, end]: [Scalar; 2]| {
+            let center_value = value(center);
+            let [a, b] = [a, b].map(|axis| value(center + axis) - center_value);
+            let value_at = |t: Scalar| {
+                let (sin, cos) = t.sin_cos();
+                center_value + a * cos + b * sin
+            };
+
+            let mut values = vec![value_at(start), value_at(end)];
+            let [min, max] = [start.min(end), start.max(end)];
+            let extreme = b.atan2(a);
+            let mut t =
+                extreme + Scalar::PI * ((min - extreme) / Scalar::PI).ceil();
+            while t <= max {
+                values.push(value_at(t));
+                t += Scalar::PI;
+            }
+
+            values
+        };
+
+    let values = match half_edge.curve() {
+        Curve::Line(line) => boundary
+            .map(|t| value(line.point_from_line_coords([t])))
+            .to_vec(),
+        Curve::Circle(circle) => {
+            arc_values(circle.center(), [circle.a(), circle.b()], boundary)
+        }
+        Curve::Ellipse(ellipse) => {
+            arc_values(ellipse.center(), [ellipse.a(), ellipse.b()], boundary)
+        }
+        Curve::Bezier(bezier) => bezier.points().map(value).to_vec(),
+    };
+
+    values
+        .into_iter()
+        .fold([Scalar::MAX, -Scalar::MAX], |[min, max], value| {
+            [min.min(value), max.max(value)]
+        })
+}
+
+/// The revolution of a face around an axis
+///
+/// Creates the faces that the edges of a face describe, while being revolved,
+/// as well as the rotated copy of the face. Besides [`Revolve`], this is used to
+/// sweep faces along arcs.
+pub struct Revolution<'r> {
+    origin: Point<3>,
+    direction: Vector<3>,
+    angle: Scalar,
+    is_full: bool,
+    epsilon: Scalar,
+    cache: &'r mut RevolveCache,
+}
+
+impl<'r> Revolution<'r> {
+    /// Create a revolution around the given axis, by the given angle
+    pub fn new(
+        axis: Line<3>,
+        angle: Scalar,
+        cache: &'r mut RevolveCache,
+    ) -> Self {
+        assert!(angle != Scalar::ZERO, "Can't revolve by an angle of zero");
+
+        // Revolving by a negative angle is the same as revolving around the
+        // reversed axis.
+        let direction = axis.direction().normalize() * angle.sign().to_scalar();
+        let angle = angle.abs();
+
+        let is_full = angle >= Scalar::TAU;
+
+        Self {
+            origin: axis.origin(),
+            direction,
+            angle: angle.min(Scalar::TAU),
+            is_full,
+            epsilon: ValidationConfig::default().distinct_min_distance,
+            cache,
+        }
+    }
+
+    /// The point on the axis that is closest to the given point
+    fn center(&self, point: Point<3>) -> Point<3> {
+        self.origin
+            + self.direction * self.direction.dot(&(point - self.origin))
+    }
+
+    fn radius(&self, point: Point<3>) -> Scalar {
+        let radius = (point - self.center(point)).magnitude();
+
+        // Snapping the radius to zero prevents degenerate edges.
+        if radius <= self.epsilon {
+            Scalar::ZERO
+        } else {
+            radius
+        }
+    }
+
+    fn is_on_axis(&self, point: Point<3>) -> bool {
+        self.radius(point) == Scalar::ZERO
+    }
+
+    /// The direction in which the given point moves, at the start
+    fn motion(&self, point: Point<3>) -> Vector<3> {
+        self.direction.cross(&(point - self.origin))
+    }
+
+    /// The circle that the given point moves on
+    fn circle(&self, point: Point<3>) -> Circle<3> {
+        let center = self.center(point);
+        let a = point - center;
+        let b = self.direction.cross(&a);
+        Circle::new(center, a, b)
+    }
+
+    fn transform(&self) -> Transform {
+        let offset = self.origin - Point::origin();
+        Transform::translation(offset)
+            * Transform::rotation(self.direction * self.angle)
+            * Transform::translation(-offset)
+    }
+
+    fn rotated_vertex(
+        &mut self,
+        vertex: &Handle<GlobalVertex>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalVertex> {
+        if self.is_full || self.is_on_axis(vertex.position()) {
+            return vertex.clone();
+        }
+
+        if let Some(rotated) = self.cache.global_vertex.get(&vertex.id()) {
+            return rotated.clone();
+        }
+
+        let position = self
+            .circle(vertex.position())
+            .point_from_circle_coords([self.angle]);
+        let rotated = GlobalVertex::new(position).insert(objects);
+
+        self.cache
+            .global_vertex
+            .insert(vertex.id(), rotated.clone());
+        rotated
+    }
+
+    fn rotated_edge(
+        &mut self,
+        edge: &Handle<GlobalEdge>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalEdge> {
+        if self.is_full {
+            return edge.clone();
+        }
+
+        if let Some(rotated) = self.cache.global_edge.get(&edge.id()) {
+            return rotated.clone();
+        }
+
+        let [a, b] = edge.vertices().access_in_normalized_order();
+        let vertices = [
+            self.rotated_vertex(&a, objects),
+            self.rotated_vertex(&b, objects),
+        ];
+        let rotated = GlobalEdge::new(vertices).insert(objects);
+
+        self.cache.global_edge.insert(edge.id(), rotated.clone());
+        rotated
+    }
+
+    /// The edge that the given vertex describes while being revolved
+    fn arc(
+        &mut self,
+        vertex: &Handle<GlobalVertex>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalEdge> {
+        if let Some(arc) = self.cache.arc.get(&vertex.id()) {
+            return arc.clone();
+        }
+
+        let vertices = [vertex.clone(), self.rotated_vertex(vertex, objects)];
+        let arc = GlobalEdge::new(vertices).insert(objects);
+
+        self.cache.arc.insert(vertex.id(), arc.clone());
+        arc
+    }
+
+    /// Create the faces that the half-edges of the front face describe
+    ///
+    /// The front face is the one, whose normal points in the direction the
+    /// face is moving.
+    pub fn side_faces(
+        &mut self,
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Vec<Handle<Face>> {
+        let mut faces = Vec::new();
+
+        for cycle in front.all_cycles() {
+            for (half_edge, next) in
+                cycle.half_edges().cloned().circular_tuple_windows()
+            {
+                if let Some(face) =
+                    self.side_face(&half_edge, &next, front, objects)
+                {
+                    faces.push(face);
+                }
+            }
+        }
+
+        faces
+    }
+
+    /// Create the face that a half-edge of the front face describes
+    ///
+    /// Returns `None`, if the half-edge is located on the axis.
+    fn side_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        next: &Handle<HalfEdge>,
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Option<Handle<Face>> {
+        let [a, b] = [half_edge, next]
+            .map(|half_edge| half_edge.start_vertex().global_form().clone());
+        let [ra, rb] = [&a, &b].map(|vertex| self.radius(vertex.position()));
+
+        let Curve::Line(_) = half_edge.curve() else {
+            return Some(self.revolved_face(half_edge, [a, b], front, objects));
+        };
+        if ra == Scalar::ZERO && rb == Scalar::ZERO {
+            return None;
+        }
+
+        let [ha, hb] = [&a, &b].map(|vertex| {
+            self.direction.dot(&(vertex.position() - self.origin))
+        });
+        let face = if (ra - rb).abs() <= self.epsilon {
+            self.cylindrical_face(half_edge, [a, b], front, objects)
+        } else if (ha - hb).abs() <= self.epsilon {
+            self.planar_face(half_edge, [a, b], [ra, rb], front, objects)
+        } else {
+            self.revolved_face(half_edge, [a, b], front, objects)
+        };
+
+        Some(face)
+    }
+
+    /// Create the face that any other edge describes
+    ///
+    /// This covers straight edges that are at an angle to the axis, which
+    /// describe cones, as well as curved edges.
+    fn revolved_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        [a, b]: [Handle<GlobalVertex>; 2],
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let path = front
+            .surface()
+            .geometry()
+            .path_from_surface_curve(half_edge.curve());
+        let surface = Surface::new(SurfaceGeometry::Revolved {
+            path,
+            axis: Line::from_origin_and_direction(self.origin, self.direction),
+        })
+        .insert(objects);
+
+        // The u-coordinate of the surface is the angle of the revolution, the
+        // v-coordinate is the coordinate on the edge's curve. This way, the
+        // edges of this face have the same line coordinates as the same edges
+        // in the faces next to this one, which makes sure they share their
+        // approximations.
+        let angle = self.angle;
+        let [t0, t1] = half_edge.boundary().map(|point| point.t);
+        let along_u = |v: Scalar, boundary: [Scalar; 2]| {
+            let points =
+                boundary.map(|u| (Point::from([u]), Point::from([u, v])));
+            let curve = Curve::from_points_with_line_coords(points);
+            Some((curve, boundary.map(|t| Point::from([t]))))
+        };
+        let along_v = |u: Scalar, boundary: [Scalar; 2]| {
+            let points =
+                boundary.map(|v| (Point::from([v]), Point::from([u, v])));
+            let curve = Curve::from_points_with_line_coords(points);
+            Some((curve, boundary.map(|t| Point::from([t]))))
+        };
+
+        let corners = vec![
+            (Point::from([Scalar::ZERO, t0]), a.clone()),
+            (Point::from([Scalar::ZERO, t1]), b.clone()),
+            (Point::from([angle, t1]), self.rotated_vertex(&b, objects)),
+            (Point::from([angle, t0]), self.rotated_vertex(&a, objects)),
+        ];
+        let edges = vec![
+            (
+                along_v(Scalar::ZERO, [t0, t1]),
+                half_edge.global_form().clone(),
+            ),
+            (along_u(t1, [Scalar::ZERO, angle]), self.arc(&b, objects)),
+            (
+                along_v(angle, [t1, t0]),
+                self.rotated_edge(half_edge.global_form(), objects),
+            ),
+            (along_u(t0, [angle, Scalar::ZERO]), self.arc(&a, objects)),
+        ];
+
+        let exterior = cycle_from_corners(corners, edges, objects);
+        Face::new(surface, exterior, [], front.color()).insert(objects)
+    }
+
+    /// Create the face that an edge parallel to the axis describes
+    fn cylindrical_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        [a, b]: [Handle<GlobalVertex>; 2],
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let surface = Surface::new(SurfaceGeometry::Swept {
+            u: GlobalPath::Circle(self.circle(a.position())),
+            v: b.position() - a.position(),
+        })
+        .insert(objects);
+
+        // The arcs are lines on the surface. Their line coordinates match the
+        // coordinates of the arcs on the planar faces next to this one, which
+        // makes sure they share their approximations.
+        let angle = self.angle;
+        let arc = |v: Scalar, boundary: [Scalar; 2]| {
+            let points =
+                boundary.map(|u| (Point::from([u]), Point::from([u, v])));
+            let curve = Curve::from_points_with_line_coords(points);
+            Some((curve, boundary.map(|t| Point::from([t]))))
+        };
+
+        let corners = vec![
+            (Point::from([Scalar::ZERO, Scalar::ZERO]), a.clone()),
+            (Point::from([Scalar::ZERO, Scalar::ONE]), b.clone()),
+            (
+                Point::from([angle, Scalar::ONE]),
+                self.rotated_vertex(&b, objects),
+            ),
+            (
+                Point::from([angle, Scalar::ZERO]),
+                self.rotated_vertex(&a, objects),
+            ),
+        ];
+        let edges = vec![
+            (None, half_edge.global_form().clone()),
+            (
+                arc(Scalar::ONE, [Scalar::ZERO, angle]),
+                self.arc(&b, objects),
+            ),
+            (None, self.rotated_edge(half_edge.global_form(), objects)),
+            (
+                arc(Scalar::ZERO, [angle, Scalar::ZERO]),
+                self.arc(&a, objects),
+            ),
+        ];
+
+        let exterior = cycle_from_corners(corners, edges, objects);
+        Face::new(surface, exterior, [], front.color()).insert(objects)
+    }
+
+    /// Create the face that an edge perpendicular to the axis describes
+    fn planar_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        [a, b]: [Handle<GlobalVertex>; 2],
+        [ra, rb]: [Scalar; 2],
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        // The surface is defined such that the u-axis points towards the outer
+        // vertex, and the vertices move in the direction of the v-axis.
+        let outer = if ra > rb { &a } else { &b };
+        let center = self.center(outer.position());
+        let radial = (outer.position() - center).normalize();
+        let surface = Surface::new(SurfaceGeometry::Swept {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                center, radial,
+            )),
+            v: self.direction.cross(&radial),
+        })
+        .insert(objects);
+
+        // Positions on the surface, and the arcs that the vertices describe,
+        // in polar coordinates.
+        let position = |radius: Scalar, angle: Scalar| {
+            let (sin, cos) = angle.sin_cos();
+            Point::from([radius * cos, radius * sin])
+        };
+        let arc = |radius: Scalar, boundary: [Scalar; 2]| {
+            let curve = Curve::circle_from_radius(radius);
+            Some((curve, boundary.map(|t| Point::from([t]))))
+        };
+
+        // The arc of `b` runs in the direction of the revolution, the arc of
+        // `a` in the opposite direction.
+        let angle = self.angle;
+        let boundary_a = [angle, Scalar::ZERO];
+        let boundary_b = [Scalar::ZERO, angle];
+
+        if self.is_full {
+            // The arc of the outer vertex becomes the exterior, the one of the
+            // inner vertex (if it isn't located on the axis) an interior.
+            let mut cycles = Vec::new();
+            for (vertex, radius, boundary) in
+                [(&a, ra, boundary_a), (&b, rb, boundary_b)]
+            {
+                if radius == Scalar::ZERO {
+                    continue;
+                }
+
+                let corners =
+                    vec![(position(radius, boundary[0]), vertex.clone())];
+                let edges =
+                    vec![(arc(radius, boundary), self.arc(vertex, objects))];
+                cycles.push((
+                    radius,
+                    cycle_from_corners(corners, edges, objects),
+                ));
+            }
+            cycles.sort_by_key(|(radius, _)| -*radius);
+
+            let mut cycles = cycles.into_iter().map(|(_, cycle)| cycle);
+            let exterior = cycles.next().expect("Face must have exterior");
+            return Face::new(surface, exterior, cycles, front.color())
+                .insert(objects);
+        }
+
+        let mut corners = vec![
+            (position(ra, Scalar::ZERO), a.clone()),
+            (position(rb, Scalar::ZERO), b.clone()),
+        ];
+        let mut edges = vec![(None, half_edge.global_form().clone())];
+
+        if rb != Scalar::ZERO {
+            corners
+                .push((position(rb, angle), self.rotated_vertex(&b, objects)));
+            edges.push((arc(rb, boundary_b), self.arc(&b, objects)));
+        }
+
+        edges.push((None, self.rotated_edge(half_edge.global_form(), objects)));
+
+        if ra != Scalar::ZERO {
+            corners
+                .push((position(ra, angle), self.rotated_vertex(&a, objects)));
+            edges.push((arc(ra, boundary_a), self.arc(&a, objects)));
+        }
+
+        let exterior = cycle_from_corners(corners, edges, objects);
+        Face::new(surface, exterior, [], front.color()).insert(objects)
+    }
+
+    /// Create a copy of the front face, rotated by the angle of the revolution
+    pub fn rotated_face(
+        &mut self,
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let surface = Surface::new(
+            front.surface().geometry().transform(&self.transform()),
+        )
+        .insert(objects);
+
+        let mut surface_vertices: BTreeMap<ObjectId, Handle<SurfaceVertex>> =
+            BTreeMap::new();
+        let mut cycles = Vec::new();
+
+        for cycle in front.all_cycles() {
+            let mut half_edges = Vec::new();
+
+            for half_edge in cycle.half_edges() {
+                let [start, end] = half_edge.surface_vertices().map(|vertex| {
+                    if let Some(rotated) = surface_vertices.get(&vertex.id()) {
+                        return rotated.clone();
+                    }
+
+                    let global_form =
+                        self.rotated_vertex(vertex.global_form(), objects);
+                    let rotated =
+                        SurfaceVertex::new(vertex.position(), global_form)
+                            .insert(objects);
+
+                    surface_vertices.insert(vertex.id(), rotated.clone());
+                    rotated
+                });
+
+                half_edges.push(
+                    HalfEdge::new(
+                        half_edge.curve(),
+                        half_edge.boundary(),
+                        [start, end],
+                        self.rotated_edge(half_edge.global_form(), objects),
+                    )
+                    .insert(objects),
+                );
+            }
+
+            cycles.push(Cycle::new(half_edges).insert(objects));
+        }
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Face must have exterior");
+        Face::new(surface, exterior, cycles, front.color()).insert(objects)
+    }
+}
+
+/// Create a cycle from its corners and the edges between them
+///
+/// Each edge connects the corner with the same index to the next one. Edges
+/// without a curve are straight lines.
+pub fn cycle_from_corners(
+    corners: Vec<(Point<2>, Handle<GlobalVertex>)>,
+    edges: Vec<(EdgeCurve, Handle<GlobalEdge>)>,
+    objects: &mut Service<Objects>,
+) -> Handle<Cycle> {
+    let surface_vertices = corners
+        .into_iter()
+        .map(|(position, global_form)| {
+            SurfaceVertex::new(position, global_form).insert(objects)
+        })
+        .collect::<Vec<_>>();
+
+    let half_edges = edges
+        .into_iter()
+        .zip(surface_vertices.iter().circular_tuple_windows())
+        .map(|((curve, global_edge), (start, end))| {
+            let (curve, boundary) = curve.unwrap_or_else(|| {
+                Curve::line_from_points([start.position(), end.position()])
+            });
+            HalfEdge::new(
+                curve,
+                boundary,
+                [start.clone(), end.clone()],
+                global_edge,
+            )
+            .insert(objects)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges).insert(objects)
+}
+
+/// The curve of an edge and the edge's boundary on it
+///
+/// `None` stands for a straight line between the corners of the edge.
+pub type EdgeCurve = Option<(Curve, [Point<1>; 2])>;
@@ -0,0 +1,240 @@
+//! Revolving objects around an axis to create new objects
+
+mod face;
+mod sketch;
+
+pub(crate) use self::face::{
+    cycle_from_corners, side_bounds, EdgeCurve, Revolution,
+};
+
+use std::collections::BTreeMap;
+
+use fj_math::{Line, Scalar};
+
+use crate::{
+    objects::{GlobalEdge, GlobalVertex, Objects},
+    services::Service,
+    storage::{Handle, ObjectId},
+};
+
+/// Revolve an object around an axis to create another object
+pub trait Revolve: Sized {
+    /// The object that is created by revolving the implementing object
+    type Revolved;
+
+    /// Revolve the object around the given axis, by the given angle
+    ///
+    /// The angle is given in radians. Positive angles revolve the object
+    /// counter-clockwise, when looking against the direction of the axis. An
+    /// angle of at least a full turn results in a full revolution.
+    ///
+    /// The axis must lie within the plane of the object, and the object must
+    /// not extend to both sides of the axis. Otherwise, the result is a
+    /// [`RevolveError`].
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Revolved {
+        let mut cache = RevolveCache::default();
+        self.revolve_with_cache(axis, angle, &mut cache, objects)
+    }
+
+    /// Revolve the object around the given axis, using the provided cache
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Self::Revolved;
+}
+
+/// An object that can't be revolved
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RevolveError {
+    /// The angle of the revolution is zero
+    #[error("Can't revolve by an angle of zero")]
+    ZeroAngle,
+
+    /// A face is not located in a plane
+    #[error("Revolving faces in curved surfaces is not supported")]
+    CurvedSurface,
+
+    /// The axis doesn't lie within the plane of a face
+    #[error("Axis of revolution must lie within the plane of the face")]
+    AxisNotInPlane,
+
+    /// A face is located entirely on the axis
+    #[error("Can't revolve face that is located on the axis")]
+    OnAxis,
+
+    /// A face extends to both sides of the axis
+    #[error("Can't revolve face that is located on both sides of the axis")]
+    BothSidesOfAxis,
+}
+
+/// A cache used for revolving
+///
+/// See [`Revolve`].
+#[derive(Default)]
+pub struct RevolveCache {
+    /// Cache for the rotated copies of global vertices
+    pub global_vertex: BTreeMap<ObjectId, Handle<GlobalVertex>>,
+
+    /// Cache for the rotated copies of global edges
+    pub global_edge: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+
+    /// Cache for the arcs that global vertices describe while being revolved
+    pub arc: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, TAU};
+
+    use fj_math::{Line, Point, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, transform::TransformObject,
+            triangulate::Triangulate,
+        },
+        objects::{Objects, Sketch},
+        services::{Service, Services},
+        storage::Handle,
+        test_helpers::{self, assert_mesh_volume, assert_volume, polygon},
+    };
+
+    use super::{Revolve, RevolveError};
+
+    #[test]
+    fn full_revolution() {
+        let mut services = Services::new();
+
+        let sketch = rectangle([1., 0.], [2., 1.], &mut services.objects);
+        let solid = sketch
+            .revolve(y_axis(), TAU, &mut services.objects)
+            .unwrap();
+
+        assert_volume(&solid, 3. * PI, 0.005);
+    }
+
+    #[test]
+    fn partial_revolution() {
+        let mut services = Services::new();
+
+        for angle in [PI / 2., PI * 1.5, -PI / 2.] {
+            let sketch = rectangle([1., 0.], [2., 1.], &mut services.objects);
+            let solid = sketch
+                .revolve(y_axis(), angle, &mut services.objects)
+                .unwrap();
+
+            assert_volume(&solid, 3. * angle.abs() / 2., 0.005);
+        }
+    }
+
+    #[test]
+    fn revolution_touching_axis() {
+        let mut services = Services::new();
+
+        for angle in [TAU, PI] {
+            let sketch = rectangle([0., 0.], [1., 2.], &mut services.objects);
+            let solid = sketch
+                .revolve(y_axis(), angle, &mut services.objects)
+                .unwrap();
+
+            assert_volume(&solid, angle, 0.005);
+        }
+    }
+
+    #[test]
+    fn revolution_of_slanted_edge() {
+        let mut services = Services::new();
+
+        let sketch =
+            polygon([[0., 0.], [1., 0.], [0., 1.]], &mut services.objects);
+        let solid = sketch
+            .revolve(y_axis(), TAU, &mut services.objects)
+            .unwrap();
+
+        // A cone with a radius and height of `1`.
+        assert_volume(&solid, PI / 3., 0.005);
+    }
+
+    #[test]
+    fn revolution_of_curved_edge() {
+        let mut services = Services::new();
+
+        for angle in [TAU, PI] {
+            let sketch = circle([2., 0.], 0.5, &mut services.objects);
+            let solid = sketch
+                .revolve(y_axis(), angle, &mut services.objects)
+                .unwrap();
+
+            // A torus, or a part of it, with a major radius of `2` and a minor
+            // radius of `0.5`. Its surface is curved in both directions, so a
+            // coarser tolerance keeps the number of triangles manageable.
+            let tolerance = Tolerance::from_scalar(0.01).unwrap();
+            let mesh = (&*solid, tolerance).triangulate();
+            assert_mesh_volume(&mesh, PI * 0.25 * 2. * angle, 0.02);
+        }
+    }
+
+    #[test]
+    fn revolution_of_circle_crossing_axis() {
+        let mut services = Services::new();
+
+        // The circle's only vertex is on one side of the axis, but the circle
+        // itself crosses it.
+        let sketch = circle([0.5, 0.], 1., &mut services.objects);
+        let result = sketch.revolve(y_axis(), TAU, &mut services.objects);
+
+        assert_eq!(result, Err(RevolveError::BothSidesOfAxis));
+    }
+
+    #[test]
+    fn invalid_revolutions() {
+        let mut services = Services::new();
+
+        let sketch = rectangle([1., 0.], [2., 1.], &mut services.objects);
+        let result = sketch.revolve(y_axis(), 0., &mut services.objects);
+        assert_eq!(result, Err(RevolveError::ZeroAngle));
+
+        let sketch = rectangle([-1., 0.], [1., 1.], &mut services.objects);
+        let result = sketch.revolve(y_axis(), TAU, &mut services.objects);
+        assert_eq!(result, Err(RevolveError::BothSidesOfAxis));
+
+        let z_axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_z());
+        let sketch = rectangle([1., 0.], [2., 1.], &mut services.objects);
+        let result = sketch.revolve(z_axis, TAU, &mut services.objects);
+        assert_eq!(result, Err(RevolveError::AxisNotInPlane));
+    }
+
+    fn y_axis() -> Line<3> {
+        Line::from_origin_and_direction(Point::origin(), Vector::unit_y())
+    }
+
+    fn rectangle(
+        min: [f64; 2],
+        max: [f64; 2],
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        let [x0, y0] = min;
+        let [x1, y1] = max;
+
+        polygon([[x0, y0], [x1, y0], [x1, y1], [x0, y1]], objects)
+    }
+
+    fn circle(
+        center: [f64; 2],
+        radius: f64,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Sketch> {
+        let [x, y] = center;
+        test_helpers::circle(radius, objects)
+            .translate(Vector::from([x, y, 0.]), objects)
+    }
+}
@@ -0,0 +1,32 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    services::Service,
+    storage::Handle,
+};
+
+use super::{Revolve, RevolveCache, RevolveError};
+
+impl Revolve for Handle<Sketch> {
+    type Revolved = Result<Handle<Solid>, RevolveError>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Self::Revolved {
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell = face.revolve_with_cache(axis, angle, cache, objects)?;
+            shells.push(shell);
+        }
+
+        Ok(Solid::new(shells).insert(objects))
+    }
+}
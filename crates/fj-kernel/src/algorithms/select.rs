@@ -0,0 +1,271 @@
+//! Select faces and edges of a solid
+//!
+//! Operations like blends or hollowing need to refer to specific parts of the
+//! solid they operate on. The queries in this module describe those parts in
+//! terms of their geometry and their [`FaceOrigin`], and are resolved against a
+//! built solid.
+
+use std::collections::BTreeSet;
+
+use fj_math::{Scalar, Vector, Winding};
+
+use crate::{
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    objects::{Face, FaceOrigin, GlobalEdge, Solid},
+    storage::Handle,
+};
+
+/// A query that selects faces
+///
+/// A face is selected, if it matches all criteria of the query. The default
+/// query selects all faces.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FaceQuery {
+    planar: bool,
+    normal: Option<Vector<3>>,
+    origin: Option<FaceOrigin>,
+}
+
+impl FaceQuery {
+    /// Only select planar faces
+    pub fn planar(mut self) -> Self {
+        self.planar = true;
+        self
+    }
+
+    /// Only select planar faces whose front side faces the given direction
+    pub fn facing(mut self, normal: impl Into<Vector<3>>) -> Self {
+        self.planar = true;
+        self.normal = Some(normal.into());
+        self
+    }
+
+    /// Only select faces with the given origin
+    pub fn with_origin(mut self, origin: FaceOrigin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Determine whether the query selects the given face
+    pub fn matches(&self, face: &Face) -> bool {
+        if let Some(origin) = self.origin {
+            if face.origin() != origin {
+                return false;
+            }
+        }
+
+        if self.planar {
+            let Some(face_normal) = normal(face) else {
+                return false;
+            };
+
+            if let Some(normal) = self.normal {
+                if !is_parallel(face_normal, normal) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// A query that selects edges of the faces that a [`FaceQuery`] selects
+///
+/// An edge is selected, if it matches all criteria of the query. The default
+/// query selects all edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EdgeQuery {
+    direction: Option<Vector<3>>,
+    interior: bool,
+}
+
+impl EdgeQuery {
+    /// Only select straight edges that are parallel to the given direction
+    pub fn parallel_to(mut self, direction: impl Into<Vector<3>>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Only select edges of the faces' interior cycles
+    ///
+    /// Those are the edges that bound the holes in the faces.
+    pub fn interior(mut self) -> Self {
+        self.interior = true;
+        self
+    }
+}
+
+/// Select faces and edges of an object
+pub trait Select {
+    /// Select the faces that match the query
+    fn select_faces(&self, query: &FaceQuery) -> Vec<Handle<Face>>;
+
+    /// Select the edges that match the edge query, of the matching faces
+    ///
+    /// Each edge is only returned once, even if it is shared by two of the
+    /// selected faces.
+    fn select_edges(
+        &self,
+        faces: &FaceQuery,
+        edges: &EdgeQuery,
+    ) -> Vec<Handle<GlobalEdge>>;
+}
+
+impl Select for Solid {
+    fn select_faces(&self, query: &FaceQuery) -> Vec<Handle<Face>> {
+        self.shells()
+            .flat_map(|shell| shell.faces())
+            .filter(|face| query.matches(face))
+            .cloned()
+            .collect()
+    }
+
+    fn select_edges(
+        &self,
+        faces: &FaceQuery,
+        edges: &EdgeQuery,
+    ) -> Vec<Handle<GlobalEdge>> {
+        let mut selected = Vec::new();
+        let mut visited = BTreeSet::new();
+
+        for face in self.select_faces(faces) {
+            let cycles = if edges.interior {
+                face.interiors().collect::<Vec<_>>()
+            } else {
+                face.all_cycles().collect()
+            };
+
+            for half_edge in cycles.into_iter().flat_map(|c| c.half_edges()) {
+                let edge = half_edge.global_form();
+
+                if let Some(direction) = edges.direction {
+                    let Curve::Line(_) = half_edge.curve() else {
+                        continue;
+                    };
+
+                    let [a, b] = edge
+                        .vertices()
+                        .access_in_normalized_order()
+                        .map(|vertex| vertex.position());
+
+                    // Closed edges, like full circles, start and end at the
+                    // same vertex. They don't have a direction.
+                    if a == b {
+                        continue;
+                    }
+
+                    if !is_parallel(b - a, direction)
+                        && !is_parallel(b - a, -direction)
+                    {
+                        continue;
+                    }
+                }
+
+                // Neighboring faces don't necessarily share the same global
+                // edge object, but they do share its vertices.
+                let vertices = edge
+                    .vertices()
+                    .access_in_normalized_order()
+                    .map(|vertex| vertex.id());
+
+                if visited.insert(vertices) {
+                    selected.push(edge.clone());
+                }
+            }
+        }
+
+        selected
+    }
+}
+
+/// Compute the direction that the front side of a planar face is facing
+///
+/// Returns `None`, if the face is not planar.
+fn normal(face: &Face) -> Option<Vector<3>> {
+    let SurfaceGeometry::Swept {
+        u: GlobalPath::Line(line),
+        v,
+    } = face.surface().geometry()
+    else {
+        return None;
+    };
+
+    let normal = line.direction().cross(&v);
+    let normal = match face.exterior().winding() {
+        Winding::Ccw => normal,
+        Winding::Cw => -normal,
+    };
+
+    Some(normal)
+}
+
+fn is_parallel(a: Vector<3>, b: Vector<3>) -> bool {
+    a.normalize().dot(&b.normalize()) > Scalar::ONE - Scalar::from(1e-9)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::sweep::Sweep,
+        builder::{CycleBuilder, FaceBuilder},
+        insert::Insert,
+        objects::{FaceOrigin, Sketch},
+        partial::{Partial, PartialFace, PartialObject},
+        services::Services,
+    };
+
+    use super::{EdgeQuery, FaceQuery, Select};
+
+    #[test]
+    fn select_by_origin() {
+        let mut services = Services::new();
+
+        let mut face = PartialFace {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        face.exterior.write().update_as_polygon_from_points([
+            [0., 0.],
+            [2., 0.],
+            [2., 2.],
+            [0., 2.],
+        ]);
+        face.add_interior().write().update_as_polygon_from_points([
+            [0.5, 0.5],
+            [0.5, 1.5],
+            [1.5, 1.5],
+            [1.5, 0.5],
+        ]);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+        let sketch = Sketch::new([face]).insert(&mut services.objects);
+
+        let solid = sketch.sweep([0., 0., 1.], &mut services.objects);
+
+        let top = FaceQuery::default().with_origin(FaceOrigin::SweepTop);
+        let sides = FaceQuery::default().with_origin(FaceOrigin::SweepSide);
+
+        assert_eq!(solid.select_faces(&top).len(), 1);
+        assert_eq!(solid.select_faces(&sides).len(), 8);
+        assert_eq!(
+            solid.select_faces(&FaceQuery::default().facing([0., 0., 1.])),
+            solid.select_faces(&top),
+        );
+
+        // The 4 edges around the hole in the top face
+        let edges = solid.select_edges(&top, &EdgeQuery::default().interior());
+        assert_eq!(edges.len(), 4);
+
+        // The 4 vertical edges on the outside and the 4 in the hole
+        let edges = solid.select_edges(
+            &sides,
+            &EdgeQuery::default().parallel_to([0., 0., 1.]),
+        );
+        assert_eq!(edges.len(), 8);
+    }
+}
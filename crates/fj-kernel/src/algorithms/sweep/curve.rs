@@ -2,7 +2,10 @@ use fj_math::{Circle, Line, Vector};
 
 use crate::{
     builder::SurfaceBuilder,
-    geometry::curve::{Curve, GlobalPath},
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
     insert::Insert,
     objects::{Objects, Surface},
     partial::{PartialObject, PartialSurface},
@@ -23,8 +26,14 @@ impl Sweep for (Curve, &Surface) {
     ) -> Self::Swept {
         let (curve, surface) = self;
 
-        match surface.geometry().u {
-            GlobalPath::Circle(_) => {
+        match surface.geometry() {
+            SurfaceGeometry::Swept {
+                u:
+                    GlobalPath::Circle(_)
+                    | GlobalPath::Bezier(_)
+                    | GlobalPath::Ellipse(_),
+                ..
+            } => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -41,7 +50,22 @@ impl Sweep for (Curve, &Surface) {
                     not supported yet."
                 )
             }
-            GlobalPath::Line(_) => {
+            SurfaceGeometry::Ruled { .. } => {
+                todo!(
+                    "Sweeping a curve that is defined on a ruled surface is \
+                    not supported yet."
+                )
+            }
+            SurfaceGeometry::Revolved { .. } => {
+                todo!(
+                    "Sweeping a curve that is defined on a revolved surface \
+                    is not supported yet."
+                )
+            }
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Line(_),
+                ..
+            } => {
                 // We're sweeping from a curve on a flat surface, which is
                 // supported. Carry on.
             }
@@ -72,6 +96,9 @@ impl Sweep for (Curve, &Surface) {
 
                 GlobalPath::Line(line)
             }
+            Curve::Bezier(_) | Curve::Ellipse(_) => {
+                surface.geometry().path_from_surface_curve(curve)
+            }
         };
 
         PartialSurface::from_axes(u, path)
@@ -0,0 +1,461 @@
+use fj_interop::mesh::Color;
+use fj_math::{Point, Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::{
+        offset::{global_edges_between, Join, OffsetCycle, OffsetCycleError},
+        reverse::Reverse,
+        revolve::{cycle_from_corners, EdgeCurve},
+        transform::TransformObject,
+    },
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Face, FaceOrigin, GlobalEdge, GlobalVertex, HalfEdge, Objects, Shell,
+        Sketch, Solid, Surface,
+    },
+    services::Service,
+    storage::Handle,
+};
+
+use super::with_origin;
+
+/// Sweep an object along a straight path, with a draft angle
+///
+/// The draft angle tilts the side walls of the result towards the inside, for
+/// positive angles, and towards the outside, for negative ones. The top face
+/// is an offset of the swept face. Side walls of straight edges are planes,
+/// side walls of circular edges are cones.
+///
+/// # Limitations
+///
+/// Faces must be planar, their edges must be lines or circles, and the path must
+/// not be parallel to them. The draft angle must be small enough that no edge
+/// of the top face vanishes. Otherwise, the result is a [`DraftError`].
+pub trait SweepWithDraft: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, with the given draft angle
+    ///
+    /// The angle is given in radians.
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept;
+}
+
+/// A sweep with a draft angle that can't be created
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DraftError {
+    /// A face is not located in a plane
+    #[error("Sweeping faces in curved surfaces with a draft is not supported")]
+    CurvedSurface,
+
+    /// The coordinate system of a face's surface is distorted
+    #[error(
+        "Sweeping faces in distorted surfaces with a draft is not supported"
+    )]
+    DistortedSurface,
+
+    /// The path is parallel to a face
+    #[error("Can't sweep face with a draft along path that is parallel to it")]
+    ParallelToFace,
+
+    /// An edge is neither a line nor a circle
+    #[error(
+        "Sweeping Bézier curves and ellipses with a draft is not supported"
+    )]
+    UnsupportedCurve,
+
+    /// An edge of the top face vanishes
+    #[error("Draft angle is too large for the sweep")]
+    AngleTooLarge,
+}
+
+impl SweepWithDraft for Handle<Sketch> {
+    type Swept = Result<Handle<Solid>, DraftError>;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell = face.sweep_with_draft(path, angle, objects)?;
+            shells.push(shell);
+        }
+
+        Ok(Solid::new(shells).insert(objects))
+    }
+}
+
+impl SweepWithDraft for Handle<Face> {
+    type Swept = Result<Handle<Shell>, DraftError>;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        angle: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let angle = angle.into();
+
+        let (u, v) = match self.surface().geometry() {
+            SurfaceGeometry::Swept {
+                u: GlobalPath::Line(line),
+                v,
+            } => (line.direction(), v),
+            _ => return Err(DraftError::CurvedSurface),
+        };
+
+        // The offset is computed in surface coordinates, which only works,
+        // if they aren't distorted.
+        let epsilon = Scalar::from_f64(1e-9) * u.magnitude();
+        if u.dot(&v).abs() > epsilon * v.magnitude()
+            || (u.magnitude() - v.magnitude()).abs() > epsilon
+        {
+            return Err(DraftError::DistortedSurface);
+        }
+
+        let normal = u.cross(&v).normalize();
+        let height = normal.dot(&path);
+        let is_negative_sweep = height < Scalar::ZERO;
+        if height.abs() <= epsilon {
+            return Err(DraftError::ParallelToFace);
+        }
+
+        // The side walls would turn back on themselves at a right angle.
+        if angle.abs() >= Scalar::PI / 2. {
+            return Err(DraftError::AngleTooLarge);
+        }
+
+        // Cycles are offset to their left, which is the inside of the face, if
+        // its exterior is counter-clockwise.
+        let offset = height.abs() * Scalar::from(angle.into_f64().tan())
+            / u.magnitude()
+            * match self.exterior().winding() {
+                Winding::Ccw => Scalar::ONE,
+                Winding::Cw => -Scalar::ONE,
+            };
+
+        let top_surface = self.surface().clone().translate(path, objects);
+
+        let mut side_faces = Vec::new();
+        let mut top_cycles = Vec::new();
+        for cycle in self.all_cycles() {
+            let top_cycle = OffsetCycle::new(cycle, offset, Join::Miter)
+                .map_err(|err| match err {
+                    OffsetCycleError::UnsupportedCurve => {
+                        DraftError::UnsupportedCurve
+                    }
+                    OffsetCycleError::Collapsed
+                    | OffsetCycleError::EdgeVanished(_) => {
+                        DraftError::AngleTooLarge
+                    }
+                })?;
+            let top_vertices = top_cycle.global_vertices(&top_surface, objects);
+            let top_edges = global_edges_between(&top_vertices, objects);
+            let rulings = cycle
+                .half_edges()
+                .zip(&top_vertices)
+                .map(|(half_edge, top_vertex)| {
+                    GlobalEdge::new([
+                        half_edge.start_vertex().global_form().clone(),
+                        top_vertex.clone(),
+                    ])
+                    .insert(objects)
+                })
+                .collect::<Vec<_>>();
+
+            let half_edges = cycle.half_edges().collect::<Vec<_>>();
+            for i in 0..half_edges.len() {
+                let j = (i + 1) % half_edges.len();
+
+                let bottom = Side {
+                    vertices: [i, j].map(|k| {
+                        half_edges[k].start_vertex().global_form().clone()
+                    }),
+                    boundary: half_edges[i].boundary(),
+                    global_edge: half_edges[i].global_form().clone(),
+                };
+                let top = Side {
+                    vertices: [i, j].map(|k| top_vertices[k].clone()),
+                    boundary: top_cycle.edges[i].1,
+                    global_edge: top_edges[i].clone(),
+                };
+                let rulings = [i, j].map(|k| rulings[k].clone());
+
+                let face = side_face(
+                    half_edges[i],
+                    &top_cycle.edges[i].0,
+                    [bottom, top],
+                    rulings,
+                    is_negative_sweep,
+                    self.surface(),
+                    &top_surface,
+                    self.color(),
+                    objects,
+                );
+                side_faces.push(face);
+            }
+
+            top_cycles.push(top_cycle.cycle(top_vertices, top_edges, objects));
+        }
+
+        let mut faces = Vec::new();
+
+        let bottom_face = if is_negative_sweep {
+            self.clone()
+        } else {
+            self.clone().reverse(objects)
+        };
+        faces.push(with_origin(bottom_face, FaceOrigin::SweepBottom, objects));
+        faces.extend(side_faces);
+
+        let mut top_cycles = top_cycles.into_iter();
+        let exterior = top_cycles.next().expect("Face has an exterior");
+        let top_face =
+            Face::new(top_surface, exterior, top_cycles, self.color())
+                .with_origin(FaceOrigin::SweepTop)
+                .insert(objects);
+        faces.push(if is_negative_sweep {
+            top_face.reverse(objects)
+        } else {
+            top_face
+        });
+
+        Ok(Shell::new(faces).insert(objects))
+    }
+}
+
+/// One of the two edges of a side face, that are not rulings
+struct Side {
+    vertices: [Handle<GlobalVertex>; 2],
+    boundary: [Point<1>; 2],
+    global_edge: Handle<GlobalEdge>,
+}
+
+/// Create the side face between an edge and its offset copy
+#[allow(clippy::too_many_arguments)]
+fn side_face(
+    half_edge: &HalfEdge,
+    top_curve: &Curve,
+    [bottom, top]: [Side; 2],
+    rulings: [Handle<GlobalEdge>; 2],
+    is_negative_sweep: bool,
+    bottom_surface: &Surface,
+    top_surface: &Surface,
+    color: Color,
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    let bottom_path = bottom_surface
+        .geometry()
+        .path_from_surface_curve(half_edge.curve());
+
+    // The side face must be oriented counter-clockwise, when looking against
+    // the direction of the sweep. Faces of negative sweeps run the other way.
+    let order = |[a, b]: [usize; 2]| {
+        if is_negative_sweep {
+            [b, a]
+        } else {
+            [a, b]
+        }
+    };
+    let [start, end] = order([0, 1]);
+    let [bottom_start, bottom_end] = [start, end].map(|i| bottom.boundary[i]);
+
+    let (surface, [top_start, top_end]) = match bottom_path {
+        GlobalPath::Circle(_)
+        | GlobalPath::Bezier(_)
+        | GlobalPath::Ellipse(_) => {
+            let top_path =
+                top_surface.geometry().path_from_surface_curve(*top_curve);
+            let surface = SurfaceGeometry::Ruled {
+                a: bottom_path,
+                b: top_path,
+            };
+
+            (surface, [start, end].map(|i| top.boundary[i].t))
+        }
+        GlobalPath::Line(line) => {
+            // The side face is a plane, whose v-axis connects the start
+            // vertices of the bottom and top edges.
+            let [top_start, top_end] =
+                [start, end].map(|i| top.vertices[i].position());
+            let v =
+                top_start - bottom_path.point_from_path_coords(bottom_start);
+            let surface = SurfaceGeometry::Swept { u: bottom_path, v };
+
+            let direction = line.direction();
+            let top_end = bottom_start.t
+                + (top_end - top_start).dot(&direction)
+                    / direction.dot(&direction);
+
+            (surface, [bottom_start.t, top_end])
+        }
+    };
+    let surface = Surface::new(surface).insert(objects);
+
+    let line = |v: Scalar, boundary: [Point<1>; 2], u: [Scalar; 2]| {
+        let points = [0, 1].map(|i| (boundary[i], Point::from([u[i], v])));
+        let curve: EdgeCurve =
+            Some((Curve::from_points_with_line_coords(points), boundary));
+        curve
+    };
+
+    let corners = vec![
+        (
+            Point::from([bottom_start.t, Scalar::ZERO]),
+            bottom.vertices[start].clone(),
+        ),
+        (
+            Point::from([bottom_end.t, Scalar::ZERO]),
+            bottom.vertices[end].clone(),
+        ),
+        (
+            Point::from([top_end, Scalar::ONE]),
+            top.vertices[end].clone(),
+        ),
+        (
+            Point::from([top_start, Scalar::ONE]),
+            top.vertices[start].clone(),
+        ),
+    ];
+    let top_boundary = [end, start].map(|i| top.boundary[i]);
+    let edges = vec![
+        (
+            line(
+                Scalar::ZERO,
+                [bottom_start, bottom_end],
+                [bottom_start.t, bottom_end.t],
+            ),
+            bottom.global_edge,
+        ),
+        (None, rulings[end].clone()),
+        (
+            match top_curve {
+                Curve::Circle(_) | Curve::Bezier(_) | Curve::Ellipse(_) => {
+                    line(Scalar::ONE, top_boundary, [top_end, top_start])
+                }
+                Curve::Line(_) => None,
+            },
+            top.global_edge,
+        ),
+        (None, rulings[start].clone()),
+    ];
+
+    let exterior = cycle_from_corners(corners, edges, objects);
+    Face::new(surface, exterior, [], color)
+        .with_origin(FaceOrigin::SweepSide)
+        .insert(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        services::Services,
+        test_helpers::{assert_volume, circle, square},
+    };
+
+    use super::{DraftError, SweepWithDraft};
+
+    #[test]
+    fn drafted_square() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep_with_draft(
+                [0., 0., 1.],
+                0.5_f64.atan(),
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // The top face is a square with half the size of the bottom one.
+        assert_volume(&solid, 7. / 3., 0.);
+    }
+
+    #[test]
+    fn drafted_circle() {
+        let mut services = Services::new();
+
+        let solid = circle(1., &mut services.objects)
+            .sweep_with_draft(
+                [0., 0., 1.],
+                0.5_f64.atan(),
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // The result is a frustum of a cone, with a top radius of 0.5.
+        assert_volume(&solid, PI * 1.75 / 3., 0.005);
+    }
+
+    #[test]
+    fn negative_draft() {
+        let mut services = Services::new();
+
+        let solid = square(1., &mut services.objects)
+            .sweep_with_draft(
+                [0., 0., 1.],
+                -(0.5_f64.atan()),
+                &mut services.objects,
+            )
+            .unwrap();
+
+        // The top face is a square with twice the size of the bottom one.
+        assert_volume(&solid, 7. / 3., 0.);
+    }
+
+    #[test]
+    fn negative_sweep() {
+        let mut services = Services::new();
+
+        let solid = square(2., &mut services.objects)
+            .sweep_with_draft(
+                [0., 0., -1.],
+                0.5_f64.atan(),
+                &mut services.objects,
+            )
+            .unwrap();
+
+        assert_volume(&solid, 7. / 3., 0.);
+    }
+
+    #[test]
+    fn invalid_drafts() {
+        let mut services = Services::new();
+
+        let mut sweep = |path: [f64; 3], angle: f64| {
+            square(2., &mut services.objects)
+                .sweep_with_draft(path, angle, &mut services.objects)
+                .map(|_| ())
+        };
+
+        assert_eq!(sweep([1., 0., 0.], 0.1), Err(DraftError::ParallelToFace));
+        assert_eq!(
+            sweep([0., 0., 1.], PI / 2.),
+            Err(DraftError::AngleTooLarge)
+        );
+
+        // The top face would collapse.
+        assert_eq!(
+            sweep([0., 0., 1.], 2_f64.atan()),
+            Err(DraftError::AngleTooLarge)
+        );
+    }
+}
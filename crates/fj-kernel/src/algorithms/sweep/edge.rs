@@ -4,7 +4,7 @@ use fj_math::{Point, Scalar, Vector};
 use crate::{
     builder::{CycleBuilder, HalfEdgeBuilder},
     insert::Insert,
-    objects::{Face, HalfEdge, Objects, Surface, SurfaceVertex},
+    objects::{Face, FaceOrigin, HalfEdge, Objects, Surface, SurfaceVertex},
     partial::{Partial, PartialFace, PartialObject},
     services::Service,
     storage::Handle,
@@ -27,6 +27,7 @@ impl Sweep for (Handle<HalfEdge>, &Handle<SurfaceVertex>, &Surface, Color) {
         // The result of sweeping an edge is a face. Let's create that.
         let mut face = PartialFace {
             color: Some(color),
+            origin: FaceOrigin::SweepSide,
             ..Default::default()
         };
 
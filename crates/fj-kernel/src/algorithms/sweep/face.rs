@@ -7,15 +7,15 @@ use itertools::Itertools;
 use crate::{
     algorithms::{reverse::Reverse, transform::TransformObject},
     builder::{CycleBuilder, FaceBuilder},
-    geometry::curve::GlobalPath,
+    geometry::{curve::GlobalPath, surface::SurfaceGeometry},
     insert::Insert,
-    objects::{Face, Objects, Shell},
+    objects::{Face, FaceOrigin, Objects, Shell},
     partial::{Partial, PartialFace, PartialObject, PartialShell},
     services::Service,
     storage::Handle,
 };
 
-use super::{Sweep, SweepCache};
+use super::{with_origin, Sweep, SweepCache};
 
 impl Sweep for Handle<Face> {
     type Swept = Handle<Shell>;
@@ -31,14 +31,16 @@ impl Sweep for Handle<Face> {
         let mut faces = Vec::new();
 
         let is_negative_sweep = {
-            let u = match self.surface().geometry().u {
-                GlobalPath::Circle(_) => todo!(
+            let (u, v) = match self.surface().geometry() {
+                SurfaceGeometry::Swept {
+                    u: GlobalPath::Line(line),
+                    v,
+                } => (line.direction(), v),
+                _ => todo!(
                     "Sweeping from faces defined in round surfaces is not \
                     supported"
                 ),
-                GlobalPath::Line(line) => line.direction(),
             };
-            let v = self.surface().geometry().v;
 
             let normal = u.cross(&v);
 
@@ -46,11 +48,12 @@ impl Sweep for Handle<Face> {
         };
 
         let bottom_face = {
-            if is_negative_sweep {
+            let face = if is_negative_sweep {
                 self.clone()
             } else {
                 self.clone().reverse(objects)
-            }
+            };
+            with_origin(face, FaceOrigin::SweepBottom, objects)
         };
         faces.push(bottom_face.clone());
 
@@ -59,6 +62,7 @@ impl Sweep for Handle<Face> {
         let mut top_face = PartialFace {
             surface: Partial::from(top_surface.clone()),
             color: Some(self.color()),
+            origin: FaceOrigin::SweepTop,
             ..PartialFace::default()
         };
 
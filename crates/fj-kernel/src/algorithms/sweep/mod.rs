@@ -1,17 +1,25 @@
 //! Sweeping objects along a path to create new objects
 
 mod curve;
+mod draft;
 mod edge;
 mod face;
+mod path;
 mod sketch;
 mod vertex;
 
+pub use self::{
+    draft::{DraftError, SweepWithDraft},
+    path::{SweepAlongPath, SweepPath, SweepPathError, SweepSegment},
+};
+
 use std::collections::BTreeMap;
 
 use fj_math::Vector;
 
 use crate::{
-    objects::{GlobalVertex, Objects},
+    insert::Insert,
+    objects::{Face, FaceOrigin, GlobalVertex, Objects},
     services::Service,
     storage::{Handle, ObjectId},
 };
@@ -48,3 +56,18 @@ pub struct SweepCache {
     /// Cache for global vertices
     pub global_vertex: BTreeMap<ObjectId, Handle<GlobalVertex>>,
 }
+
+/// Record the role that a face plays in a sweep
+///
+/// Creates a copy of the face, unless it already has the given origin.
+fn with_origin(
+    face: Handle<Face>,
+    origin: FaceOrigin,
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    if face.origin() == origin {
+        return face;
+    }
+
+    face.clone_object().with_origin(origin).insert(objects)
+}
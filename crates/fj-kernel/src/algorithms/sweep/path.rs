@@ -0,0 +1,839 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Line, Point, Scalar, Transform, Vector, Winding};
+use itertools::Itertools;
+
+use crate::{
+    algorithms::{
+        reverse::Reverse,
+        revolve::{
+            cycle_from_corners, side_bounds, EdgeCurve, Revolution,
+            RevolveCache,
+        },
+    },
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Cycle, Face, FaceOrigin, GlobalEdge, GlobalVertex, HalfEdge, Objects,
+        Shell, Sketch, Solid, Surface, SurfaceVertex,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::with_origin;
+
+/// A path made of lines and arcs, that objects can be swept along
+///
+/// The path starts at the origin. Each segment starts where the previous one
+/// ends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SweepPath {
+    /// The segments of the path
+    pub segments: Vec<SweepSegment>,
+}
+
+/// A segment of a [`SweepPath`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SweepSegment {
+    /// A straight line to the given point
+    Line(Point<3>),
+
+    /// An arc to the given point
+    ///
+    /// The arc continues the path tangentially. If it's the first segment of
+    /// the path, it starts in the direction of the swept face's normal.
+    Arc(Point<3>),
+}
+
+/// Sweep an object along a path made of lines and arcs
+///
+/// The object is carried along the path, which means it is rotated with the
+/// path, wherever the path changes its direction. Two consecutive lines are
+/// connected by a mitered joint.
+///
+/// # Limitations
+///
+/// Faces must be planar, and the path must not start parallel to them. The path
+/// must not turn back on itself, and its lines and arcs must be long and wide
+/// enough for the face to fit through its joints. Otherwise, the result is a
+/// [`SweepPathError`].
+pub trait SweepAlongPath: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path
+    fn sweep_along_path(
+        self,
+        path: &SweepPath,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept;
+}
+
+/// A path that an object can't be swept along
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepPathError {
+    /// The path has no segments
+    #[error("Can't sweep along empty path")]
+    EmptyPath,
+
+    /// A face is not located in a plane
+    #[error("Sweeping faces in curved surfaces along paths is not supported")]
+    CurvedSurface,
+
+    /// The path starts out parallel to a face
+    #[error("Can't sweep face along path that is parallel to it")]
+    ParallelToFace,
+
+    /// A line of the path has zero length
+    #[error("Can't sweep along line of zero length")]
+    ZeroLengthLine,
+
+    /// The end point of an arc is located on its tangent
+    #[error("Can't sweep along arc whose end point is located on its tangent")]
+    ArcEndOnTangent,
+
+    /// The path turns back on itself
+    #[error("Can't sweep along path that turns back on itself")]
+    TurnsBack,
+
+    /// A face reaches beyond the end of a line, at a joint
+    #[error("Line of path is too short for the swept face")]
+    LineTooShort,
+
+    /// A face reaches across the axis of an arc
+    #[error("Arc of path is too tight for the swept face")]
+    ArcTooTight,
+}
+
+impl SweepAlongPath for Handle<Sketch> {
+    type Swept = Result<Handle<Solid>, SweepPathError>;
+
+    fn sweep_along_path(
+        self,
+        path: &SweepPath,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell = face.sweep_along_path(path, objects)?;
+            shells.push(shell);
+        }
+
+        Ok(Solid::new(shells).insert(objects))
+    }
+}
+
+impl SweepAlongPath for Handle<Face> {
+    type Swept = Result<Handle<Shell>, SweepPathError>;
+
+    fn sweep_along_path(
+        self,
+        path: &SweepPath,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let normal = {
+            let (u, v) = match self.surface().geometry() {
+                SurfaceGeometry::Swept {
+                    u: GlobalPath::Line(line),
+                    v,
+                } => (line.direction(), v),
+                _ => return Err(SweepPathError::CurvedSurface),
+            };
+
+            let normal = u.cross(&v).normalize();
+            match self.exterior().winding() {
+                Winding::Ccw => normal,
+                Winding::Cw => -normal,
+            }
+        };
+
+        let (pieces, is_front) = Piece::compute(path, normal)?;
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        // The front side of the face is the one facing in the direction of
+        // the path.
+        let (mut section, back) = if is_front {
+            (self.clone(), self.reverse(objects))
+        } else {
+            (self.clone().reverse(objects), self)
+        };
+
+        let mut faces =
+            vec![with_origin(back, FaceOrigin::SweepBottom, objects)];
+
+        for piece in pieces {
+            match piece {
+                Piece::Line { direction, end } => {
+                    let mut extrusion = Extrusion::new(direction, end);
+                    let distance = |point| extrusion.distance(point);
+                    if minimum(&section, distance) <= epsilon {
+                        return Err(SweepPathError::LineTooShort);
+                    }
+
+                    faces.extend(extrusion.side_faces(&section, objects));
+                    section = extrusion.moved_face(&section, objects);
+                }
+                Piece::Arc {
+                    axis,
+                    angle,
+                    outward,
+                } => {
+                    let radius =
+                        |point: Point<3>| (point - axis.origin()).dot(&outward);
+                    if minimum(&section, radius) <= epsilon {
+                        return Err(SweepPathError::ArcTooTight);
+                    }
+
+                    let mut cache = RevolveCache::default();
+                    let mut revolution =
+                        Revolution::new(axis, angle, &mut cache);
+                    for face in revolution.side_faces(&section, objects) {
+                        faces.push(with_origin(
+                            face,
+                            FaceOrigin::SweepSide,
+                            objects,
+                        ));
+                    }
+                    section = revolution.rotated_face(&section, objects);
+                }
+            }
+        }
+
+        faces.push(with_origin(section, FaceOrigin::SweepTop, objects));
+
+        Ok(Shell::new(faces).insert(objects))
+    }
+}
+
+/// The minimum of a linear function, over all edges of a planar face
+fn minimum(face: &Face, function: impl Fn(Point<3>) -> Scalar) -> Scalar {
+    let surface = face.surface().geometry();
+    face.all_cycles()
+        .flat_map(|cycle| cycle.half_edges())
+        .map(|half_edge| side_bounds(half_edge, &surface, &function)[0])
+        .fold(Scalar::MAX, Scalar::min)
+}
+
+/// The geometry of a segment of the path
+enum Piece {
+    /// A line in the given direction, ending at the given plane
+    Line { direction: Vector<3>, end: Plane },
+
+    /// An arc around the given axis
+    ///
+    /// The outward direction points from the axis to the start of the arc.
+    Arc {
+        axis: Line<3>,
+        angle: Scalar,
+        outward: Vector<3>,
+    },
+}
+
+impl Piece {
+    /// Compute the pieces of a path
+    ///
+    /// Also returns, whether the path starts out in the direction of the given
+    /// normal.
+    fn compute(
+        path: &SweepPath,
+        normal: Vector<3>,
+    ) -> Result<(Vec<Self>, bool), SweepPathError> {
+        let epsilon = ValidationConfig::default().distinct_min_distance;
+
+        let mut point = Point::origin();
+        let mut tangent: Option<Vector<3>> = None;
+
+        // Lines and arcs, with their start and end tangents. The end planes of
+        // lines are computed further down, as they depend on the following
+        // segment.
+        let mut segments = Vec::new();
+
+        for segment in &path.segments {
+            match *segment {
+                SweepSegment::Line(end) => {
+                    let line = end - point;
+                    if line.magnitude() <= epsilon {
+                        return Err(SweepPathError::ZeroLengthLine);
+                    }
+
+                    let direction = line.normalize();
+                    segments.push((None, direction, direction, end));
+
+                    tangent = Some(direction);
+                    point = end;
+                }
+                SweepSegment::Arc(end) => {
+                    let chord = end - point;
+                    let start_tangent = tangent.unwrap_or_else(|| {
+                        if normal.dot(&chord) < Scalar::ZERO {
+                            -normal
+                        } else {
+                            normal
+                        }
+                    });
+
+                    let to_center =
+                        chord - start_tangent * start_tangent.dot(&chord);
+                    if to_center.magnitude() <= epsilon {
+                        return Err(SweepPathError::ArcEndOnTangent);
+                    }
+                    let to_center = to_center.normalize();
+
+                    let radius =
+                        chord.dot(&chord) / (chord.dot(&to_center) * 2.);
+                    let center = point + to_center * radius;
+                    let direction = start_tangent.cross(&to_center);
+
+                    let [a, b] = [point, end].map(|point| point - center);
+                    let mut angle =
+                        a.cross(&b).dot(&direction).atan2(a.dot(&b));
+                    if angle <= Scalar::ZERO {
+                        angle += Scalar::TAU;
+                    }
+
+                    let axis =
+                        Line::from_origin_and_direction(center, direction);
+                    let end_tangent = direction.cross(&b).normalize();
+                    segments.push((
+                        Some((axis, angle, -to_center)),
+                        start_tangent,
+                        end_tangent,
+                        end,
+                    ));
+
+                    tangent = Some(end_tangent);
+                    point = end;
+                }
+            }
+        }
+
+        let Some(&(_, first_tangent, _, _)) = segments.first() else {
+            return Err(SweepPathError::EmptyPath);
+        };
+        let is_front = match normal.dot(&first_tangent) {
+            dot if dot > epsilon => true,
+            dot if dot < -epsilon => false,
+            _ => return Err(SweepPathError::ParallelToFace),
+        };
+
+        // The normal of the face, carried along the path. It defines the end
+        // plane of the last segment, if that is a line.
+        let mut carried_normal = if is_front { normal } else { -normal };
+
+        let mut pieces = Vec::new();
+        for (i, &(arc, start_tangent, end_tangent, end)) in
+            segments.iter().enumerate()
+        {
+            if let Some((axis, angle, outward)) = arc {
+                carried_normal = Transform::rotation(axis.direction() * angle)
+                    .transform_vector(&carried_normal);
+                pieces.push(Self::Arc {
+                    axis,
+                    angle,
+                    outward,
+                });
+                continue;
+            }
+
+            let direction = start_tangent;
+            let normal = match segments.get(i + 1) {
+                Some(&(None, next, _, _)) => {
+                    // Two lines are connected by a mitered joint, which rotates
+                    // the face in the direction of the next line.
+                    let axis = direction.cross(&next);
+                    if axis.magnitude() > Scalar::ZERO {
+                        let angle = direction.dot(&next).acos();
+                        carried_normal =
+                            Transform::rotation(axis.normalize() * angle)
+                                .transform_vector(&carried_normal);
+                    }
+
+                    direction + next
+                }
+                Some(&(Some(_), next, _, _)) => next,
+                None => carried_normal,
+            };
+            if normal.dot(&end_tangent) <= epsilon {
+                return Err(SweepPathError::TurnsBack);
+            }
+
+            pieces.push(Self::Line {
+                direction,
+                end: Plane {
+                    origin: end,
+                    normal: normal.normalize(),
+                },
+            });
+        }
+
+        Ok((pieces, is_front))
+    }
+}
+
+/// A plane, defined by a point and its normal
+#[derive(Clone, Copy)]
+struct Plane {
+    origin: Point<3>,
+    normal: Vector<3>,
+}
+
+/// The extrusion of a face along a line, up to a plane
+struct Extrusion {
+    direction: Vector<3>,
+    end: Plane,
+    epsilon: Scalar,
+    global_vertex: BTreeMap<ObjectId, Handle<GlobalVertex>>,
+    global_edge: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+    rail: BTreeMap<ObjectId, Handle<GlobalEdge>>,
+}
+
+impl Extrusion {
+    fn new(direction: Vector<3>, end: Plane) -> Self {
+        Self {
+            direction,
+            end,
+            epsilon: ValidationConfig::default().distinct_min_distance,
+            global_vertex: BTreeMap::new(),
+            global_edge: BTreeMap::new(),
+            rail: BTreeMap::new(),
+        }
+    }
+
+    /// The distance a point travels, until it reaches the end plane
+    fn distance(&self, point: Point<3>) -> Scalar {
+        (self.end.origin - point).dot(&self.end.normal)
+            / self.direction.dot(&self.end.normal)
+    }
+
+    /// Project a vector along the line, into the end plane
+    fn project(&self, vector: Vector<3>) -> Vector<3> {
+        vector
+            - self.direction
+                * (vector.dot(&self.end.normal)
+                    / self.direction.dot(&self.end.normal))
+    }
+
+    fn moved_vertex(
+        &mut self,
+        vertex: &Handle<GlobalVertex>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalVertex> {
+        if let Some(moved) = self.global_vertex.get(&vertex.id()) {
+            return moved.clone();
+        }
+
+        let position = vertex.position()
+            + self.direction * self.distance(vertex.position());
+        let moved = GlobalVertex::new(position).insert(objects);
+
+        self.global_vertex.insert(vertex.id(), moved.clone());
+        moved
+    }
+
+    fn moved_edge(
+        &mut self,
+        edge: &Handle<GlobalEdge>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalEdge> {
+        if let Some(moved) = self.global_edge.get(&edge.id()) {
+            return moved.clone();
+        }
+
+        let [a, b] = edge.vertices().access_in_normalized_order();
+        let vertices = [
+            self.moved_vertex(&a, objects),
+            self.moved_vertex(&b, objects),
+        ];
+        let moved = GlobalEdge::new(vertices).insert(objects);
+
+        self.global_edge.insert(edge.id(), moved.clone());
+        moved
+    }
+
+    /// The edge that the given vertex describes while being moved
+    fn rail(
+        &mut self,
+        vertex: &Handle<GlobalVertex>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalEdge> {
+        if let Some(rail) = self.rail.get(&vertex.id()) {
+            return rail.clone();
+        }
+
+        let vertices = [vertex.clone(), self.moved_vertex(vertex, objects)];
+        let rail = GlobalEdge::new(vertices).insert(objects);
+
+        self.rail.insert(vertex.id(), rail.clone());
+        rail
+    }
+
+    /// Create the faces that the half-edges of the front face describe
+    fn side_faces(
+        &mut self,
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Vec<Handle<Face>> {
+        let mut faces = Vec::new();
+
+        for cycle in front.all_cycles() {
+            for (half_edge, next) in
+                cycle.half_edges().cloned().circular_tuple_windows()
+            {
+                let [a, b] = [&half_edge, &next]
+                    .map(|half_edge| half_edge.start_vertex().global_form());
+
+                let face = match half_edge.curve() {
+                    Curve::Line(_) => {
+                        self.planar_face(&half_edge, [a, b], front, objects)
+                    }
+                    Curve::Circle(_) | Curve::Bezier(_) | Curve::Ellipse(_) => {
+                        self.curved_face(&half_edge, [a, b], front, objects)
+                    }
+                };
+                faces.push(face);
+            }
+        }
+
+        faces
+    }
+
+    /// Create the face that a straight edge describes
+    fn planar_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        [a, b]: [&Handle<GlobalVertex>; 2],
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let surface = Surface::new(SurfaceGeometry::Swept {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                a.position(),
+                b.position() - a.position(),
+            )),
+            v: self.direction,
+        })
+        .insert(objects);
+
+        let [distance_a, distance_b] =
+            [a, b].map(|vertex| self.distance(vertex.position()));
+        let corners = vec![
+            (Point::from([Scalar::ZERO, Scalar::ZERO]), a.clone()),
+            (Point::from([Scalar::ONE, Scalar::ZERO]), b.clone()),
+            (
+                Point::from([Scalar::ONE, distance_b]),
+                self.moved_vertex(b, objects),
+            ),
+            (
+                Point::from([Scalar::ZERO, distance_a]),
+                self.moved_vertex(a, objects),
+            ),
+        ];
+        let edges = vec![
+            (None, half_edge.global_form().clone()),
+            (None, self.rail(b, objects)),
+            (None, self.moved_edge(half_edge.global_form(), objects)),
+            (None, self.rail(a, objects)),
+        ];
+
+        let exterior = cycle_from_corners(corners, edges, objects);
+        Face::new(surface, exterior, [], front.color())
+            .with_origin(FaceOrigin::SweepSide)
+            .insert(objects)
+    }
+
+    /// Create the face that a circular edge describes
+    fn curved_face(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        [a, b]: [&Handle<GlobalVertex>; 2],
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let geometry = front.surface().geometry();
+        let [u, v] = [Vector::unit_u(), Vector::unit_v()]
+            .map(|vector| geometry.vector_from_surface_coords(vector));
+
+        // The curve keeps its shape, if it is moved by the same distance
+        // everywhere. Otherwise, like at a mitered joint, the face is a ruled
+        // surface between the curve and its projection into the end plane.
+        // If the face has been distorted by a joint, a circle turns into an
+        // ellipse, which is fine.
+        let is_parallel =
+            u.cross(&v).normalize().cross(&self.end.normal).magnitude()
+                <= self.epsilon;
+
+        let path = geometry.path_from_surface_curve(half_edge.curve());
+        let surface = if is_parallel {
+            SurfaceGeometry::Swept {
+                u: path,
+                v: self.direction * self.distance(a.position()),
+            }
+        } else {
+            SurfaceGeometry::Ruled {
+                a: path,
+                b: self
+                    .moved_surface(front)
+                    .path_from_surface_curve(half_edge.curve()),
+            }
+        };
+        let surface = Surface::new(surface).insert(objects);
+
+        // The bottom and top edges are lines on the surface. Their line
+        // coordinates match the coordinates of the curve, which makes sure
+        // they share their approximations with the front face and the moved
+        // face.
+        let [start, end] = half_edge.boundary().map(|point| point.t);
+        let line = |v: Scalar, boundary: [Scalar; 2]| {
+            let points =
+                boundary.map(|u| (Point::from([u]), Point::from([u, v])));
+            let curve = Curve::from_points_with_line_coords(points);
+            Some((curve, boundary.map(|t| Point::from([t]))))
+        };
+
+        let corners = vec![
+            (Point::from([start, Scalar::ZERO]), a.clone()),
+            (Point::from([end, Scalar::ZERO]), b.clone()),
+            (
+                Point::from([end, Scalar::ONE]),
+                self.moved_vertex(b, objects),
+            ),
+            (
+                Point::from([start, Scalar::ONE]),
+                self.moved_vertex(a, objects),
+            ),
+        ];
+        let edges: Vec<(EdgeCurve, _)> = vec![
+            (
+                line(Scalar::ZERO, [start, end]),
+                half_edge.global_form().clone(),
+            ),
+            (None, self.rail(b, objects)),
+            (
+                line(Scalar::ONE, [end, start]),
+                self.moved_edge(half_edge.global_form(), objects),
+            ),
+            (None, self.rail(a, objects)),
+        ];
+
+        let exterior = cycle_from_corners(corners, edges, objects);
+        Face::new(surface, exterior, [], front.color())
+            .with_origin(FaceOrigin::SweepSide)
+            .insert(objects)
+    }
+
+    /// The projection of the front face's surface into the end plane
+    ///
+    /// This way, the projected surface has the same surface coordinates as the
+    /// front face.
+    fn moved_surface(&self, front: &Face) -> SurfaceGeometry {
+        let SurfaceGeometry::Swept {
+            u: GlobalPath::Line(u),
+            v,
+        } = front.surface().geometry()
+        else {
+            unreachable!("Faces that are swept along paths are planar")
+        };
+
+        let origin = u.origin() + self.direction * self.distance(u.origin());
+        SurfaceGeometry::Swept {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                origin,
+                self.project(u.direction()),
+            )),
+            v: self.project(v),
+        }
+    }
+
+    /// Create a copy of the front face, moved to the end plane
+    ///
+    /// The copy uses the surface that [`Extrusion::moved_surface`] computes.
+    fn moved_face(
+        &mut self,
+        front: &Face,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let surface = Surface::new(self.moved_surface(front)).insert(objects);
+
+        let mut surface_vertices: BTreeMap<ObjectId, Handle<SurfaceVertex>> =
+            BTreeMap::new();
+        let mut cycles = Vec::new();
+
+        for cycle in front.all_cycles() {
+            let mut half_edges = Vec::new();
+
+            for half_edge in cycle.half_edges() {
+                let [start, end] = half_edge.surface_vertices().map(|vertex| {
+                    if let Some(moved) = surface_vertices.get(&vertex.id()) {
+                        return moved.clone();
+                    }
+
+                    let global_form =
+                        self.moved_vertex(vertex.global_form(), objects);
+                    let moved =
+                        SurfaceVertex::new(vertex.position(), global_form)
+                            .insert(objects);
+
+                    surface_vertices.insert(vertex.id(), moved.clone());
+                    moved
+                });
+
+                half_edges.push(
+                    HalfEdge::new(
+                        half_edge.curve(),
+                        half_edge.boundary(),
+                        [start, end],
+                        self.moved_edge(half_edge.global_form(), objects),
+                    )
+                    .insert(objects),
+                );
+            }
+
+            cycles.push(Cycle::new(half_edges).insert(objects));
+        }
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Face must have exterior");
+        Face::new(surface, exterior, cycles, front.color()).insert(objects)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Point;
+
+    use crate::{
+        services::Services,
+        test_helpers::{assert_volume, circle, square},
+    };
+
+    use super::{SweepAlongPath, SweepPath, SweepPathError, SweepSegment};
+
+    #[test]
+    fn mitered_lines() {
+        let mut services = Services::new();
+
+        let path = SweepPath {
+            segments: vec![
+                SweepSegment::Line(Point::from([0., 0., 2.])),
+                SweepSegment::Line(Point::from([2., 0., 2.])),
+                SweepSegment::Line(Point::from([2., 2., 4.])),
+            ],
+        };
+        let solid = square(1., &mut services.objects)
+            .sweep_along_path(&path, &mut services.objects)
+            .unwrap();
+
+        // The volume of a mitered sweep is the area of the face, times the
+        // length of the path.
+        let length = 4. + 8_f64.sqrt();
+        assert_volume(&solid, length, 1e-9);
+    }
+
+    #[test]
+    fn lines_and_arcs() {
+        let mut services = Services::new();
+
+        let path = SweepPath {
+            segments: vec![
+                SweepSegment::Arc(Point::from([2., 0., 2.])),
+                SweepSegment::Line(Point::from([3., 0., 2.])),
+                SweepSegment::Arc(Point::from([4., 0., 3.])),
+                SweepSegment::Arc(Point::from([4., 2., 5.])),
+            ],
+        };
+        let solid = square(1., &mut services.objects)
+            .sweep_along_path(&path, &mut services.objects)
+            .unwrap();
+
+        // The centroid of the face follows the path, so the volume is the area
+        // of the face times the length of the path.
+        let length = PI + 1. + PI / 2. + PI;
+        assert_volume(&solid, length, 0.001);
+    }
+
+    #[test]
+    fn circle_along_line() {
+        let mut services = Services::new();
+
+        let path = SweepPath {
+            segments: vec![SweepSegment::Line(Point::from([1., 0., 2.]))],
+        };
+        let solid = circle(1., &mut services.objects)
+            .sweep_along_path(&path, &mut services.objects)
+            .unwrap();
+
+        assert_volume(&solid, PI * 2., 0.005);
+    }
+
+    #[test]
+    fn circle_through_miter() {
+        let mut services = Services::new();
+
+        let path = SweepPath {
+            segments: vec![
+                SweepSegment::Line(Point::from([0., 0., 2.])),
+                SweepSegment::Line(Point::from([2., 0., 2.])),
+            ],
+        };
+        let solid = circle(0.5, &mut services.objects)
+            .sweep_along_path(&path, &mut services.objects)
+            .unwrap();
+
+        // Like for the square, the volume is the area of the face, times the
+        // length of the path.
+        assert_volume(&solid, PI / 4. * 4., 0.005);
+    }
+
+    #[test]
+    fn invalid_paths() {
+        let mut services = Services::new();
+
+        let mut sweep = |segments| {
+            square(1., &mut services.objects)
+                .sweep_along_path(
+                    &SweepPath { segments },
+                    &mut services.objects,
+                )
+                .map(|_| ())
+        };
+
+        assert_eq!(sweep(vec![]), Err(SweepPathError::EmptyPath));
+        assert_eq!(
+            sweep(vec![SweepSegment::Line(Point::from([1., 0., 0.]))]),
+            Err(SweepPathError::ParallelToFace)
+        );
+        assert_eq!(
+            sweep(vec![SweepSegment::Line(Point::origin())]),
+            Err(SweepPathError::ZeroLengthLine)
+        );
+        assert_eq!(
+            sweep(vec![SweepSegment::Arc(Point::from([0., 0., 1.]))]),
+            Err(SweepPathError::ArcEndOnTangent)
+        );
+        assert_eq!(
+            sweep(vec![
+                SweepSegment::Line(Point::from([0., 0., 2.])),
+                SweepSegment::Line(Point::from([0., 0., 1.])),
+            ]),
+            Err(SweepPathError::TurnsBack)
+        );
+        assert_eq!(
+            sweep(vec![
+                SweepSegment::Line(Point::from([0., 0., 0.25])),
+                SweepSegment::Line(Point::from([2., 0., 0.25])),
+            ]),
+            Err(SweepPathError::LineTooShort)
+        );
+        assert_eq!(
+            sweep(vec![SweepSegment::Arc(Point::from([0.5, 0., 0.5]))]),
+            Err(SweepPathError::ArcTooTight)
+        );
+    }
+}
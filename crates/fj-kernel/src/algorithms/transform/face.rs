@@ -1,6 +1,7 @@
 use fj_math::Transform;
 
 use crate::{
+    algorithms::reverse::Reverse,
     objects::{Face, FaceSet, Objects},
     services::Service,
 };
@@ -14,22 +15,40 @@ impl TransformObject for Face {
         objects: &mut Service<Objects>,
         cache: &mut TransformCache,
     ) -> Self {
-        // Color does not need to be transformed.
+        // Color and origin do not need to be transformed.
         let color = self.color();
+        let origin = self.origin();
 
         let surface = self
             .surface()
             .clone()
             .transform_with_cache(transform, objects, cache);
-        let exterior = self
+        let mut exterior = self
             .exterior()
             .clone()
             .transform_with_cache(transform, objects, cache);
-        let interiors = self.interiors().cloned().map(|interior| {
-            interior.transform_with_cache(transform, objects, cache)
-        });
+        let mut interiors = self
+            .interiors()
+            .cloned()
+            .map(|interior| {
+                interior.transform_with_cache(transform, objects, cache)
+            })
+            .collect::<Vec<_>>();
 
-        Self::new(surface, exterior, interiors, color)
+        // A transform that reverses orientation, like a reflection, keeps the
+        // surface coordinates of the cycles, but flips the side of the surface
+        // from which they appear counter-clockwise. Reversing the cycles keeps
+        // the front side of the face pointing the same way, relative to the
+        // transformed shape.
+        if transform.reverses_orientation() {
+            exterior = exterior.reverse(objects);
+            interiors = interiors
+                .into_iter()
+                .map(|interior| interior.reverse(objects))
+                .collect();
+        }
+
+        Self::new(surface, exterior, interiors, color).with_origin(origin)
     }
 }
 
@@ -49,3 +68,45 @@ impl TransformObject for FaceSet {
         faces
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Transform;
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        builder::CycleBuilder,
+        insert::Insert,
+        objects::Handedness,
+        partial::{Partial, PartialFace, PartialObject},
+        services::Services,
+    };
+
+    #[test]
+    fn reflect_face() {
+        let mut services = Services::new();
+
+        let mut face = PartialFace {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        face.exterior.write().update_as_polygon_from_points([
+            [0., 0.],
+            [1., 0.],
+            [0., 1.],
+        ]);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        assert_eq!(face.coord_handedness(), Handedness::RightHanded);
+
+        // The reflection leaves the surface coordinate system as it is, but
+        // the front side of the face must now point towards negative z.
+        let face = face.transform(
+            &Transform::reflection([0., 0., 1.]),
+            &mut services.objects,
+        );
+        assert_eq!(face.coord_handedness(), Handedness::LeftHanded);
+    }
+}
@@ -3,11 +3,18 @@ use std::collections::BTreeMap;
 use fj_math::{Point, Scalar, Triangle, Winding};
 use spade::HasPosition;
 
-use crate::{algorithms::approx::cycle::CycleApprox, objects::Handedness};
+use crate::{
+    algorithms::approx::{cycle::CycleApprox, ApproxPoint},
+    objects::Handedness,
+};
 
 /// Create a Delaunay triangulation of all points
+///
+/// The edges of the cycles constrain the triangulation. The additional points
+/// don't.
 pub fn triangulate(
     cycles: impl IntoIterator<Item = CycleApprox>,
+    additional_points: impl IntoIterator<Item = ApproxPoint<2>>,
     coord_handedness: Handedness,
 ) -> Vec<[TriangulationPoint; 3]> {
     use spade::Triangulation as _;
@@ -44,6 +51,20 @@ pub fn triangulate(
         }
     }
 
+    for point in additional_points {
+        if points.contains_key(&point) {
+            continue;
+        }
+
+        let handle = triangulation
+            .insert(TriangulationPoint {
+                point_surface: point.local_form,
+                point_global: point.global_form,
+            })
+            .expect("Inserted invalid point into triangulation");
+        points.insert(point, handle);
+    }
+
     let mut triangles = Vec::new();
     for triangle in triangulation.inner_faces() {
         let [v0, v1, v2] = triangle.vertices().map(|vertex| *vertex.data());
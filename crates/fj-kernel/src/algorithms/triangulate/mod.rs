@@ -57,12 +57,20 @@ impl Triangulate for FaceApprox {
 
         let cycles = [self.exterior].into_iter().chain(self.interiors);
         let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
+            delaunay::triangulate(cycles, self.surface, self.coord_handedness);
         triangles.retain(|triangle| {
             face_as_polygon
                 .contains_triangle(triangle.map(|point| point.point_surface))
         });
 
+        // Distinct points on a surface can be the same point in model
+        // coordinates, like the points along an edge that collapses into the
+        // pole of a sphere. Triangles between those points are degenerate.
+        triangles.retain(|triangle| {
+            let [a, b, c] = triangle.map(|point| point.point_global);
+            a != b && b != c && c != a
+        });
+
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
             mesh.push_triangle(points, self.color);
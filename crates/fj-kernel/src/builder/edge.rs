@@ -1,5 +1,5 @@
 use fj_interop::ext::ArrayExt;
-use fj_math::{Point, Scalar};
+use fj_math::{CubicBezier, Point, Scalar};
 
 use crate::{
     geometry::{
@@ -28,6 +28,24 @@ pub trait HalfEdgeBuilder {
         radius: impl Into<Scalar>,
     ) -> Curve;
 
+    /// Update partial half-edge to be a circle, from the given center and
+    /// radius
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Curve;
+
+    /// Update partial half-edge to be an ellipse, from the given center and
+    /// radii
+    ///
+    /// The radii are along the u- and v-axis of the surface, respectively.
+    fn update_as_ellipse_from_center_and_radii(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radii: [impl Into<Scalar>; 2],
+    ) -> Curve;
+
     /// Update partial half-edge to be an arc, spanning the given angle in
     /// radians
     ///
@@ -36,6 +54,12 @@ pub trait HalfEdgeBuilder {
     /// Panics if the given angle is not within the range (-2pi, 2pi) radians.
     fn update_as_arc(&mut self, angle_rad: impl Into<Scalar>);
 
+    /// Update partial half-edge to be a cubic Bézier curve
+    ///
+    /// The curve runs from the start to the end vertex of the half-edge. The
+    /// given points are its inner control points.
+    fn update_as_bezier(&mut self, controls: [impl Into<Point<2>>; 2]);
+
     /// Update partial half-edge to be a line segment, from the given points
     fn update_as_line_segment_from_points(
         &mut self,
@@ -90,31 +114,26 @@ impl HalfEdgeBuilder for PartialHalfEdge {
         &mut self,
         radius: impl Into<Scalar>,
     ) -> Curve {
-        let path = Curve::circle_from_radius(radius);
-        self.curve = Some(path.into());
-
-        let [a_curve, b_curve] =
-            [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
-
-        let mut surface_vertex = {
-            let [vertex, _] = &mut self.surface_vertices;
-            vertex.clone()
-        };
-        surface_vertex.write().position =
-            Some(path.point_from_path_coords(a_curve));
-
-        for (vertex, point_curve) in self
-            .boundary
-            .each_mut_ext()
-            .zip_ext(self.surface_vertices.each_mut_ext())
-            .zip_ext([a_curve, b_curve])
-        {
-            *vertex.0 = Some(point_curve);
-            *vertex.1 = surface_vertex.clone();
-        }
+        self.update_as_circle_from_center_and_radius(Point::origin(), radius)
+    }
 
-        self.infer_global_form();
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Curve {
+        let path = Curve::circle_from_center_and_radius(center, radius);
+        update_as_closed_curve(self, path);
+        path
+    }
 
+    fn update_as_ellipse_from_center_and_radii(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radii: [impl Into<Scalar>; 2],
+    ) -> Curve {
+        let path = Curve::ellipse_from_center_and_radii(center, radii);
+        update_as_closed_curve(self, path);
         path
     }
 
@@ -152,6 +171,29 @@ impl HalfEdgeBuilder for PartialHalfEdge {
         self.infer_global_form();
     }
 
+    fn update_as_bezier(&mut self, controls: [impl Into<Point<2>>; 2]) {
+        let [start, end] = self.surface_vertices.each_ref_ext().map(|vertex| {
+            vertex
+                .read()
+                .position
+                .expect("Can't infer Bézier curve without surface position")
+        });
+        let [b, c] = controls.map(Into::into);
+
+        let path = Curve::Bezier(CubicBezier::from_points([start, b, c, end]));
+        self.curve = Some(path.into());
+
+        for (vertex, point_curve) in self
+            .boundary
+            .each_mut_ext()
+            .zip_ext([Point::from([0.]), Point::from([1.])])
+        {
+            *vertex = Some(point_curve);
+        }
+
+        self.infer_global_form();
+    }
+
     fn update_as_line_segment_from_points(
         &mut self,
         points: [impl Into<Point<2>>; 2],
@@ -265,8 +307,11 @@ impl HalfEdgeBuilder for PartialHalfEdge {
             // We have information about the other edge's surface available. We
             // need to use that to interpret what the other edge's curve path
             // means for our curve path.
-            match surface.u {
-                GlobalPath::Circle(circle) => {
+            match surface {
+                SurfaceGeometry::Swept {
+                    u: GlobalPath::Circle(circle),
+                    ..
+                } => {
                     // The other surface is curved. We're entering some dodgy
                     // territory here, as only some edge cases can be
                     // represented using our current curve/surface
@@ -314,7 +359,19 @@ impl HalfEdgeBuilder for PartialHalfEdge {
                         }
                     }
                 }
-                GlobalPath::Line(_) => {
+                SurfaceGeometry::Swept {
+                    u: GlobalPath::Bezier(_) | GlobalPath::Ellipse(_),
+                    ..
+                } => {
+                    // The other surface is a free-form or elliptic one. There's no way to
+                    // represent our edge without knowing more about it, so
+                    // it's up to the caller to define its curve.
+                    None
+                }
+                SurfaceGeometry::Swept {
+                    u: GlobalPath::Line(_),
+                    ..
+                } => {
                     // The other edge is defined on a plane.
                     match path {
                         MaybeCurve::Defined(Curve::Line(_))
@@ -332,6 +389,12 @@ impl HalfEdgeBuilder for PartialHalfEdge {
                         }
                     }
                 }
+                SurfaceGeometry::Ruled { .. } => {
+                    todo!("Can't connect edge to edge on ruled surface")
+                }
+                SurfaceGeometry::Revolved { .. } => {
+                    todo!("Can't connect edge to edge on revolved surface")
+                }
             }
         });
 
@@ -346,6 +409,35 @@ impl HalfEdgeBuilder for PartialHalfEdge {
     }
 }
 
+/// Update a partial half-edge to go around a closed curve once
+///
+/// Both vertices of the half-edge are at the curve's origin.
+fn update_as_closed_curve(half_edge: &mut PartialHalfEdge, path: Curve) {
+    half_edge.curve = Some(path.into());
+
+    let [a_curve, b_curve] =
+        [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
+
+    let mut surface_vertex = {
+        let [vertex, _] = &mut half_edge.surface_vertices;
+        vertex.clone()
+    };
+    surface_vertex.write().position =
+        Some(path.point_from_path_coords(a_curve));
+
+    for (vertex, point_curve) in half_edge
+        .boundary
+        .each_mut_ext()
+        .zip_ext(half_edge.surface_vertices.each_mut_ext())
+        .zip_ext([a_curve, b_curve])
+    {
+        *vertex.0 = Some(point_curve);
+        *vertex.1 = surface_vertex.clone();
+    }
+
+    half_edge.infer_global_form();
+}
+
 /// Builder API for [`PartialGlobalEdge`]
 pub trait GlobalEdgeBuilder {
     // No methods are currently defined. This trait serves as a placeholder, to
@@ -1,9 +1,593 @@
-use crate::partial::PartialSolid;
+use std::collections::BTreeMap;
+
+use fj_interop::ext::ArrayExt;
+use fj_math::{Circle, Line, Point, Scalar, Vector, Winding};
+
+use crate::{
+    geometry::{
+        curve::{Curve, GlobalPath},
+        surface::SurfaceGeometry,
+    },
+    objects::{Face, GlobalEdge, GlobalVertex, HalfEdge, Shell, SurfaceVertex},
+    partial::{
+        Partial, PartialCycle, PartialFace, PartialGlobalEdge,
+        PartialGlobalVertex, PartialHalfEdge, PartialShell, PartialSolid,
+        PartialSurface, PartialSurfaceVertex,
+    },
+};
 
 /// Builder API for [`PartialSolid`]
+///
+/// The `update_as_*` methods replace all shells of the solid with a single
+/// shell, which forms the respective primitive. The faces of that shell have
+/// no color, which can be set on the partial faces before building them.
 pub trait SolidBuilder {
-    // No methods are currently defined. This trait serves as a placeholder, to
-    // make it clear where to add such methods, once necessary.
+    /// Update the solid as a cuboid, centered around the origin
+    fn update_as_cuboid(&mut self, size: impl Into<Vector<3>>);
+
+    /// Update the solid as a cylinder
+    ///
+    /// The bottom of the cylinder is centered around the origin of the
+    /// xy-plane. The cylinder extends along the positive z-axis.
+    fn update_as_cylinder(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    );
+
+    /// Update the solid as a cone
+    ///
+    /// The base of the cone is centered around the origin of the xy-plane. Its
+    /// apex is located on the positive z-axis.
+    fn update_as_cone(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    );
+
+    /// Update the solid as a sphere, centered around the origin
+    fn update_as_sphere(&mut self, radius: impl Into<Scalar>);
+
+    /// Update the solid as a torus, centered around the origin
+    ///
+    /// The major radius is the distance from the z-axis, which the torus
+    /// revolves around, to the center of its tube. The minor radius is the
+    /// radius of the tube.
+    fn update_as_torus(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    );
+}
+
+impl SolidBuilder for PartialSolid {
+    fn update_as_cuboid(&mut self, size: impl Into<Vector<3>>) {
+        let size = size.into();
+        assert!(
+            size.components.iter().all(|&length| length > Scalar::ZERO),
+            "Cuboid must have a positive size"
+        );
+
+        // The index of a vertex encodes on which side of the cuboid it is
+        // located: The lowest bit along the x-axis, the highest along the
+        // z-axis.
+        let vertices = (0..8)
+            .map(|i| {
+                let side = |bit: usize| {
+                    if i & (1 << bit) == 0 {
+                        Scalar::from(-0.5)
+                    } else {
+                        Scalar::from(0.5)
+                    }
+                };
+                global_vertex([
+                    size.x * side(0),
+                    size.y * side(1),
+                    size.z * side(2),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        // The corners of each face, counter-clockwise when viewed from outside
+        // of the cuboid.
+        let faces = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+        ];
+
+        let mut edges = BTreeMap::new();
+        let faces = faces.map(|corners| {
+            let positions = corners.map(|i| {
+                vertices[i]
+                    .read()
+                    .position
+                    .expect("Cuboid vertices have positions")
+            });
+            let [a, b, _, d] = positions;
+            let (u, _) = GlobalPath::line_from_points([a, b]);
+            let surface = SurfaceGeometry::Swept { u, v: d - a };
+
+            let edges = [0, 1, 2, 3].map(|i| {
+                let [a, b] = [corners[i], corners[(i + 1) % 4]];
+                edges
+                    .entry((a.min(b), a.max(b)))
+                    .or_insert_with(|| {
+                        global_edge([&vertices[a], &vertices[b]])
+                    })
+                    .clone()
+            });
+
+            rectangular_face(
+                surface,
+                [0., 1.],
+                [0., 1.],
+                corners.map(|i| vertices[i].clone()),
+                edges,
+            )
+        });
+
+        self.shells = vec![shell(faces)];
+    }
+
+    fn update_as_cylinder(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    ) {
+        let radius = radius.into();
+        let height = height.into();
+        assert!(
+            radius > Scalar::ZERO && height > Scalar::ZERO,
+            "Cylinder must have a positive radius and height"
+        );
+
+        let bottom_vertex = global_vertex([radius, Scalar::ZERO, Scalar::ZERO]);
+        let top_vertex = global_vertex([radius, Scalar::ZERO, height]);
+
+        let bottom_edge = global_edge([&bottom_vertex, &bottom_vertex]);
+        let top_edge = global_edge([&top_vertex, &top_vertex]);
+        let seam = global_edge([&bottom_vertex, &top_vertex]);
+
+        let side = rectangular_face(
+            SurfaceGeometry::Swept {
+                u: GlobalPath::circle_from_radius(radius),
+                v: Vector::from([Scalar::ZERO, Scalar::ZERO, height]),
+            },
+            [Scalar::ZERO, Scalar::TAU],
+            [0., 1.],
+            [
+                bottom_vertex.clone(),
+                bottom_vertex.clone(),
+                top_vertex.clone(),
+                top_vertex.clone(),
+            ],
+            [bottom_edge.clone(), seam.clone(), top_edge.clone(), seam],
+        );
+        let bottom = disk_face(
+            Point::origin(),
+            radius,
+            Winding::Cw,
+            bottom_vertex,
+            bottom_edge,
+        );
+        let top = disk_face(
+            Point::from([Scalar::ZERO, Scalar::ZERO, height]),
+            radius,
+            Winding::Ccw,
+            top_vertex,
+            top_edge,
+        );
+
+        self.shells = vec![shell([bottom, side, top])];
+    }
+
+    fn update_as_cone(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    ) {
+        let radius = radius.into();
+        let height = height.into();
+        assert!(
+            radius > Scalar::ZERO && height > Scalar::ZERO,
+            "Cone must have a positive radius and height"
+        );
+
+        let rim = Point::from([radius, Scalar::ZERO, Scalar::ZERO]);
+        let apex = Point::from([Scalar::ZERO, Scalar::ZERO, height]);
+
+        let rim_vertex = global_vertex(rim);
+        let apex_vertex = global_vertex(apex);
+
+        let base_edge = global_edge([&rim_vertex, &rim_vertex]);
+        let seam = global_edge([&rim_vertex, &apex_vertex]);
+        let apex_edge = global_edge([&apex_vertex, &apex_vertex]);
+
+        let (path, _) = GlobalPath::line_from_points([rim, apex]);
+        let side = rectangular_face(
+            SurfaceGeometry::Revolved {
+                path,
+                axis: z_axis(),
+            },
+            [Scalar::ZERO, Scalar::TAU],
+            [0., 1.],
+            [
+                rim_vertex.clone(),
+                rim_vertex.clone(),
+                apex_vertex.clone(),
+                apex_vertex,
+            ],
+            [base_edge.clone(), seam.clone(), apex_edge, seam],
+        );
+        let base = disk_face(
+            Point::origin(),
+            radius,
+            Winding::Cw,
+            rim_vertex,
+            base_edge,
+        );
+
+        self.shells = vec![shell([base, side])];
+    }
+
+    fn update_as_sphere(&mut self, radius: impl Into<Scalar>) {
+        let radius = radius.into();
+        assert!(radius > Scalar::ZERO, "Sphere must have a positive radius");
+
+        let south_pole = global_vertex([Scalar::ZERO, Scalar::ZERO, -radius]);
+        let north_pole = global_vertex([Scalar::ZERO, Scalar::ZERO, radius]);
+
+        // The edges at the poles are collapsed into a single point each.
+        let south_edge = global_edge([&south_pole, &south_pole]);
+        let meridian = global_edge([&south_pole, &north_pole]);
+        let north_edge = global_edge([&north_pole, &north_pole]);
+
+        // A half circle from the south pole to the north pole, through the
+        // positive x-axis.
+        let path = GlobalPath::Circle(Circle::new(
+            Point::origin(),
+            Vector::from([Scalar::ZERO, Scalar::ZERO, -radius]),
+            Vector::from([radius, Scalar::ZERO, Scalar::ZERO]),
+        ));
+        let face = rectangular_face(
+            SurfaceGeometry::Revolved {
+                path,
+                axis: z_axis(),
+            },
+            [Scalar::ZERO, Scalar::TAU],
+            [Scalar::ZERO, Scalar::PI],
+            [
+                south_pole.clone(),
+                south_pole,
+                north_pole.clone(),
+                north_pole,
+            ],
+            [south_edge, meridian.clone(), north_edge, meridian],
+        );
+
+        self.shells = vec![shell([face])];
+    }
+
+    fn update_as_torus(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    ) {
+        let major_radius = major_radius.into();
+        let minor_radius = minor_radius.into();
+        assert!(
+            minor_radius > Scalar::ZERO && major_radius > minor_radius,
+            "Torus must have a positive minor radius that is smaller than its \
+            major radius"
+        );
+
+        let vertex = global_vertex([
+            major_radius + minor_radius,
+            Scalar::ZERO,
+            Scalar::ZERO,
+        ]);
+
+        let equator = global_edge([&vertex, &vertex]);
+        let meridian = global_edge([&vertex, &vertex]);
+
+        // The circle that forms the tube, starting on its outside.
+        let path = GlobalPath::Circle(Circle::new(
+            Point::from([major_radius, Scalar::ZERO, Scalar::ZERO]),
+            Vector::from([minor_radius, Scalar::ZERO, Scalar::ZERO]),
+            Vector::from([Scalar::ZERO, Scalar::ZERO, minor_radius]),
+        ));
+        let face = rectangular_face(
+            SurfaceGeometry::Revolved {
+                path,
+                axis: z_axis(),
+            },
+            [Scalar::ZERO, Scalar::TAU],
+            [Scalar::ZERO, Scalar::TAU],
+            [vertex.clone(), vertex.clone(), vertex.clone(), vertex],
+            [equator.clone(), meridian.clone(), equator, meridian],
+        );
+
+        self.shells = vec![shell([face])];
+    }
+}
+
+/// Create a face that covers a rectangle in surface coordinates
+///
+/// The vertices are the corners of the rectangle, counter-clockwise, starting
+/// at the minimum of both coordinates. The edges are the sides of the rectangle
+/// in the same order, starting with the side along the u-axis.
+///
+/// The surface's normal must point outside of the solid.
+fn rectangular_face(
+    surface: SurfaceGeometry,
+    range_u: [impl Into<Scalar>; 2],
+    range_v: [impl Into<Scalar>; 2],
+    vertices: [Partial<GlobalVertex>; 4],
+    edges: [Partial<GlobalEdge>; 4],
+) -> Partial<Face> {
+    let [u0, u1] = range_u.map(Into::into);
+    let [v0, v1] = range_v.map(Into::into);
+
+    let corners = [[u0, v0], [u1, v0], [u1, v1], [u0, v1]];
+    let surface_vertices = corners
+        .zip_ext(vertices)
+        .map(|(position, vertex)| surface_vertex(position, vertex));
+
+    // Opposite sides share their parametrization, so the sides of a seam
+    // approximate their edge in the same way.
+    let along_u = |v| {
+        Curve::Line(Line::from_origin_and_direction(
+            Point::from([Scalar::ZERO, v]),
+            Vector::from([Scalar::ONE, Scalar::ZERO]),
+        ))
+    };
+    let along_v = |u| {
+        Curve::Line(Line::from_origin_and_direction(
+            Point::from([u, Scalar::ZERO]),
+            Vector::from([Scalar::ZERO, Scalar::ONE]),
+        ))
+    };
+    let sides = [
+        (along_u(v0), [u0, u1]),
+        (along_v(u1), [v0, v1]),
+        (along_u(v1), [u1, u0]),
+        (along_v(u0), [v1, v0]),
+    ];
+
+    let half_edges = sides
+        .zip_ext(edges)
+        .into_iter()
+        .enumerate()
+        .map(|(i, ((curve, boundary), global_form))| {
+            half_edge(
+                curve,
+                boundary,
+                [
+                    surface_vertices[i].clone(),
+                    surface_vertices[(i + 1) % 4].clone(),
+                ],
+                global_form,
+            )
+        })
+        .collect();
+
+    face(surface, half_edges)
+}
+
+/// Create a planar face that is bounded by a circle
+///
+/// The face is parallel to the xy-plane. It is facing upwards, if its exterior
+/// is wound counter-clockwise, and downwards otherwise. The circle starts at
+/// the point on the face that lies in positive x-direction from its center.
+fn disk_face(
+    center: Point<3>,
+    radius: Scalar,
+    winding: Winding,
+    vertex: Partial<GlobalVertex>,
+    edge: Partial<GlobalEdge>,
+) -> Partial<Face> {
+    let surface = SurfaceGeometry::Swept {
+        u: GlobalPath::Line(Line::from_origin_and_direction(
+            center,
+            Vector::unit_x(),
+        )),
+        v: Vector::unit_y(),
+    };
+
+    let curve = Curve::circle_from_radius(radius);
+    let boundary = match winding {
+        Winding::Ccw => [Scalar::ZERO, Scalar::TAU],
+        Winding::Cw => [Scalar::TAU, Scalar::ZERO],
+    };
+    let surface_vertex = surface_vertex([radius, Scalar::ZERO], vertex);
+
+    let half_edge = half_edge(
+        curve,
+        boundary,
+        [surface_vertex.clone(), surface_vertex],
+        edge,
+    );
+
+    face(surface, vec![half_edge])
+}
+
+fn z_axis() -> Line<3> {
+    Line::from_origin_and_direction(Point::origin(), Vector::unit_z())
+}
+
+fn shell(faces: impl IntoIterator<Item = Partial<Face>>) -> Partial<Shell> {
+    Partial::from_partial(PartialShell {
+        faces: faces.into_iter().collect(),
+    })
+}
+
+fn face(
+    surface: SurfaceGeometry,
+    half_edges: Vec<Partial<HalfEdge>>,
+) -> Partial<Face> {
+    Partial::from_partial(PartialFace {
+        surface: Partial::from_partial(PartialSurface {
+            geometry: Some(surface),
+        }),
+        exterior: Partial::from_partial(PartialCycle { half_edges }),
+        ..Default::default()
+    })
+}
+
+fn half_edge(
+    curve: Curve,
+    boundary: [Scalar; 2],
+    surface_vertices: [Partial<SurfaceVertex>; 2],
+    global_form: Partial<GlobalEdge>,
+) -> Partial<HalfEdge> {
+    Partial::from_partial(PartialHalfEdge {
+        curve: Some(curve.into()),
+        boundary: boundary.map(|coord| Some(Point::from([coord]))),
+        surface_vertices,
+        global_form,
+    })
+}
+
+fn surface_vertex(
+    position: [Scalar; 2],
+    global_form: Partial<GlobalVertex>,
+) -> Partial<SurfaceVertex> {
+    Partial::from_partial(PartialSurfaceVertex {
+        position: Some(Point::from(position)),
+        global_form,
+    })
+}
+
+fn global_edge(vertices: [&Partial<GlobalVertex>; 2]) -> Partial<GlobalEdge> {
+    Partial::from_partial(PartialGlobalEdge {
+        vertices: vertices.map(Clone::clone),
+    })
+}
+
+fn global_vertex(position: impl Into<Point<3>>) -> Partial<GlobalVertex> {
+    Partial::from_partial(PartialGlobalVertex {
+        position: Some(position.into()),
+    })
 }
 
-impl SolidBuilder for PartialSolid {}
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::mesh::Mesh;
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        insert::Insert,
+        partial::{PartialObject, PartialSolid},
+        services::Services,
+        test_helpers::assert_mesh_volume,
+    };
+
+    use super::SolidBuilder;
+
+    #[test]
+    fn cuboid() {
+        let mesh = triangulate(|solid| solid.update_as_cuboid([1., 2., 3.]));
+
+        assert_mesh_volume(&mesh, 6., 0.02);
+        assert_within_tolerance(&mesh, |point| {
+            let [x, y, z] = point.coords.components;
+            (x.abs() - 0.5).max(y.abs() - 1.).max(z.abs() - 1.5)
+        });
+    }
+
+    #[test]
+    fn cylinder() {
+        let mesh = triangulate(|solid| solid.update_as_cylinder(1., 2.));
+
+        assert_mesh_volume(&mesh, PI * 2., 0.02);
+        assert_within_tolerance(&mesh, |point| {
+            let [x, y, z] = point.coords.components;
+            let radial = (x * x + y * y).sqrt() - 1.;
+            let axial = (z - 1.).abs() - 1.;
+            radial.max(axial)
+        });
+    }
+
+    #[test]
+    fn cone() {
+        let mesh = triangulate(|solid| solid.update_as_cone(1., 2.));
+
+        assert_mesh_volume(&mesh, PI * 2. / 3., 0.02);
+        assert_within_tolerance(&mesh, |point| {
+            let [x, y, z] = point.coords.components;
+            let radial = ((x * x + y * y).sqrt() - (Scalar::ONE - z / 2.)) * 2.
+                / Scalar::from(5.).sqrt();
+            radial.max(-z)
+        });
+    }
+
+    #[test]
+    fn sphere() {
+        let mesh = triangulate(|solid| solid.update_as_sphere(1.));
+
+        assert_mesh_volume(&mesh, PI * 4. / 3., 0.02);
+        assert_within_tolerance(&mesh, |point| {
+            point.coords.magnitude() - Scalar::ONE
+        });
+    }
+
+    #[test]
+    fn torus() {
+        let mesh = triangulate(|solid| solid.update_as_torus(2., 1.));
+
+        assert_mesh_volume(&mesh, 2. * PI * PI * 2., 0.02);
+        assert_within_tolerance(&mesh, |point| {
+            let [x, y, z] = point.coords.components;
+            let radial = (x * x + y * y).sqrt() - 2.;
+            (radial * radial + z * z).sqrt() - Scalar::ONE
+        });
+    }
+
+    fn triangulate(update: impl FnOnce(&mut PartialSolid)) -> Mesh<Point<3>> {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        update(&mut solid);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        (&*solid, tolerance()).triangulate()
+    }
+
+    fn tolerance() -> Tolerance {
+        Tolerance::from_scalar(0.01).unwrap()
+    }
+
+    /// Check that the mesh deviates from the solid within the tolerance
+    ///
+    /// The solid is defined by a function that returns the signed distance of
+    /// a point to its boundary. The corners and centers of all triangles are
+    /// checked.
+    fn assert_within_tolerance(
+        mesh: &Mesh<Point<3>>,
+        distance: impl Fn(Point<3>) -> Scalar,
+    ) {
+        for triangle in mesh.triangles() {
+            let points = triangle.inner.points();
+            let [a, b, c] = points;
+            let center =
+                Point::origin() + (a.coords + b.coords + c.coords) / 3.;
+
+            for point in points.into_iter().chain([center]) {
+                let distance = distance(point).abs();
+                assert!(
+                    distance <= tolerance().inner() + 1e-9,
+                    "{point:?} deviates from the solid by {distance}"
+                );
+            }
+        }
+    }
+}
@@ -22,7 +22,7 @@ impl SurfaceBuilder for PartialSurface {
         let v = v.into();
 
         Self {
-            geometry: Some(SurfaceGeometry { u, v }),
+            geometry: Some(SurfaceGeometry::Swept { u, v }),
         }
     }
 
@@ -35,7 +35,7 @@ impl SurfaceBuilder for PartialSurface {
         let (u, u_coords) = GlobalPath::line_from_points([a, b]);
         let v = c - a;
 
-        let geometry = SurfaceGeometry { u, v };
+        let geometry = SurfaceGeometry::Swept { u, v };
         self.geometry = Some(geometry);
 
         let [a, b] = u_coords.map(|point| point.t);
@@ -2,7 +2,9 @@
 //!
 //! See [`Curve`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{
+    Circle, CubicBezier, Ellipse, Line, Point, Scalar, Transform, Vector,
+};
 
 /// A path through surface (2D) space
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -12,6 +14,12 @@ pub enum Curve {
 
     /// A line
     Line(Line<2>),
+
+    /// A cubic Bézier curve
+    Bezier(CubicBezier<2>),
+
+    /// An ellipse
+    Ellipse(Ellipse<2>),
 }
 
 impl Curve {
@@ -28,6 +36,16 @@ impl Curve {
         Self::Circle(Circle::from_center_and_radius(center, radius))
     }
 
+    /// Build an ellipse from the given center and radii
+    ///
+    /// The radii are along the u- and v-axis of the surface, respectively.
+    pub fn ellipse_from_center_and_radii(
+        center: impl Into<Point<2>>,
+        radii: [impl Into<Scalar>; 2],
+    ) -> Self {
+        Self::Ellipse(Ellipse::from_center_and_radii(center, radii))
+    }
+
     /// Build a line that represents the u-axis of the surface its on
     pub fn u_axis() -> Self {
         let a = Point::origin();
@@ -71,6 +89,8 @@ impl Curve {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
         }
     }
 }
@@ -83,6 +103,12 @@ pub enum GlobalPath {
 
     /// A line
     Line(Line<3>),
+
+    /// A cubic Bézier curve
+    Bezier(CubicBezier<3>),
+
+    /// An ellipse
+    Ellipse(Ellipse<3>),
 }
 
 impl GlobalPath {
@@ -132,6 +158,8 @@ impl GlobalPath {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
             Self::Line(line) => line.origin(),
+            Self::Bezier(bezier) => bezier.points()[0],
+            Self::Ellipse(ellipse) => ellipse.center() + ellipse.a(),
         }
     }
 
@@ -143,10 +171,16 @@ impl GlobalPath {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Bezier(bezier) => bezier.point_from_bezier_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
         }
     }
 
     /// Convert a vector on the path into global coordinates
+    ///
+    /// Like for circles and ellipses, the result for Bézier curves depends on
+    /// the position on the curve. It is the vector from the start of the curve
+    /// to the point at the given coordinate.
     pub fn vector_from_path_coords(
         &self,
         vector: impl Into<Vector<1>>,
@@ -154,17 +188,40 @@ impl GlobalPath {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
             Self::Line(line) => line.vector_from_line_coords(vector),
+            Self::Bezier(bezier) => {
+                bezier.point_from_bezier_coords([vector.into().t])
+                    - bezier.points()[0]
+            }
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
         }
     }
 
     /// Transform the path
+    ///
+    /// A circle that is scaled by different factors along different axes turns
+    /// into an ellipse.
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
         match self {
             Self::Circle(curve) => {
-                Self::Circle(transform.transform_circle(&curve))
+                if transform.is_conformal() {
+                    Self::Circle(transform.transform_circle(&curve))
+                } else {
+                    Self::Ellipse(
+                        transform
+                            .transform_ellipse(&Ellipse::from_circle(&curve)),
+                    )
+                }
             }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
+            Self::Bezier(curve) => {
+                Self::Bezier(transform.transform_bezier(&curve))
+            }
+            Self::Ellipse(curve) => {
+                Self::Ellipse(transform.transform_ellipse(&curve))
+            }
         }
     }
 }
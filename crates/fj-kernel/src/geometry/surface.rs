@@ -1,17 +1,51 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{
+    Circle, CubicBezier, Ellipse, Line, Plane, Point, Scalar, Transform, Vector,
+};
 
-use super::curve::GlobalPath;
+use super::curve::{Curve, GlobalPath};
 
 /// The geometry that defines a surface
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct SurfaceGeometry {
-    /// The u-axis of the surface
-    pub u: GlobalPath,
+pub enum SurfaceGeometry {
+    /// A surface that is created by sweeping a path along a vector
+    ///
+    /// Planes and cylinders are represented by this kind of surface.
+    Swept {
+        /// The u-axis of the surface
+        u: GlobalPath,
 
-    /// The v-axis of the surface
-    pub v: Vector<3>,
+        /// The v-axis of the surface
+        v: Vector<3>,
+    },
+
+    /// A ruled surface, created by connecting two paths with straight lines
+    ///
+    /// Both paths share the u-coordinate of the surface. The straight line that
+    /// connects the points on both paths at a given u-coordinate defines the
+    /// v-coordinate, which is `0` at the first path and `1` at the second.
+    Ruled {
+        /// The path at the v-coordinate `0`
+        a: GlobalPath,
+
+        /// The path at the v-coordinate `1`
+        b: GlobalPath,
+    },
+
+    /// A surface that is created by revolving a path around an axis
+    ///
+    /// Cones, spheres, and tori are represented by this kind of surface. The
+    /// u-coordinate is the angle of the revolution in radians, counter-clockwise
+    /// when looking against the direction of the axis. The v-coordinate is the
+    /// coordinate on the revolved path.
+    Revolved {
+        /// The path that is revolved around the axis
+        path: GlobalPath,
+
+        /// The axis of the revolution
+        axis: Line<3>,
+    },
 }
 
 impl SurfaceGeometry {
@@ -21,54 +55,174 @@ impl SurfaceGeometry {
         point: impl Into<Point<2>>,
     ) -> Point<3> {
         let point = point.into();
-        self.u.point_from_path_coords([point.u])
-            + self.path_to_line().vector_from_line_coords([point.v])
+
+        match self {
+            Self::Swept { u, v } => {
+                u.point_from_path_coords([point.u])
+                    + path_to_line(u, v).vector_from_line_coords([point.v])
+            }
+            Self::Ruled { a, b } => {
+                let a = a.point_from_path_coords([point.u]);
+                let b = b.point_from_path_coords([point.u]);
+                a + (b - a) * point.v
+            }
+            Self::Revolved { path, axis } => revolve_point(
+                path.point_from_path_coords([point.v]),
+                axis,
+                point.u,
+            ),
+        }
     }
 
     /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// Only swept surfaces are supported, as the conversion would depend on
+    /// the position on the surface for any other ones.
     pub fn vector_from_surface_coords(
         &self,
         vector: impl Into<Vector<2>>,
     ) -> Vector<3> {
+        let Self::Swept { u, v } = self else {
+            todo!("Converting vectors on a non-swept surface is not supported")
+        };
+
         let vector = vector.into();
-        self.u.vector_from_path_coords([vector.u])
-            + self.path_to_line().vector_from_line_coords([vector.v])
+        u.vector_from_path_coords([vector.u])
+            + path_to_line(u, v).vector_from_line_coords([vector.v])
     }
 
-    fn path_to_line(&self) -> Line<3> {
-        Line::from_origin_and_direction(self.u.origin(), self.v)
+    /// Convert a curve in surface coordinates to a path in model coordinates
+    ///
+    /// Only planes are supported, as a curve on any other surface can't
+    /// generally be represented as a path.
+    pub fn path_from_surface_curve(&self, curve: Curve) -> GlobalPath {
+        let Self::Swept {
+            u: GlobalPath::Line(_),
+            ..
+        } = self
+        else {
+            todo!("Converting curve on non-plane surface is not supported")
+        };
+
+        match curve {
+            Curve::Circle(circle) => {
+                let center = self.point_from_surface_coords(circle.center());
+                let [a, b] = [circle.a(), circle.b()]
+                    .map(|vector| self.vector_from_surface_coords(vector));
+
+                // If the plane's coordinate system is distorted, the circle
+                // turns into an ellipse. This depends on the plane, not the
+                // result, which could look distorted due to numerical
+                // inaccuracy.
+                let [u, v] = [[1., 0.], [0., 1.]]
+                    .map(|vector| self.vector_from_surface_coords(vector));
+                let epsilon = u.magnitude() * v.magnitude() * 1e-9;
+                let is_distorted = (u.dot(&u) - v.dot(&v)).abs() > epsilon
+                    || u.dot(&v).abs() > epsilon;
+
+                if is_distorted {
+                    GlobalPath::Ellipse(Ellipse::new(center, a, b))
+                } else {
+                    GlobalPath::Circle(Circle::new_corrected(center, a, b))
+                }
+            }
+            Curve::Line(line) => {
+                GlobalPath::Line(Line::from_origin_and_direction(
+                    self.point_from_surface_coords(line.origin()),
+                    self.vector_from_surface_coords(line.direction()),
+                ))
+            }
+            Curve::Bezier(bezier) => {
+                GlobalPath::Bezier(CubicBezier::from_points(
+                    bezier
+                        .points()
+                        .map(|point| self.point_from_surface_coords(point)),
+                ))
+            }
+            Curve::Ellipse(ellipse) => GlobalPath::Ellipse(Ellipse::new(
+                self.point_from_surface_coords(ellipse.center()),
+                self.vector_from_surface_coords(ellipse.a()),
+                self.vector_from_surface_coords(ellipse.b()),
+            )),
+        }
     }
 
     /// Project the global point into the surface
     pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
-        let GlobalPath::Line(line) = self.u else {
+        let Self::Swept {
+            u: GlobalPath::Line(line),
+            v,
+        } = self
+        else {
             todo!("Projecting point into non-plane surface is not supported")
         };
 
-        let plane =
-            Plane::from_parametric(line.origin(), line.direction(), self.v);
+        let plane = Plane::from_parametric(line.origin(), line.direction(), *v);
         plane.project_point(point)
     }
 
     /// Transform the surface geometry
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
-        let u = self.u.transform(transform);
-        let v = transform.transform_vector(&self.v);
-        Self { u, v }
+        match self {
+            Self::Swept { u, v } => {
+                let u = u.transform(transform);
+                let v = transform.transform_vector(&v);
+                Self::Swept { u, v }
+            }
+            Self::Ruled { a, b } => {
+                let a = a.transform(transform);
+                let b = b.transform(transform);
+                Self::Ruled { a, b }
+            }
+            Self::Revolved { path, axis } => {
+                let path = path.transform(transform);
+                let mut axis = transform.transform_line(&axis);
+
+                // Revolving is counter-clockwise around the axis. Reversing
+                // the orientation would turn that into clockwise, unless the
+                // axis is reversed too.
+                if transform.reverses_orientation() {
+                    axis = axis.reverse();
+                }
+
+                Self::Revolved { path, axis }
+            }
+        }
     }
 }
 
+fn path_to_line(u: &GlobalPath, v: &Vector<3>) -> Line<3> {
+    Line::from_origin_and_direction(u.origin(), *v)
+}
+
+fn revolve_point(
+    point: Point<3>,
+    axis: &Line<3>,
+    angle: impl Into<Scalar>,
+) -> Point<3> {
+    let direction = axis.direction().normalize();
+
+    let offset = point - axis.origin();
+    let along_axis = direction * direction.dot(&offset);
+    let radial = offset - along_axis;
+
+    let (sin, cos) = angle.into().sin_cos();
+    axis.origin() + along_axis + radial * cos + direction.cross(&radial) * sin
+}
+
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::PI;
+
+    use fj_math::{CubicBezier, Line, Point, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::geometry::{curve::GlobalPath, surface::SurfaceGeometry};
 
     #[test]
     fn point_from_surface_coords() {
-        let surface = SurfaceGeometry {
+        let surface = SurfaceGeometry::Swept {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 1., 1.]),
                 Vector::from([0., 2., 0.]),
@@ -84,7 +238,7 @@ mod tests {
 
     #[test]
     fn vector_from_surface_coords() {
-        let surface = SurfaceGeometry {
+        let surface = SurfaceGeometry::Swept {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 0., 0.]),
                 Vector::from([0., 2., 0.]),
@@ -97,4 +251,63 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn vector_from_bezier_surface_coords() {
+        let surface = SurfaceGeometry::Swept {
+            u: GlobalPath::Bezier(CubicBezier::from_points([
+                [1., 0., 0.],
+                [1., 1., 0.],
+                [2., 1., 0.],
+                [2., 2., 0.],
+            ])),
+            v: Vector::from([0., 0., 2.]),
+        };
+
+        // Vectors are relative to the start of the curve, just like points are
+        // relative to it.
+        for coords in [[0., 1.], [0.5, 2.], [1., 0.]] {
+            assert_eq!(
+                surface.vector_from_surface_coords(coords),
+                surface.point_from_surface_coords(coords)
+                    - Point::from([1., 0., 0.]),
+            );
+        }
+    }
+
+    #[test]
+    fn point_from_ruled_surface_coords() {
+        let surface = SurfaceGeometry::Ruled {
+            a: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([0., 0., 0.]),
+                Vector::from([1., 0., 0.]),
+            )),
+            b: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([0., 0., 2.]),
+                Vector::from([0., 1., 0.]),
+            )),
+        };
+
+        assert_eq!(
+            surface.point_from_surface_coords([2., 0.5]),
+            Point::from([1., 1., 1.]),
+        );
+    }
+
+    #[test]
+    fn point_from_revolved_surface_coords() {
+        let surface = SurfaceGeometry::Revolved {
+            path: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([1., 0., 0.]),
+                Vector::from([0., 0., 1.]),
+            )),
+            axis: Line::from_origin_and_direction(
+                Point::from([0., 0., 1.]),
+                Vector::from([0., 0., 2.]),
+            ),
+        };
+
+        let point = surface.point_from_surface_coords([PI / 2., 3.]);
+        assert!(point.distance_to(&Point::from([0., 1., 3.])) < 1e-12.into());
+    }
 }
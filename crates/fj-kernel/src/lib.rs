@@ -96,3 +96,6 @@ pub mod partial;
 pub mod services;
 pub mod storage;
 pub mod validate;
+
+#[cfg(test)]
+mod test_helpers;
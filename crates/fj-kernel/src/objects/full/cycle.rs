@@ -1,7 +1,7 @@
 use std::slice;
 
 use fj_interop::ext::SliceExt;
-use fj_math::{Scalar, Winding};
+use fj_math::{CubicBezier, Point, Scalar, Winding};
 
 use crate::{geometry::curve::Curve, objects::HalfEdge, storage::Handle};
 
@@ -43,44 +43,57 @@ impl Cycle {
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
     pub fn winding(&self) -> Winding {
-        // The cycle could be made up of one or two circles. If that is the
-        // case, the winding of the cycle is determined by the winding of the
-        // first circle.
-        if self.half_edges.len() < 3 {
-            let first = self
-                .half_edges()
-                .next()
-                .expect("Invalid cycle: expected at least one half-edge");
-
-            let [a, b] = first.boundary();
-            let edge_direction_positive = a < b;
-
-            let circle = match first.curve() {
-                Curve::Circle(circle) => circle,
-                Curve::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
-                ),
-            };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
-
-            if edge_direction_positive == cross_positive {
-                return Winding::Ccw;
-            } else {
-                return Winding::Cw;
-            }
-        }
-
-        // Now that we got the special case out of the way, we can treat the
-        // cycle as a polygon:
+        // The winding is determined by the sign of the area that the cycle
+        // encloses, which is computed by treating the cycle as a polygon:
         // https://stackoverflow.com/a/1165943
+        //
+        // Curves don't fit that model, so for each curve, the area between the
+        // curve and the straight line between its vertices is added. This also
+        // takes care of cycles made up of less than three half-edges, which
+        // are necessarily made up of curves.
 
         let mut sum = Scalar::ZERO;
 
         for [a, b] in self.half_edges.as_slice().array_windows_ext() {
-            let [a, b] =
-                [a, b].map(|half_edge| half_edge.start_vertex().position());
+            sum += polygon_term(a, b);
+        }
+        if let (Some(last), Some(first)) =
+            (self.half_edges.last(), self.half_edges.first())
+        {
+            sum += polygon_term(last, first);
+        }
 
-            sum += (b.u - a.u) * (b.v + a.v);
+        for half_edge in &self.half_edges {
+            let segment_area = match half_edge.curve() {
+                Curve::Circle(circle) => {
+                    let [a, b] = half_edge.boundary();
+                    let angle = b.t - a.t;
+                    let (sin, _) = angle.sin_cos();
+
+                    let direction = circle.a().cross2d(&circle.b()).sign();
+                    circle.radius() * circle.radius() / 2.
+                        * (angle - sin)
+                        * direction.to_scalar()
+                }
+                Curve::Bezier(bezier) => {
+                    bezier_segment_area(&bezier, half_edge.boundary())
+                }
+                Curve::Ellipse(ellipse) => {
+                    // An ellipse is an affine image of the unit circle. The
+                    // area scales with the determinant of that mapping, which
+                    // also carries the direction.
+                    let [a, b] = half_edge.boundary();
+                    let angle = b.t - a.t;
+                    let (sin, _) = angle.sin_cos();
+
+                    ellipse.a().cross2d(&ellipse.b()) / 2. * (angle - sin)
+                }
+                Curve::Line(_) => continue,
+            };
+
+            // The polygon sum is negative for a counter-clockwise polygon,
+            // and twice the area.
+            sum -= segment_area * 2.;
         }
 
         if sum > Scalar::ZERO {
@@ -98,3 +111,95 @@ impl Cycle {
 ///
 /// Returned by [`Cycle::half_edges`].
 pub type HalfEdgesOfCycle<'a> = slice::Iter<'a, Handle<HalfEdge>>;
+
+fn polygon_term(a: &Handle<HalfEdge>, b: &Handle<HalfEdge>) -> Scalar {
+    let [a, b] = [a, b].map(|half_edge| half_edge.start_vertex().position());
+    (b.u - a.u) * (b.v + a.v)
+}
+
+/// Compute the area between a Bézier curve and the line between its ends
+///
+/// The area is positive, if the curve and the line back to its start run
+/// counter-clockwise.
+fn bezier_segment_area(
+    bezier: &CubicBezier<2>,
+    [a, b]: [Point<1>; 2],
+) -> Scalar {
+    // The area is half the integral of `p x p'` along the closed path. For the
+    // curve, that integrand is a polynomial of degree 5, which Gauss-Legendre
+    // quadrature with three points integrates exactly.
+    let nodes = [
+        (-(0.6_f64.sqrt()), 5. / 9.),
+        (0., 8. / 9.),
+        (0.6_f64.sqrt(), 5. / 9.),
+    ];
+    let [middle, half] = [(a.t + b.t) / 2., (b.t - a.t) / 2.];
+
+    let curve = nodes.into_iter().fold(Scalar::ZERO, |sum, (x, weight)| {
+        let t = middle + half * x;
+        let point = bezier.point_from_bezier_coords([t]).coords;
+        sum + point.cross2d(&bezier.tangent_at([t])) * half * weight
+    });
+
+    let [start, end] =
+        [a, b].map(|t| bezier.point_from_bezier_coords(t).coords);
+    let line = end.cross2d(&start);
+
+    (curve + line) / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Winding;
+
+    use crate::{
+        builder::{CycleBuilder, HalfEdgeBuilder},
+        partial::{Partial, PartialFace, PartialObject},
+        services::Services,
+    };
+
+    #[test]
+    fn winding_of_cycle_with_arc() {
+        let mut services = Services::new();
+
+        // A half circle, closed by a straight line. The vertices alone don't
+        // define a winding, so the arc must be taken into account.
+        for (angle, expected) in [(PI, Winding::Ccw), (-PI, Winding::Cw)] {
+            let mut face = PartialFace {
+                surface: Partial::from(services.objects.surfaces.xy_plane()),
+                ..Default::default()
+            };
+            let [_, mut arc] = face
+                .exterior
+                .write()
+                .update_as_polygon_from_points([[0., -1.], [0., 1.]]);
+            arc.write().update_as_arc(angle);
+
+            let face = face.build(&mut services.objects);
+            assert_eq!(face.exterior().winding(), expected);
+        }
+    }
+
+    #[test]
+    fn winding_of_cycle_with_large_arc() {
+        let mut services = Services::new();
+
+        // The vertices form a clockwise triangle, but the arc between the last
+        // and the first vertex goes around all of them counter-clockwise. This
+        // makes the whole cycle counter-clockwise.
+        let mut face = PartialFace {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        let [_, _, mut arc] = face
+            .exterior
+            .write()
+            .update_as_polygon_from_points([[0., 0.], [1., 0.], [0.5, -0.1]]);
+        arc.write().update_as_arc(PI * 1.9);
+
+        let face = face.build(&mut services.objects);
+        assert_eq!(face.exterior().winding(), Winding::Ccw);
+    }
+}
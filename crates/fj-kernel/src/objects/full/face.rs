@@ -38,6 +38,7 @@ pub struct Face {
     exterior: Handle<Cycle>,
     interiors: Vec<Handle<Cycle>>,
     color: Color,
+    origin: FaceOrigin,
 }
 
 impl Face {
@@ -55,9 +56,16 @@ impl Face {
             exterior,
             interiors,
             color,
+            origin: FaceOrigin::default(),
         }
     }
 
+    /// Record which operation created the face, and in which role
+    pub fn with_origin(mut self, origin: FaceOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
     /// Access the surface of the face
     pub fn surface(&self) -> &Handle<Surface> {
         &self.surface
@@ -85,6 +93,14 @@ impl Face {
         self.color
     }
 
+    /// Access the origin of the face
+    ///
+    /// Faces that are derived from another face, for example by transforming
+    /// it, keep the origin of that face.
+    pub fn origin(&self) -> FaceOrigin {
+        self.origin
+    }
+
     /// Determine handed-ness of the face's front-side coordinate system
     ///
     /// A face is defined on a surface, which has a coordinate system. Since
@@ -103,6 +119,29 @@ impl Face {
     }
 }
 
+/// The operation that created a face, and the face's role in it
+///
+/// Unlike a face's geometry, its origin doesn't change with the parameters of
+/// a model. This makes it possible to refer to a face in a way that survives
+/// such changes, like "the top face of the sweep".
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FaceOrigin {
+    /// The origin of the face is not known
+    #[default]
+    Unknown,
+
+    /// The face is where a sweep started
+    ///
+    /// This is the swept face, with its orientation reversed, if necessary.
+    SweepBottom,
+
+    /// The face is where a sweep ended
+    SweepTop,
+
+    /// The face was created by sweeping an edge of the swept face
+    SweepSide,
+}
+
 /// A collection of faces
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct FaceSet {
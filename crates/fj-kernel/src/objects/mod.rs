@@ -81,7 +81,7 @@ pub use self::{
     full::{
         cycle::{Cycle, HalfEdgesOfCycle},
         edge::{GlobalEdge, HalfEdge, VerticesInNormalizedOrder},
-        face::{Face, FaceSet, Handedness},
+        face::{Face, FaceOrigin, FaceSet, Handedness},
         shell::Shell,
         sketch::Sketch,
         solid::Solid,
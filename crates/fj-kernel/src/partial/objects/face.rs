@@ -2,7 +2,7 @@ use fj_interop::mesh::Color;
 
 use crate::{
     builder::CycleBuilder,
-    objects::{Cycle, Face, Objects, Surface},
+    objects::{Cycle, Face, FaceOrigin, Objects, Surface},
     partial::{FullToPartialCache, Partial, PartialObject},
     services::Service,
 };
@@ -23,6 +23,9 @@ pub struct PartialFace {
 
     /// The color of the face
     pub color: Option<Color>,
+
+    /// The origin of the face
+    pub origin: FaceOrigin,
 }
 
 impl PartialObject for PartialFace {
@@ -37,6 +40,7 @@ impl PartialObject for PartialFace {
                 .map(|cycle| Partial::from_full(cycle.clone(), cache))
                 .collect(),
             color: Some(face.color()),
+            origin: face.origin(),
         }
     }
 
@@ -57,6 +61,6 @@ impl PartialObject for PartialFace {
             self.interiors.into_iter().map(|cycle| cycle.build(objects));
         let color = self.color.unwrap_or_default();
 
-        Face::new(surface, exterior, interiors, color)
+        Face::new(surface, exterior, interiors, color).with_origin(self.origin)
     }
 }
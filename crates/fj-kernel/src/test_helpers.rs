@@ -0,0 +1,102 @@
+//! Helpers that are shared between tests
+
+use fj_interop::mesh::Mesh;
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::{approx::Tolerance, triangulate::Triangulate},
+    builder::{CycleBuilder, HalfEdgeBuilder, ObjectArgument},
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    partial::{Partial, PartialCycle, PartialFace, PartialObject},
+    services::Service,
+    storage::Handle,
+};
+
+/// Create a sketch of a polygon in the xy-plane
+pub fn polygon(
+    points: impl ObjectArgument<[f64; 2]>,
+    objects: &mut Service<Objects>,
+) -> Handle<Sketch> {
+    let mut face = PartialFace {
+        surface: Partial::from(objects.surfaces.xy_plane()),
+        ..Default::default()
+    };
+    face.exterior.write().update_as_polygon_from_points(points);
+    let face = face.build(objects).insert(objects);
+
+    Sketch::new([face]).insert(objects)
+}
+
+/// Create a sketch of a square in the xy-plane, centered on the origin
+pub fn square(size: f64, objects: &mut Service<Objects>) -> Handle<Sketch> {
+    let half = size / 2.;
+    polygon(
+        [[-half, -half], [half, -half], [half, half], [-half, half]],
+        objects,
+    )
+}
+
+/// Create a sketch of a circle in the xy-plane, centered on the origin
+pub fn circle(radius: f64, objects: &mut Service<Objects>) -> Handle<Sketch> {
+    let mut cycle = PartialCycle::default();
+    let mut half_edge = cycle.add_half_edge();
+    half_edge.write().update_as_circle_from_radius(radius);
+
+    let face = PartialFace {
+        surface: Partial::from(objects.surfaces.xy_plane()),
+        exterior: Partial::from_partial(cycle),
+        ..Default::default()
+    };
+    let face = face.build(objects).insert(objects);
+
+    Sketch::new([face]).insert(objects)
+}
+
+/// Compute the volume of a solid's triangle mesh
+pub fn volume(
+    solid: &Handle<Solid>,
+    tolerance: impl Into<Tolerance>,
+) -> Scalar {
+    mesh_volume(&(&**solid, tolerance.into()).triangulate())
+}
+
+/// Compute the volume enclosed by a triangle mesh
+///
+/// Each triangle spans a tetrahedron with the origin, whose signed volume
+/// depends on which side of the triangle the origin is on.
+pub fn mesh_volume(mesh: &Mesh<Point<3>>) -> Scalar {
+    mesh.triangles()
+        .map(|triangle| {
+            let [a, b, c] =
+                triangle.inner.points().map(|point| point - Point::origin());
+            a.dot(&b.cross(&c))
+        })
+        .fold(Scalar::ZERO, |sum, volume| sum + volume)
+        / 6.
+}
+
+/// Check the volume of a solid, triangulated with a tolerance of `0.001`
+///
+/// See [`assert_mesh_volume`].
+pub fn assert_volume(solid: &Handle<Solid>, expected: f64, margin: f64) {
+    let tolerance = Tolerance::from_scalar(0.001).unwrap();
+    assert_mesh_volume(&(&**solid, tolerance).triangulate(), expected, margin);
+}
+
+/// Check the volume enclosed by a triangle mesh
+///
+/// The approximation of curved faces lies within the solid, which makes the
+/// volume slightly smaller than the exact one. The margin is the fraction of
+/// the expected volume that it may be smaller by.
+pub fn assert_mesh_volume(mesh: &Mesh<Point<3>>, expected: f64, margin: f64) {
+    let volume = mesh_volume(mesh);
+
+    let expected = Scalar::from(expected);
+    let epsilon = Scalar::from(1e-9);
+    assert!(volume <= expected + epsilon, "{volume} > {expected}");
+    assert!(
+        volume >= expected * (1. - margin) - epsilon,
+        "{volume} < {expected}"
+    );
+}
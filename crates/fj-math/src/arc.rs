@@ -66,13 +66,13 @@ impl Arc {
         };
         let end_angle = {
             let from_center = p1 - center;
-            let offset = if more_than_half_turn {
-                Scalar::TAU
-            } else {
-                Scalar::ZERO
-            };
+            let end_angle = from_center.v.atan2(from_center.u);
 
-            from_center.v.atan2(from_center.u) + offset
+            // `atan2` returns angles between -pi and pi. Make sure the arc
+            // covers the requested angle, even if it crosses that boundary.
+            let turns =
+                ((start_angle + angle_rad - end_angle) / Scalar::TAU).round();
+            end_angle + Scalar::TAU * turns
         };
         Self {
             center,
@@ -123,6 +123,18 @@ mod tests {
             0_f64.to_radians(),
             270_f64.to_radians(),
         );
+        check_arc_calculation(
+            [2., 1.],
+            1.,
+            135_f64.to_radians(),
+            225_f64.to_radians(),
+        );
+        check_arc_calculation(
+            [0., 0.],
+            2.,
+            -90_f64.to_radians(),
+            -270_f64.to_radians(),
+        );
     }
 
     fn check_arc_calculation(
@@ -0,0 +1,266 @@
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional cubic Bézier curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter.
+///
+/// The curve starts at the first and ends at the last of its control points.
+/// Its coordinate is `0` at the start and `1` at the end. Outside of that
+/// range, the polynomial that defines the curve is extrapolated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CubicBezier<const D: usize> {
+    points: [Point<D>; 4],
+}
+
+impl<const D: usize> CubicBezier<D> {
+    /// Construct a cubic Bézier curve from its control points
+    pub fn from_points(points: [impl Into<Point<D>>; 4]) -> Self {
+        let points = points.map(Into::into);
+        Self { points }
+    }
+
+    /// Construct a cubic Bézier curve from the control points of a quadratic
+    ///
+    /// The resulting curve is identical to the quadratic one.
+    pub fn from_quadratic_points(points: [impl Into<Point<D>>; 3]) -> Self {
+        let [a, b, c] = points.map(Into::into);
+
+        // Elevate the degree of the curve. This moves the inner control points
+        // two thirds of the way towards the quadratic curve's control point.
+        Self::from_points([
+            a,
+            a + (b - a) * (2. / 3.),
+            c + (b - c) * (2. / 3.),
+            c,
+        ])
+    }
+
+    /// Convert a B-spline into a chain of cubic Bézier curves
+    ///
+    /// The B-spline is clamped to its ends, which are its first and last
+    /// control points, and its knots are spaced uniformly. Its degree is three,
+    /// or lower, if there are not enough control points for that.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two control points are provided.
+    pub fn chain_from_b_spline(points: &[Point<D>]) -> Vec<Self> {
+        assert!(
+            points.len() >= 2,
+            "B-spline must have at least two control points"
+        );
+
+        let n = points.len() - 1;
+        let degree = n.min(3);
+        let num_segments = n - degree + 1;
+
+        let knots = (0..n + degree + 2)
+            .map(|i| {
+                let knot = i.saturating_sub(degree).min(num_segments);
+                Scalar::from(knot as f64)
+            })
+            .collect::<Vec<_>>();
+
+        // Evaluate the polar form of the spline within the span that starts at
+        // the knot with index `k`, using de Boor's algorithm with a different
+        // parameter on each level.
+        let blossom = |k: usize, params: &[Scalar]| {
+            let mut d = points[k - degree..=k]
+                .iter()
+                .map(|point| point.coords)
+                .collect::<Vec<_>>();
+
+            for (r, &t) in (1..=degree).zip(params) {
+                for j in (r..=degree).rev() {
+                    let start = knots[j + k - degree];
+                    let end = knots[j + 1 + k - r];
+                    let alpha = (t - start) / (end - start);
+                    d[j] = d[j - 1] * (Scalar::ONE - alpha) + d[j] * alpha;
+                }
+            }
+
+            Point { coords: d[degree] }
+        };
+
+        (0..num_segments)
+            .map(|segment| {
+                let k = degree + segment;
+                let [start, end] = [knots[k], knots[k + 1]];
+
+                // The control points of the Bézier curve within the span are
+                // the values of the polar form, with each parameter at either
+                // end of the span.
+                let points = (0..=degree)
+                    .map(|j| {
+                        let mut params = vec![start; degree - j];
+                        params.extend(vec![end; j]);
+                        blossom(k, &params)
+                    })
+                    .collect::<Vec<_>>();
+
+                match *points.as_slice() {
+                    [a, b] => Self::from_points([
+                        a,
+                        a + (b - a) / 3.,
+                        a + (b - a) * (2. / 3.),
+                        b,
+                    ]),
+                    [a, b, c] => Self::from_quadratic_points([a, b, c]),
+                    [a, b, c, d] => Self::from_points([a, b, c, d]),
+                    _ => unreachable!("Degree is between one and three"),
+                }
+            })
+            .collect()
+    }
+
+    /// Access the control points of the curve
+    pub fn points(&self) -> [Point<D>; 4] {
+        self.points
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve is identical to the original one, but its coordinate
+    /// runs the other way.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.points.reverse();
+        self
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_bezier_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [a, b, c, d] = self.points.map(|point| point.coords);
+        let coords = a * (s * s * s)
+            + b * (s * s * t * 3.)
+            + c * (s * t * t * 3.)
+            + d * (t * t * t);
+
+        Point { coords }
+    }
+
+    /// Compute the derivative of the curve at a point in curve coordinates
+    ///
+    /// The result is the tangent of the curve at that point. Its length is the
+    /// speed at which the curve is traversed there.
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let t = point.into().t;
+        let s = Scalar::ONE - t;
+
+        let [a, b, c, d] = self.points;
+        (b - a) * (s * s * 3.) + (c - b) * (s * t * 6.) + (d - c) * (t * t * 3.)
+    }
+
+    /// Compute the maximum magnitude of the curve's second derivative
+    ///
+    /// The second derivative of a cubic Bézier curve changes linearly along
+    /// the curve, so its maximum within the curve is found at one of its ends.
+    pub fn max_second_derivative(&self) -> Scalar {
+        let [a, b, c, d] = self.points.map(|point| point.coords);
+
+        let start = (a - b * 2. + c) * 6.;
+        let end = (b - c * 2. + d) * 6.;
+
+        start.magnitude().max(end.magnitude())
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for CubicBezier<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.points
+            .iter()
+            .zip(other.points.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::CubicBezier;
+
+    #[test]
+    fn point_from_bezier_coords() {
+        let bezier =
+            CubicBezier::from_quadratic_points([[0., 0.], [1., 2.], [2., 0.]]);
+
+        assert_eq!(
+            bezier.point_from_bezier_coords([0.]),
+            Point::from([0., 0.])
+        );
+        assert_eq!(
+            bezier.point_from_bezier_coords([1.]),
+            Point::from([2., 0.])
+        );
+        assert_abs_diff_eq!(
+            bezier.point_from_bezier_coords([0.5]),
+            Point::from([1., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            bezier.tangent_at([0.]),
+            Vector::from([2., 4.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            bezier.reverse().point_from_bezier_coords([0.25]),
+            bezier.point_from_bezier_coords([0.75]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn chain_from_b_spline() {
+        // With four control points, the spline is a single Bézier curve.
+        let points = [[0., 0.], [1., 2.], [3., 2.], [4., 0.]].map(Point::from);
+        assert_eq!(
+            CubicBezier::chain_from_b_spline(&points),
+            vec![CubicBezier::from_points(points)],
+        );
+
+        // With more, it is split into pieces that connect smoothly.
+        let points =
+            [[0., 0.], [1., 2.], [3., 2.], [4., 0.], [6., 0.]].map(Point::from);
+        let chain = CubicBezier::chain_from_b_spline(&points);
+
+        assert_eq!(chain.len(), 2);
+        let [a, b] = [chain[0], chain[1]];
+
+        assert_eq!(a.points()[0], points[0]);
+        assert_eq!(b.points()[3], points[4]);
+        assert_abs_diff_eq!(
+            a.points()[3],
+            b.points()[0],
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            a.tangent_at([1.]),
+            b.tangent_at([0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        // The middle knot of this spline is `1`, at which the uniform cubic
+        // B-spline has a known value.
+        assert_abs_diff_eq!(
+            a.points()[3],
+            Point::from([2.75, 1.5]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
@@ -33,27 +33,58 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
-            "`a` and `b` must be of equal length"
-        );
         assert_ne!(
             a.magnitude(),
             Scalar::ZERO,
             "circle radius must not be zero"
         );
-        // Requiring the vector to be *precisely* perpendicular is not
-        // practical, because of numerical inaccuracy. This epsilon value seems
-        // seems to work for now, but maybe it needs to become configurable.
+        // Requiring the vectors to be *precisely* of equal length and
+        // perpendicular is not practical, because of numerical inaccuracy. For
+        // example, rotating a circle will result in slight differences. These
+        // epsilon values seem to work for now, but maybe they need to become
+        // configurable.
+        assert!(
+            (a.magnitude() - b.magnitude()).abs()
+                <= Scalar::default_epsilon() * a.magnitude() * 4.,
+            "`a` and `b` must be of equal length"
+        );
         assert!(
-            a.dot(&b) < Scalar::default_epsilon(),
+            a.dot(&b).abs()
+                <= Scalar::default_epsilon()
+                    * a.magnitude()
+                    * b.magnitude()
+                    * 4.,
             "`a` and `b` must be perpendicular to each other"
         );
 
         Self { center, a, b }
     }
 
+    /// Construct a circle from vectors that are only approximately valid
+    ///
+    /// Corrects `b` to be perpendicular to `a` and of equal length, keeping
+    /// it in the plane and on the side of `a` it was on. This is useful for
+    /// circles that result from computations, like angle-preserving
+    /// transforms, whose numerical inaccuracy can exceed what [`Circle::new`]
+    /// accepts.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` is zero, or if `b` is parallel to `a`.
+    pub fn new_corrected(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let a = a.into();
+        let b = b.into();
+
+        let b = b - a * (a.dot(&b) / a.dot(&a));
+        let b = b.normalize() * a.magnitude();
+
+        Self::new(center, a, b)
+    }
+
     /// Construct a `Circle` from a center point and a radius
     pub fn from_center_and_radius(
         center: impl Into<Point<D>>,
@@ -171,10 +202,55 @@ impl<const D: usize> approx::AbsDiffEq for Circle<D> {
 mod tests {
     use std::f64::consts::{FRAC_PI_2, PI};
 
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Circle;
 
+    #[test]
+    fn new_accepts_numerical_inaccuracy() {
+        let epsilon = f64::EPSILON;
+
+        // Rotating a circle can result in differences like these.
+        Circle::new([0., 0.], [2., 0.], [0., 2. * (1. + epsilon)]);
+        Circle::new([0., 0.], [2., 0.], [2. * epsilon, 2.]);
+        Circle::new([0., 0.], [2., 0.], [-2. * epsilon, 2.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`a` and `b` must be of equal length")]
+    fn new_rejects_vectors_of_different_length() {
+        Circle::new([0., 0.], [1., 0.], [0., 1.001]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`a` and `b` must be perpendicular")]
+    fn new_rejects_vectors_that_are_not_perpendicular() {
+        // A negative dot product is no more perpendicular than a positive one.
+        Circle::new([0., 0.], [1., 0.], [-0.6, 0.8]);
+    }
+
+    #[test]
+    fn new_with_scaled_vectors() {
+        // The epsilon is relative to the radius, so large circles don't need
+        // to be more precise than small ones.
+        let radius = 1e6;
+        let circle = Circle::new(
+            [0., 0.],
+            [radius, 0.],
+            [radius * f64::EPSILON, radius],
+        );
+        assert_eq!(circle.radius(), Scalar::from(radius));
+    }
+
+    #[test]
+    fn new_corrected() {
+        let circle = Circle::new_corrected([0., 0.], [2., 0.], [0.001, 1.999]);
+        assert_eq!(circle.b(), Vector::from([0., 2.]));
+
+        let circle = Circle::new_corrected([0., 0.], [2., 0.], [0.001, -1.999]);
+        assert_eq!(circle.b(), Vector::from([0., -2.]));
+    }
+
     #[test]
     fn point_to_circle_coords() {
         let circle = Circle {
@@ -0,0 +1,277 @@
+use approx::AbsDiffEq;
+
+use crate::{Circle, Point, Scalar, Vector};
+
+/// An n-dimensional ellipse
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+///
+/// The ellipse is defined by its center and two vectors, `a` and `b`. A point
+/// on the ellipse is `center + a * cos(t) + b * sin(t)`, where `t` is its
+/// coordinate. `a` and `b` don't need to be perpendicular. This means that any
+/// affine transformation of an ellipse (or a circle) is again an ellipse.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` and `b` are parallel, or if either of them is zero.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        // This is the squared area of the parallelogram spanned by `a` and
+        // `b`. It is zero, if they are parallel or if either of them is zero.
+        let gram_determinant = a.dot(&a) * b.dot(&b) - a.dot(&b) * a.dot(&b);
+        assert!(
+            gram_determinant
+                > Scalar::default_epsilon() * a.dot(&a) * b.dot(&b) * 4.,
+            "`a` and `b` must not be parallel or zero"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Construct an `Ellipse` from a center point and its two radii
+    ///
+    /// The first radius is along the first axis of the coordinate system, the
+    /// second radius along the second axis.
+    pub fn from_center_and_radii(
+        center: impl Into<Point<D>>,
+        radii: [impl Into<Scalar>; 2],
+    ) -> Self {
+        let [radius_a, radius_b] = radii.map(Into::into);
+
+        let mut a = [Scalar::ZERO; D];
+        let mut b = [Scalar::ZERO; D];
+
+        a[0] = radius_a;
+        b[1] = radius_b;
+
+        Self::new(center, a, b)
+    }
+
+    /// Construct an `Ellipse` that is identical to a circle
+    ///
+    /// The ellipse has the same coordinate system as the circle.
+    pub fn from_circle(circle: &Circle<D>) -> Self {
+        Self::new(circle.center(), circle.a(), circle.b())
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the starting point of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the zero
+    /// coordinate of the ellipse's coordinate system.
+    ///
+    /// Please also refer to [`Self::b`].
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the plane of the ellipse
+    ///
+    /// Also defines the direction of the ellipse's coordinate system. The point
+    /// where this vector points from the ellipse center, is at the coordinate
+    /// `PI / 2.`.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Access the radii of the ellipse
+    ///
+    /// Returns the lengths of the semi-major and semi-minor axis, in that
+    /// order.
+    pub fn radii(&self) -> [Scalar; 2] {
+        // The radii are the singular values of the matrix that has `a` and `b`
+        // as its columns. They are the square roots of the eigenvalues of the
+        // symmetric 2x2 matrix below.
+        let aa = self.a.dot(&self.a);
+        let bb = self.b.dot(&self.b);
+        let ab = self.a.dot(&self.b);
+
+        let mean = (aa + bb) / 2.;
+        let half_difference = (aa - bb) / 2.;
+        let deviation = (half_difference * half_difference + ab * ab).sqrt();
+
+        let major = mean + deviation;
+        let minor = Scalar::max(mean - deviation, Scalar::ZERO);
+
+        [major.sqrt(), minor.sqrt()]
+    }
+
+    /// Convert the ellipse into a circle, if it is one
+    ///
+    /// Returns `None`, if `a` and `b` aren't of equal length and perpendicular
+    /// to each other, within the same tolerance that [`Circle::new`] uses.
+    pub fn to_circle(&self) -> Option<Circle<D>> {
+        let a = self.a.magnitude();
+        let b = self.b.magnitude();
+
+        let is_circle = (a - b).abs() <= Scalar::default_epsilon() * a * 4.
+            && self.a.dot(&self.b).abs()
+                <= Scalar::default_epsilon() * a * b * 4.;
+
+        is_circle.then(|| Circle::new(self.center, self.a, self.b))
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a `D`-dimensional point to ellipse coordinates
+    ///
+    /// Converts the provided point into ellipse coordinates between `0.`
+    /// (inclusive) and `PI * 2.` (exclusive).
+    ///
+    /// Projects the point onto the plane of the ellipse and then radially onto
+    /// the ellipse, before computing the ellipse coordinate. As with
+    /// [`Circle::point_to_circle_coords`], a point that is not on the ellipse
+    /// will not result in an error.
+    pub fn point_to_ellipse_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let vector = point.into() - self.center;
+
+        // Solve `a * cos + b * sin = vector` in the least-squares sense.
+        let aa = self.a.dot(&self.a);
+        let bb = self.b.dot(&self.b);
+        let ab = self.a.dot(&self.b);
+        let av = self.a.dot(&vector);
+        let bv = self.b.dot(&vector);
+
+        let determinant = aa * bb - ab * ab;
+        let cos = (bb * av - ab * bv) / determinant;
+        let sin = (aa * bv - ab * av) / determinant;
+
+        let atan = Scalar::atan2(sin, cos);
+        let coord = if atan >= Scalar::ZERO {
+            atan
+        } else {
+            atan + Scalar::TAU
+        };
+        Point::from([coord])
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional point
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin
+    }
+}
+
+impl<const D: usize> approx::AbsDiffEq for Ellipse<D> {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.a.abs_diff_eq(&other.a, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar};
+
+    use super::Ellipse;
+
+    #[test]
+    fn point_to_ellipse_coords() {
+        let ellipse = Ellipse::new([1., 2., 3.], [2., 0., 0.], [1., 1., 0.]);
+
+        for coord in [0., FRAC_PI_2, PI, FRAC_PI_2 * 3., 1.] {
+            let point = ellipse.point_from_ellipse_coords([coord]);
+
+            assert_abs_diff_eq!(
+                ellipse.point_to_ellipse_coords(point),
+                Point::from([coord]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+
+        // Points off the ellipse are projected onto it.
+        assert_abs_diff_eq!(
+            ellipse.point_to_ellipse_coords([5., 2., 4.]),
+            Point::from([0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn to_circle() {
+        let ellipse = Ellipse::new([1., 2.], [0., 2.], [-2., 0.]);
+        assert_eq!(
+            ellipse.to_circle(),
+            Some(Circle::new([1., 2.], [0., 2.], [-2., 0.])),
+        );
+
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [1., 2.]);
+        assert_eq!(ellipse.to_circle(), None);
+
+        let ellipse = Ellipse::new([0., 0.], [1., 0.], [1., 1.]);
+        assert_eq!(ellipse.to_circle(), None);
+    }
+
+    #[test]
+    fn radii() {
+        let ellipse = Ellipse::from_center_and_radii([0., 0.], [1., 3.]);
+        assert_eq!(ellipse.radii(), [3., 1.].map(Scalar::from));
+
+        // A sheared circle with the same radii
+        let ellipse = Ellipse::new([0., 0.], [1., 0.], [1., 1.]);
+        let [major, minor] = ellipse.radii();
+        assert_abs_diff_eq!(
+            major * minor,
+            Scalar::ONE,
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            major * major + minor * minor,
+            Scalar::from(3.),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
@@ -39,8 +39,10 @@ pub mod robust;
 
 mod aabb;
 mod arc;
+mod bezier;
 mod circle;
 mod coordinates;
+mod ellipse;
 mod line;
 mod plane;
 mod point;
@@ -54,8 +56,10 @@ mod vector;
 pub use self::{
     aabb::Aabb,
     arc::Arc,
+    bezier::CubicBezier,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
+    ellipse::Ellipse,
     line::Line,
     plane::Plane,
     point::Point,
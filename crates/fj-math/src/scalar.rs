@@ -108,6 +108,11 @@ impl Scalar {
         self.0.abs().into()
     }
 
+    /// Compute the square root of the scalar
+    pub fn sqrt(self) -> Self {
+        self.0.sqrt().into()
+    }
+
     /// Compute the maximum of this and another scalar
     pub fn max(self, other: impl Into<Self>) -> Self {
         self.0.max(other.into().0).into()
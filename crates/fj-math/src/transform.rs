@@ -2,7 +2,7 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{Circle, CubicBezier, Ellipse, Line, Scalar};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -47,6 +47,64 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling with a separate factor along each axis
+    pub fn scale_non_uniform(scaling_factors: impl Into<Vector<3>>) -> Self {
+        let scaling_factors = scaling_factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::OMatrix::new_nonuniform_scaling(&scaling_factors.to_na()),
+        ))
+    }
+
+    /// Construct a reflection
+    ///
+    /// Reflects across the plane through the origin that is perpendicular to
+    /// `normal`. The length of `normal` is ignored.
+    pub fn reflection(normal: impl Into<Vector<3>>) -> Self {
+        let normal = normal.into().normalize().to_na();
+
+        let linear =
+            nalgebra::Matrix3::identity() - normal * normal.transpose() * 2.;
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            linear.to_homogeneous(),
+        ))
+    }
+
+    /// Indicate whether the transform reverses orientation
+    ///
+    /// This is the case for reflections, which turn a right-handed coordinate
+    /// system into a left-handed one, and vice versa.
+    pub fn reverses_orientation(&self) -> bool {
+        self.0.matrix().fixed_view::<3, 3>(0, 0).determinant() < 0.
+    }
+
+    /// Indicate whether the transform preserves angles
+    ///
+    /// This is the case for any combination of translations, rotations,
+    /// reflections, and uniform scaling. Circles stay circles under such a
+    /// transform, while any other transform can turn them into ellipses.
+    ///
+    /// Numerical inaccuracy, as it results from combining transforms, is
+    /// tolerated.
+    pub fn is_conformal(&self) -> bool {
+        let linear = self.0.matrix().fixed_view::<3, 3>(0, 0);
+
+        // The columns of a conformal transform's linear part are perpendicular
+        // to each other, and of equal length.
+        let gram = linear.transpose() * linear;
+        let scale = gram.trace() / 3.;
+
+        let epsilon = scale * 1e-9;
+        scale > 0.
+            && (0..3).all(|i| {
+                (0..3).all(|j| {
+                    let expected = if i == j { scale } else { 0. };
+                    (gram[(i, j)] - expected).abs() <= epsilon
+                })
+            })
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -87,14 +145,33 @@ impl Transform {
     }
 
     /// Transform the given circle
+    ///
+    /// The transform must be conformal (see [`Transform::is_conformal`]), or
+    /// the result will not match the transformed circle.
     pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
-        Circle::new(
+        Circle::new_corrected(
             self.transform_point(&circle.center()),
             self.transform_vector(&circle.a()),
             self.transform_vector(&circle.b()),
         )
     }
 
+    /// Transform the given ellipse
+    pub fn transform_ellipse(&self, ellipse: &Ellipse<3>) -> Ellipse<3> {
+        Ellipse::new(
+            self.transform_point(&ellipse.center()),
+            self.transform_vector(&ellipse.a()),
+            self.transform_vector(&ellipse.b()),
+        )
+    }
+
+    /// Transform the given Bézier curve
+    pub fn transform_bezier(&self, bezier: &CubicBezier<3>) -> CubicBezier<3> {
+        CubicBezier::from_points(
+            bezier.points().map(|point| self.transform_point(&point)),
+        )
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -132,11 +209,13 @@ impl Transform {
     }
 
     /// Transform the given axis-aligned bounding box
+    ///
+    /// The result contains all vertices of the transformed bounding box. If the
+    /// transform includes a rotation, it is larger than the transformed box.
     pub fn transform_aabb(&self, aabb: &Aabb<3>) -> Aabb<3> {
-        Aabb {
-            min: self.transform_point(&aabb.min),
-            max: self.transform_point(&aabb.max),
-        }
+        Aabb::<3>::from_points(
+            aabb.vertices().map(|vertex| self.transform_point(&vertex)),
+        )
     }
 
     /// Exposes the data of this Transform as a slice of f64.
@@ -169,7 +248,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Circle, Line, Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -194,6 +273,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reflection() {
+        let transform = Transform::reflection([2., 0., 0.]);
+
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([1., 2., 3.])),
+            Point::from([-1., 2., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+
+        assert!(transform.reverses_orientation());
+        assert!(!Transform::rotation(Vector::unit_z() * Scalar::PI)
+            .reverses_orientation());
+    }
+
+    #[test]
+    fn is_conformal() {
+        let rotation = Transform::rotation(Vector::from([1., 2., 3.]));
+        let conformal = [
+            Transform::identity(),
+            Transform::translation([1., 2., 3.]),
+            Transform::reflection([1., 1., 0.]),
+            Transform::scale(3.),
+            Transform::scale_non_uniform([2., 2., 2.]),
+            rotation * rotation * rotation * Transform::scale(0.001),
+        ];
+        for transform in conformal {
+            assert!(transform.is_conformal());
+        }
+
+        let not_conformal = [
+            Transform::scale(0.),
+            Transform::scale_non_uniform([1., 1., 1.001]),
+            rotation * Transform::scale_non_uniform([2., 1., 1.]),
+        ];
+        for transform in not_conformal {
+            assert!(!transform.is_conformal());
+        }
+    }
+
+    #[test]
+    fn transform_circle_corrects_numerical_inaccuracy() {
+        let circle = Circle::from_center_and_radius([0., 0., 0.], 1.);
+
+        // Combining many transforms accumulates numerical inaccuracy.
+        let rotation = Transform::rotation(Vector::from([0.1, 0.2, 0.3]));
+        let transform = (0..1000)
+            .fold(Transform::scale(2.), |transform, _| rotation * transform);
+        assert!(transform.is_conformal());
+
+        let circle = transform.transform_circle(&circle);
+        assert_abs_diff_eq!(
+            circle.radius(),
+            Scalar::from(2.),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
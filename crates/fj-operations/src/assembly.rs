@@ -0,0 +1,132 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, transform::TransformObject},
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Transform};
+
+use super::{mirror, pattern, shape_processor::Error, transform, Shape};
+
+impl Shape for fj::Assembly {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let mut faces = FaceSet::new();
+
+        for part in self.parts() {
+            faces.extend(
+                part.shape().compute_brep(objects, tolerance, debug_info)?,
+            );
+        }
+
+        Ok(faces)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.parts()
+            .iter()
+            .map(|part| part.shape().bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(Aabb {
+                min: Point::origin(),
+                max: Point::origin(),
+            })
+    }
+}
+
+/// Compute the boundary representations of the parts of an assembly
+///
+/// The parts of an assembly stay separate, if the assembly is transformed,
+/// mirrored, or repeated in a pattern. The parts of each instance of a pattern
+/// are numbered, starting at 1.
+///
+/// Returns `None`, if the shape is no assembly.
+pub fn compute_parts(
+    shape: &fj::Shape,
+    objects: &mut Service<Objects>,
+    tolerance: Tolerance,
+    debug_info: &mut DebugInfo,
+) -> Result<Option<Vec<(String, FaceSet)>>, Error> {
+    let parts = match shape {
+        fj::Shape::Assembly(assembly) => assembly
+            .parts()
+            .iter()
+            .map(|part| {
+                let faces = part
+                    .shape()
+                    .compute_brep(objects, tolerance, debug_info)?;
+                Ok((part.name().to_owned(), faces))
+            })
+            .collect::<Result<_, Error>>()?,
+        fj::Shape::Transform(shape) => {
+            let transform = transform::make_transform(shape)?;
+            let Some(parts) =
+                compute_parts(&shape.shape, objects, tolerance, debug_info)?
+            else {
+                return Ok(None);
+            };
+            transform_parts(parts, &transform, objects)
+        }
+        fj::Shape::Mirror(shape) => {
+            let transform = mirror::make_transform(shape);
+            let Some(parts) =
+                compute_parts(shape.shape(), objects, tolerance, debug_info)?
+            else {
+                return Ok(None);
+            };
+            transform_parts(parts, &transform, objects)
+        }
+        fj::Shape::LinearPattern(shape) => {
+            let Some(parts) =
+                compute_parts(shape.shape(), objects, tolerance, debug_info)?
+            else {
+                return Ok(None);
+            };
+            repeat_parts(parts, pattern::linear_transforms(shape), objects)
+        }
+        fj::Shape::CircularPattern(shape) => {
+            let Some(parts) =
+                compute_parts(shape.shape(), objects, tolerance, debug_info)?
+            else {
+                return Ok(None);
+            };
+            repeat_parts(parts, pattern::circular_transforms(shape), objects)
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(parts))
+}
+
+fn transform_parts(
+    parts: Vec<(String, FaceSet)>,
+    transform: &Transform,
+    objects: &mut Service<Objects>,
+) -> Vec<(String, FaceSet)> {
+    parts
+        .into_iter()
+        .map(|(name, faces)| (name, faces.transform(transform, objects)))
+        .collect()
+}
+
+fn repeat_parts(
+    parts: Vec<(String, FaceSet)>,
+    transforms: impl Iterator<Item = Transform>,
+    objects: &mut Service<Objects>,
+) -> Vec<(String, FaceSet)> {
+    let mut instances = Vec::new();
+    for (i, transform) in transforms.enumerate() {
+        for (name, faces) in &parts {
+            let faces = faces.clone().transform(&transform, objects);
+            instances.push((format!("{name}-{}", i + 1), faces));
+        }
+    }
+
+    instances
+}
@@ -0,0 +1,90 @@
+use std::ops::Deref;
+
+use fj::EdgeSelection;
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        blend::{Blend, BlendEdges},
+    },
+    insert::Insert,
+    objects::{Objects, Shell, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Scalar};
+
+use super::{selection::select_edges, shape_processor::Error, Shape};
+
+impl Shape for fj::Fillet {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let blend = Blend::Fillet {
+            radius: Scalar::from(self.radius()),
+        };
+        compute_blend(
+            self.shape(),
+            self.edges(),
+            blend,
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Blends stay within the corners they replace.
+        self.shape().bounding_volume()
+    }
+}
+
+impl Shape for fj::Chamfer {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let blend = Blend::Chamfer {
+            distance: Scalar::from(self.distance()),
+        };
+        compute_blend(
+            self.shape(),
+            self.edges(),
+            blend,
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Blends stay within the corners they replace.
+        self.shape().bounding_volume()
+    }
+}
+
+fn compute_blend(
+    shape: &fj::Shape,
+    edges: &EdgeSelection,
+    blend: Blend,
+    objects: &mut Service<Objects>,
+    tolerance: Tolerance,
+    debug_info: &mut DebugInfo,
+) -> Result<Solid, Error> {
+    let faces = shape.compute_brep(objects, tolerance, debug_info)?;
+    let shell = Shell::new(faces).insert(objects);
+    let solid = Solid::new([shell]).insert(objects);
+
+    let edges = select_edges(&solid, edges);
+
+    let solid = (solid, tolerance).blend_edges(edges, blend, objects)?;
+    Ok(solid.deref().clone())
+}
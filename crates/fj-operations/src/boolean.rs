@@ -0,0 +1,124 @@
+use std::ops::Deref;
+
+use fj_interop::{debug::DebugInfo, ext::ArrayExt};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        boolean::{Boolean, BooleanOp},
+    },
+    insert::Insert,
+    objects::{Objects, Shell, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::Union {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Union,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+        a.bounding_volume().merged(&b.bounding_volume())
+    }
+}
+
+impl Shape for fj::Difference3d {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Difference,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: It's never going
+        // to be bigger than the bounding box of the original shape that another
+        // is being subtracted from.
+        self.shapes()[0].bounding_volume()
+    }
+}
+
+impl Shape for fj::Intersection {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Intersection,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes().each_ref_ext().map(Shape::bounding_volume);
+        overlap(a, b)
+    }
+}
+
+/// Compute the overlap of two bounding volumes
+///
+/// If the bounding volumes don't overlap, the result is empty along the axes on
+/// which they are disjoint, instead of being inverted.
+pub fn overlap(a: Aabb<3>, b: Aabb<3>) -> Aabb<3> {
+    let min = a.min.coords.components.zip_ext(b.min.coords.components);
+    let max = a.max.coords.components.zip_ext(b.max.coords.components);
+
+    let min = min.map(|(a, b)| a.max(b));
+    let max = max.zip_ext(min).map(|((a, b), min)| a.min(b).max(min));
+
+    Aabb {
+        min: Point::from(min),
+        max: Point::from(max),
+    }
+}
+
+fn compute_boolean(
+    op: BooleanOp,
+    shapes: &[fj::Shape; 2],
+    objects: &mut Service<Objects>,
+    tolerance: Tolerance,
+    debug_info: &mut DebugInfo,
+) -> Result<Solid, Error> {
+    let [a, b] = shapes.each_ref_ext().try_map_ext(|shape| {
+        let faces = shape.compute_brep(objects, tolerance, debug_info)?;
+        let shell = Shell::new(faces).insert(objects);
+        Ok::<_, Error>(Solid::new([shell]).insert(objects))
+    })?;
+
+    // Curved surfaces are approximated to classify faces.
+    let solid = a.boolean(op, b, tolerance, objects)?;
+    Ok(solid.deref().clone())
+}
@@ -0,0 +1,108 @@
+use std::ops::Deref;
+
+use fj_interop::{debug::DebugInfo, ext::ArrayExt};
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        boolean::{Boolean, BooleanOp},
+    },
+    insert::Insert,
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::Aabb;
+
+use super::{boolean::overlap, shape_processor::Error, Shape};
+
+impl Shape for fj::Union2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Union,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+        a.bounding_volume().merged(&b.bounding_volume())
+    }
+}
+
+impl Shape for fj::Difference2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Difference,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: It's never going
+        // to be bigger than the bounding box of the original shape that another
+        // is being subtracted from.
+        self.shapes()[0].bounding_volume()
+    }
+}
+
+impl Shape for fj::Intersection2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        compute_boolean(
+            BooleanOp::Intersection,
+            self.shapes(),
+            objects,
+            tolerance,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes().each_ref_ext().map(Shape::bounding_volume);
+        overlap(a, b)
+    }
+}
+
+fn compute_boolean(
+    op: BooleanOp,
+    shapes: &[fj::Shape2d; 2],
+    objects: &mut Service<Objects>,
+    tolerance: Tolerance,
+    debug_info: &mut DebugInfo,
+) -> Result<Sketch, Error> {
+    let [a, b] = shapes.each_ref_ext().try_map_ext(|shape| {
+        Ok::<_, Error>(
+            shape
+                .compute_brep(objects, tolerance, debug_info)?
+                .insert(objects),
+        )
+    })?;
+
+    let sketch = a.boolean(op, b, tolerance, objects)?;
+    Ok(sketch.deref().clone())
+}
@@ -1,11 +1,12 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
+    algorithms::approx::Tolerance,
     objects::{FaceSet, Objects},
     services::Service,
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{shape_processor::Error, Shape};
 
 impl Shape for fj::Group {
     type Brep = FaceSet;
@@ -13,17 +14,18 @@ impl Shape for fj::Group {
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, Error> {
         let mut faces = FaceSet::new();
 
-        let a = self.a.compute_brep(objects, debug_info);
-        let b = self.b.compute_brep(objects, debug_info);
+        let a = self.a.compute_brep(objects, tolerance, debug_info)?;
+        let b = self.b.compute_brep(objects, tolerance, debug_info)?;
 
         faces.extend(a);
         faces.extend(b);
 
-        faces
+        Ok(faces)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -0,0 +1,39 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, hollow::Hollow},
+    insert::Insert,
+    objects::{Objects, Shell, Solid},
+    services::Service,
+};
+use fj_math::Aabb;
+
+use super::{selection::select_faces, shape_processor::Error, Shape};
+
+impl Shape for fj::Hollow {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let faces =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
+        let shell = Shell::new(faces).insert(objects);
+        let solid = Solid::new([shell]).insert(objects);
+
+        let open = select_faces(&solid, self.open_faces());
+
+        let solid =
+            (solid, tolerance).hollow(self.thickness(), open, objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // The walls are carved out of the shape.
+        self.shape().bounding_volume()
+    }
+}
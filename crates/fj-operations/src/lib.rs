@@ -18,18 +18,32 @@
 
 pub mod shape_processor;
 
-mod difference_2d;
+mod assembly;
+mod blend;
+mod boolean;
+mod boolean_2d;
 mod group;
+mod hollow;
+mod loft;
+mod mirror;
+mod offset;
+mod path_sweep;
+mod pattern;
+mod primitive;
+mod revolve;
+mod selection;
 mod sketch;
 mod sweep;
 mod transform;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    objects::{FaceSet, Objects, Sketch},
+    algorithms::approx::Tolerance,
+    objects::{FaceSet, Objects, Sketch, Solid},
     services::Service,
 };
 use fj_math::Aabb;
+use shape_processor::Error;
 
 /// Implemented for all operations from the [`fj`] crate
 pub trait Shape {
@@ -37,11 +51,15 @@ pub trait Shape {
     type Brep;
 
     /// Compute the boundary representation of the shape
+    ///
+    /// Operations that approximate curved geometry use the tolerance, which is
+    /// the same that is used for the final triangle mesh.
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep;
+    ) -> Result<Self::Brep, Error>;
 
     /// Access the axis-aligned bounding box of a shape
     ///
@@ -56,24 +74,78 @@ impl Shape for fj::Shape {
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        match self {
-            Self::Shape2d(shape) => {
-                shape.compute_brep(objects, debug_info).faces().clone()
+    ) -> Result<Self::Brep, Error> {
+        Ok(match self {
+            Self::Shape2d(shape) => shape
+                .compute_brep(objects, tolerance, debug_info)?
+                .faces()
+                .clone(),
+            Self::Group(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
             }
-            Self::Group(shape) => shape.compute_brep(objects, debug_info),
-            Self::Sweep(shape) => shape
-                .compute_brep(objects, debug_info)
-                .shells()
-                .map(|shell| shell.faces().clone())
-                .reduce(|mut a, b| {
-                    a.extend(b);
-                    a
-                })
-                .unwrap_or_default(),
-            Self::Transform(shape) => shape.compute_brep(objects, debug_info),
-        }
+            Self::Sweep(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::PathSweep(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Revolve(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Loft(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Transform(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
+            }
+            Self::Union(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Difference3d(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Intersection(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Fillet(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Chamfer(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Hollow(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Cuboid(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Cylinder(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Cone(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Sphere(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::Torus(shape) => faces_of_solid(
+                shape.compute_brep(objects, tolerance, debug_info)?,
+            ),
+            Self::LinearPattern(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
+            }
+            Self::CircularPattern(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
+            }
+            Self::Mirror(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
+            }
+            Self::Assembly(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)?
+            }
+        })
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -81,29 +153,75 @@ impl Shape for fj::Shape {
             Self::Shape2d(shape) => shape.bounding_volume(),
             Self::Group(shape) => shape.bounding_volume(),
             Self::Sweep(shape) => shape.bounding_volume(),
+            Self::PathSweep(shape) => shape.bounding_volume(),
+            Self::Revolve(shape) => shape.bounding_volume(),
+            Self::Loft(shape) => shape.bounding_volume(),
             Self::Transform(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
+            Self::Difference3d(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Fillet(shape) => shape.bounding_volume(),
+            Self::Chamfer(shape) => shape.bounding_volume(),
+            Self::Hollow(shape) => shape.bounding_volume(),
+            Self::Cuboid(shape) => shape.bounding_volume(),
+            Self::Cylinder(shape) => shape.bounding_volume(),
+            Self::Cone(shape) => shape.bounding_volume(),
+            Self::Sphere(shape) => shape.bounding_volume(),
+            Self::Torus(shape) => shape.bounding_volume(),
+            Self::LinearPattern(shape) => shape.bounding_volume(),
+            Self::CircularPattern(shape) => shape.bounding_volume(),
+            Self::Mirror(shape) => shape.bounding_volume(),
+            Self::Assembly(shape) => shape.bounding_volume(),
         }
     }
 }
 
+fn faces_of_solid(solid: Solid) -> FaceSet {
+    solid
+        .shells()
+        .map(|shell| shell.faces().clone())
+        .reduce(|mut a, b| {
+            a.extend(b);
+            a
+        })
+        .unwrap_or_default()
+}
+
 impl Shape for fj::Shape2d {
     type Brep = Sketch;
 
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, Error> {
         match self {
-            Self::Difference(shape) => shape.compute_brep(objects, debug_info),
-            Self::Sketch(shape) => shape.compute_brep(objects, debug_info),
+            Self::Difference(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)
+            }
+            Self::Intersection(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)
+            }
+            Self::Offset(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)
+            }
+            Self::Sketch(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(objects, tolerance, debug_info)
+            }
         }
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
@@ -0,0 +1,94 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, loft::Loft, transform::TransformObject},
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Vector};
+
+use super::{shape_processor::Error, sketch::faces, Shape};
+
+impl Shape for fj::Loft {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        // Faces of different sections are matched by their order. Sketches
+        // keep the order of their regions, while the results of other shapes
+        // have no meaningful order.
+        let sections = self
+            .sections()
+            .into_iter()
+            .map(|section| {
+                let faces = match &section.shape {
+                    fj::Shape2d::Sketch(sketch) => faces(sketch, objects),
+                    shape => shape
+                        .compute_brep(objects, tolerance, debug_info)?
+                        .faces()
+                        .into_iter()
+                        .cloned()
+                        .collect(),
+                };
+                let offset = Vector::from([0., 0., section.offset]);
+                Ok(faces
+                    .into_iter()
+                    .map(|face| face.translate(offset, objects))
+                    .collect())
+            })
+            .collect::<Result<Vec<Vec<_>>, Error>>()?;
+
+        let solid = sections.loft(objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Ruled faces stay within the bounds of the sections they connect.
+        self.sections()
+            .into_iter()
+            .map(|section| {
+                let offset = Vector::from([0., 0., section.offset]);
+                Aabb::<3>::from_points(
+                    section
+                        .shape
+                        .bounding_volume()
+                        .vertices()
+                        .map(|vertex| vertex + offset),
+                )
+            })
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, loft::LoftError},
+        services::Services,
+    };
+
+    use crate::{shape_processor::Error, Shape};
+
+    #[test]
+    fn loft_without_sections() {
+        let mut services = Services::new();
+        let loft = fj::Loft::from_sections(vec![]);
+
+        assert_eq!(loft.bounding_volume(), Default::default());
+        assert!(matches!(
+            loft.compute_brep(
+                &mut services.objects,
+                Tolerance::from_scalar(0.001).unwrap(),
+                &mut DebugInfo::new(),
+            ),
+            Err(Error::Loft(LoftError::TooFewSections))
+        ));
+    }
+}
@@ -0,0 +1,39 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, transform::TransformObject},
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::{Aabb, Transform, Vector};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::Mirror {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        // The kernel takes care of reversing the faces, so the mirrored shape
+        // keeps pointing outward.
+        Ok(self
+            .shape()
+            .compute_brep(objects, tolerance, debug_info)?
+            .transform(&make_transform(self), objects))
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        make_transform(self).transform_aabb(&self.shape().bounding_volume())
+    }
+}
+
+pub fn make_transform(mirror: &fj::Mirror) -> Transform {
+    let point = Vector::from(mirror.point());
+
+    Transform::translation(point)
+        * Transform::reflection(mirror.normal())
+        * Transform::translation(-point)
+}
@@ -0,0 +1,56 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        offset::{Join, Offset},
+    },
+    insert::Insert,
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::{Aabb, Vector};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketch = self
+            .shape()
+            .compute_brep(objects, tolerance, debug_info)?
+            .insert(objects);
+        let join = match self.join() {
+            fj::Join::Miter => Join::Miter,
+            fj::Join::Round => Join::Round,
+        };
+
+        let sketch = sketch.offset(self.distance(), join, objects)?;
+        Ok(sketch.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let Aabb { min, max } = self.shape().bounding_volume();
+
+        // Shrinking shapes stay within their bounding box, while growing ones
+        // reach no further than the distance, except for mitered corners. This
+        // estimate covers mitered corners down to 60 degrees.
+        let distance = match self.join() {
+            fj::Join::Miter => self.distance().max(0.) * 2.,
+            fj::Join::Round => self.distance().max(0.),
+        };
+        let margin = Vector::from([distance, distance, 0.]);
+
+        Aabb {
+            min: min - margin,
+            max: max + margin,
+        }
+    }
+}
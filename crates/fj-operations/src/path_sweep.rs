@@ -0,0 +1,138 @@
+use std::ops::Deref;
+
+use fj::PathSegmentRoute;
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        sweep::{SweepAlongPath, SweepPath, SweepSegment},
+    },
+    insert::Insert,
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::PathSweep {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
+        let sketch = sketch.insert(objects);
+
+        let segments = self
+            .path()
+            .into_iter()
+            .map(|segment| {
+                let endpoint = Point::from(segment.endpoint);
+                match segment.route {
+                    PathSegmentRoute::Direct => SweepSegment::Line(endpoint),
+                    PathSegmentRoute::Arc => SweepSegment::Arc(endpoint),
+                }
+            })
+            .collect();
+
+        let solid =
+            sketch.sweep_along_path(&SweepPath { segments }, objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // The shape is carried along the path, so it stays within a fixed
+        // distance of it. Only mitered joints stretch the shape.
+        let reach = self
+            .shape()
+            .bounding_volume()
+            .vertices()
+            .map(|vertex| vertex.distance_to(&Point::origin()))
+            .into_iter()
+            .fold(Scalar::ZERO, Scalar::max);
+
+        let mut points = vec![Point::origin()];
+        let mut stretch = Scalar::ONE;
+
+        let mut point = Point::origin();
+        let mut tangent: Option<Vector<3>> = None;
+        let mut previous_line: Option<Vector<3>> = None;
+
+        for segment in self.path() {
+            let end = Point::from(segment.endpoint);
+            let chord = end - point;
+            if chord.magnitude() == Scalar::ZERO {
+                continue;
+            }
+
+            match segment.route {
+                PathSegmentRoute::Direct => {
+                    let direction = chord.normalize();
+
+                    if let Some(previous) = previous_line {
+                        let cos_half_angle =
+                            ((previous.dot(&direction) + Scalar::ONE) / 2.)
+                                .sqrt();
+                        if cos_half_angle > Scalar::ZERO {
+                            stretch = stretch.max(Scalar::ONE / cos_half_angle);
+                        }
+                    }
+
+                    tangent = Some(direction);
+                    previous_line = Some(direction);
+                }
+                PathSegmentRoute::Arc => {
+                    // The arc is contained in the bounding box of its circle.
+                    let start_tangent = tangent.unwrap_or_else(|| {
+                        if chord.z < Scalar::ZERO {
+                            -Vector::unit_z()
+                        } else {
+                            Vector::unit_z()
+                        }
+                    });
+                    let to_center =
+                        chord - start_tangent * start_tangent.dot(&chord);
+
+                    if to_center.magnitude() > Scalar::ZERO {
+                        let to_center = to_center.normalize();
+                        let radius =
+                            chord.dot(&chord) / (chord.dot(&to_center) * 2.);
+                        let center = point + to_center * radius;
+
+                        points.extend(
+                            [
+                                Vector::unit_x(),
+                                Vector::unit_y(),
+                                Vector::unit_z(),
+                            ]
+                            .into_iter()
+                            .flat_map(|axis| {
+                                [center + axis * radius, center - axis * radius]
+                            }),
+                        );
+
+                        let axis = start_tangent.cross(&to_center);
+                        tangent = Some(axis.cross(&(end - center)).normalize());
+                    }
+
+                    previous_line = None;
+                }
+            }
+
+            points.push(end);
+            point = end;
+        }
+
+        let aabb = Aabb::<3>::from_points(points);
+        let margin = Vector::from([reach * stretch; 3]);
+        Aabb {
+            min: aabb.min - margin,
+            max: aabb.max + margin,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, transform::TransformObject},
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::{Aabb, Transform, Vector};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::LinearPattern {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let faces =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
+        Ok(repeat(faces, linear_transforms(self), objects))
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+        merge_transformed(aabb, linear_transforms(self))
+    }
+}
+
+impl Shape for fj::CircularPattern {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let faces =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
+        Ok(repeat(faces, circular_transforms(self), objects))
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+        merge_transformed(aabb, circular_transforms(self))
+    }
+}
+
+pub fn linear_transforms(
+    pattern: &fj::LinearPattern,
+) -> impl Iterator<Item = Transform> {
+    let offset = Vector::from(pattern.offset());
+    (0..pattern.count()).map(move |i| Transform::translation(offset * i as f64))
+}
+
+pub fn circular_transforms(
+    pattern: &fj::CircularPattern,
+) -> impl Iterator<Item = Transform> {
+    let origin = Vector::from(pattern.axis_origin());
+    let axis = Vector::from(pattern.axis_direction()).normalize();
+    let angle = pattern.angle().rad();
+
+    (0..pattern.count()).map(move |i| {
+        Transform::translation(origin)
+            * Transform::rotation(axis * (angle * i as f64))
+            * Transform::translation(-origin)
+    })
+}
+
+/// Combine the transformed instances of the faces into one set
+fn repeat(
+    faces: FaceSet,
+    transforms: impl Iterator<Item = Transform>,
+    objects: &mut Service<Objects>,
+) -> FaceSet {
+    let mut instances = FaceSet::new();
+    for transform in transforms {
+        instances.extend(faces.clone().transform(&transform, objects));
+    }
+
+    instances
+}
+
+fn merge_transformed(
+    aabb: Aabb<3>,
+    transforms: impl Iterator<Item = Transform>,
+) -> Aabb<3> {
+    transforms
+        .map(|transform| transform.transform_aabb(&aabb))
+        .reduce(|a, b| a.merged(&b))
+        .unwrap_or(aabb)
+}
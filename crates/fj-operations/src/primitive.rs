@@ -0,0 +1,222 @@
+use std::ops::Deref;
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    builder::SolidBuilder,
+    insert::Insert,
+    objects::{Objects, Solid},
+    partial::{PartialObject, PartialSolid},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::Cuboid {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        check_dimensions(
+            self.size().iter().all(|&length| length > 0.),
+            "Cuboid must have a positive size",
+        )?;
+        build_solid(self.color(), objects, |solid| {
+            solid.update_as_cuboid(self.size())
+        })
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [x, y, z] = self.size().map(|length| length / 2.);
+        Aabb {
+            min: Point::from([-x, -y, -z]),
+            max: Point::from([x, y, z]),
+        }
+    }
+}
+
+impl Shape for fj::Cylinder {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        check_dimensions(
+            self.radius() > 0. && self.height() > 0.,
+            "Cylinder must have a positive radius and height",
+        )?;
+        build_solid(self.color(), objects, |solid| {
+            solid.update_as_cylinder(self.radius(), self.height())
+        })
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let radius = self.radius();
+        Aabb {
+            min: Point::from([-radius, -radius, 0.]),
+            max: Point::from([radius, radius, self.height()]),
+        }
+    }
+}
+
+impl Shape for fj::Cone {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        check_dimensions(
+            self.radius() > 0. && self.height() > 0.,
+            "Cone must have a positive radius and height",
+        )?;
+        build_solid(self.color(), objects, |solid| {
+            solid.update_as_cone(self.radius(), self.height())
+        })
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let radius = self.radius();
+        Aabb {
+            min: Point::from([-radius, -radius, 0.]),
+            max: Point::from([radius, radius, self.height()]),
+        }
+    }
+}
+
+impl Shape for fj::Sphere {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        check_dimensions(
+            self.radius() > 0.,
+            "Sphere must have a positive radius",
+        )?;
+        build_solid(self.color(), objects, |solid| {
+            solid.update_as_sphere(self.radius())
+        })
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let radius = self.radius();
+        Aabb {
+            min: Point::from([-radius, -radius, -radius]),
+            max: Point::from([radius, radius, radius]),
+        }
+    }
+}
+
+impl Shape for fj::Torus {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        check_dimensions(
+            self.minor_radius() > 0.
+                && self.major_radius() > self.minor_radius(),
+            "Torus must have a positive minor radius that is smaller than its \
+            major radius",
+        )?;
+        build_solid(self.color(), objects, |solid| {
+            solid.update_as_torus(self.major_radius(), self.minor_radius())
+        })
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let outer = self.major_radius() + self.minor_radius();
+        let minor = self.minor_radius();
+        Aabb {
+            min: Point::from([-outer, -outer, -minor]),
+            max: Point::from([outer, outer, minor]),
+        }
+    }
+}
+
+/// Check the dimensions of a primitive, before building it
+///
+/// The builders panic on invalid dimensions, like zero or negative sizes.
+fn check_dimensions(
+    is_valid: bool,
+    message: &'static str,
+) -> Result<(), Error> {
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidDimensions(message))
+    }
+}
+
+fn build_solid(
+    color: [u8; 4],
+    objects: &mut Service<Objects>,
+    update: impl FnOnce(&mut PartialSolid),
+) -> Result<Solid, Error> {
+    let mut solid = PartialSolid::default();
+    update(&mut solid);
+
+    for shell in &solid.shells {
+        for face in &shell.read().faces {
+            face.clone().write().color = Some(Color(color));
+        }
+    }
+
+    let solid = solid.build(objects).insert(objects);
+    Ok(solid.deref().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{algorithms::approx::Tolerance, services::Services};
+
+    use crate::{shape_processor::Error, Shape};
+
+    #[test]
+    fn invalid_dimensions() {
+        let mut services = Services::new();
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+
+        let mut compute = |shape: fj::Shape| {
+            shape
+                .compute_brep(
+                    &mut services.objects,
+                    tolerance,
+                    &mut DebugInfo::new(),
+                )
+                .map(|_| ())
+        };
+
+        let shapes: [fj::Shape; 6] = [
+            fj::Cuboid::from_size([1., 0., 1.]).into(),
+            fj::Cylinder::from_radius_and_height(-1., 1.).into(),
+            fj::Cone::from_radius_and_height(1., 0.).into(),
+            fj::Sphere::from_radius(f64::NAN).into(),
+            fj::Torus::from_radii(1., 1.).into(),
+            fj::Torus::from_radii(2., 0.).into(),
+        ];
+        for shape in shapes {
+            assert!(matches!(compute(shape), Err(Error::InvalidDimensions(_))));
+        }
+
+        assert!(compute(fj::Cuboid::from_size([1., 2., 3.]).into()).is_ok());
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, revolve::Revolve},
+    insert::Insert,
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Line, Point, Scalar, Vector};
+
+use super::{shape_processor::Error, Shape};
+
+impl Shape for fj::Revolve {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
+        let sketch = sketch.insert(objects);
+
+        let solid = sketch.revolve(axis(self)?, self.angle().rad(), objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // The revolved shape is contained in the cylinder that the bounding
+        // volume of the 2-dimensional shape describes, when revolved around
+        // the axis.
+        let Ok(axis) = axis(self) else {
+            // Processing the shape fails, as the axis has no direction.
+            return self.shape().bounding_volume();
+        };
+        let direction = axis.direction().normalize();
+
+        let corners = self.shape().bounding_volume().vertices();
+        let heights =
+            corners.map(|corner| direction.dot(&(corner - axis.origin())));
+        let radius = corners
+            .into_iter()
+            .zip(heights)
+            .map(|(corner, height)| {
+                (corner - (axis.origin() + direction * height)).magnitude()
+            })
+            .fold(Scalar::ZERO, Scalar::max);
+
+        let [min, max] = [Scalar::min, Scalar::max].map(|select| {
+            heights.into_iter().reduce(select).unwrap_or(Scalar::ZERO)
+        });
+        let aabb = Aabb::<3>::from_points(
+            [min, max].map(|height| axis.origin() + direction * height),
+        );
+
+        let extent = Vector::from(direction.components.map(|component| {
+            radius
+                * (Scalar::ONE - component * component)
+                    .max(Scalar::ZERO)
+                    .sqrt()
+        }));
+        Aabb {
+            min: aabb.min - extent,
+            max: aabb.max + extent,
+        }
+    }
+}
+
+fn axis(revolve: &fj::Revolve) -> Result<Line<3>, Error> {
+    let [x, y] = revolve.axis_origin();
+    let [dx, dy] = revolve.axis_direction();
+
+    let direction = Vector::from([dx, dy, 0.]);
+    if direction.magnitude() == Scalar::ZERO {
+        return Err(Error::ZeroRevolveAxis);
+    }
+
+    Ok(Line::from_origin_and_direction(
+        Point::from([x, y, 0.]),
+        direction,
+    ))
+}
@@ -0,0 +1,67 @@
+use fj::{EdgeSelection, FaceSelection};
+use fj_kernel::{
+    algorithms::select::{EdgeQuery, FaceQuery, Select},
+    objects::{Face, FaceOrigin, GlobalEdge, Solid},
+    storage::Handle,
+};
+
+/// Resolve a selection of faces against a solid
+pub fn select_faces(
+    solid: &Solid,
+    selection: &FaceSelection,
+) -> Vec<Handle<Face>> {
+    match face_query(selection) {
+        Some(query) => solid.select_faces(&query),
+        None => Vec::new(),
+    }
+}
+
+/// Resolve a selection of edges against a solid
+pub fn select_edges(
+    solid: &Solid,
+    selection: &EdgeSelection,
+) -> Vec<Handle<GlobalEdge>> {
+    // Only edges of planar faces are selected. Skipping the other faces also
+    // skips the seams of curved faces, which aren't actual edges.
+    let (faces, edges) = match selection {
+        EdgeSelection::All => {
+            (Some(FaceQuery::default()), EdgeQuery::default())
+        }
+        EdgeSelection::Parallel { direction } => (
+            Some(FaceQuery::default()),
+            EdgeQuery::default().parallel_to(*direction),
+        ),
+        EdgeSelection::OfFaces { normal } => (
+            Some(FaceQuery::default().facing(*normal)),
+            EdgeQuery::default(),
+        ),
+        EdgeSelection::OfSelectedFaces { faces } => {
+            (face_query(faces), EdgeQuery::default())
+        }
+        EdgeSelection::InteriorOf { faces } => {
+            (face_query(faces), EdgeQuery::default().interior())
+        }
+    };
+
+    match faces {
+        Some(faces) => solid.select_edges(&faces.planar(), &edges),
+        None => Vec::new(),
+    }
+}
+
+fn face_query(selection: &FaceSelection) -> Option<FaceQuery> {
+    let query = FaceQuery::default().planar();
+
+    let query = match selection {
+        FaceSelection::None => return None,
+        FaceSelection::All => query,
+        FaceSelection::Facing { normal } => query.facing(*normal),
+        FaceSelection::SweepTop => query.with_origin(FaceOrigin::SweepTop),
+        FaceSelection::SweepBottom => {
+            query.with_origin(FaceOrigin::SweepBottom)
+        }
+        FaceSelection::SweepSides => query.with_origin(FaceOrigin::SweepSide),
+    };
+
+    Some(query)
+}
@@ -1,9 +1,20 @@
 //! API for processing shapes
 
-use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
+use fj_interop::{
+    debug::DebugInfo,
+    mesh::Mesh,
+    processed_shape::{ProcessedPart, ProcessedShape},
+};
 use fj_kernel::{
     algorithms::{
         approx::{InvalidTolerance, Tolerance},
+        blend::BlendError,
+        boolean::BooleanError,
+        hollow::HollowError,
+        loft::LoftError,
+        offset::OffsetError,
+        revolve::RevolveError,
+        sweep::{DraftError, SweepPathError},
         triangulate::Triangulate,
     },
     services::Services,
@@ -11,7 +22,7 @@ use fj_kernel::{
 };
 use fj_math::Scalar;
 
-use crate::Shape as _;
+use crate::{assembly::compute_parts, Shape as _};
 
 /// Processes an [`fj::Shape`] into a [`ProcessedShape`]
 pub struct ShapeProcessor {
@@ -44,12 +55,47 @@ impl ShapeProcessor {
 
         let mut services = Services::new();
         let mut debug_info = DebugInfo::new();
-        let shape = shape.compute_brep(&mut services.objects, &mut debug_info);
-        let mesh = (&shape, tolerance).triangulate();
+
+        let mut parts = Vec::new();
+        let assembly = compute_parts(
+            shape,
+            &mut services.objects,
+            tolerance,
+            &mut debug_info,
+        )?;
+        let mesh = match assembly {
+            Some(assembly) => {
+                let mut mesh = Mesh::new();
+
+                for (name, faces) in assembly {
+                    let part_mesh = (&faces, tolerance).triangulate();
+
+                    for triangle in part_mesh.triangles() {
+                        mesh.push_triangle(triangle.inner, triangle.color);
+                    }
+
+                    parts.push(ProcessedPart {
+                        name,
+                        mesh: part_mesh,
+                    });
+                }
+
+                mesh
+            }
+            None => {
+                let shape = shape.compute_brep(
+                    &mut services.objects,
+                    tolerance,
+                    &mut debug_info,
+                )?;
+                (&shape, tolerance).triangulate()
+            }
+        };
 
         Ok(ProcessedShape {
             aabb,
             mesh,
+            parts,
             debug_info,
         })
     }
@@ -65,4 +111,51 @@ pub enum Error {
     /// Model has zero size
     #[error("Model has zero size")]
     Extent(#[from] InvalidTolerance),
+
+    /// Model scales surfaces of revolution non-uniformly
+    #[error(
+        "Scaling by different factors along the axes is not supported for \
+        revolved shapes, cones, spheres, and tori"
+    )]
+    NonUniformScale,
+
+    /// Primitive has invalid dimensions
+    #[error("Invalid dimensions: {0}")]
+    InvalidDimensions(&'static str),
+
+    /// Edges can't be blended
+    #[error("Error blending edges")]
+    Blend(#[from] BlendError),
+
+    /// Shape can't be hollowed out
+    #[error("Error hollowing out shape")]
+    Hollow(#[from] HollowError),
+
+    /// Axis of a revolution has no direction
+    #[error("The axis of a revolution must have a non-zero direction")]
+    ZeroRevolveAxis,
+
+    /// Shape can't be offset
+    #[error("Error offsetting shape")]
+    Offset(#[from] OffsetError),
+
+    /// Shape can't be revolved
+    #[error("Error revolving shape")]
+    Revolve(#[from] RevolveError),
+
+    /// Shape can't be swept with a draft angle
+    #[error("Error sweeping shape with draft angle")]
+    Draft(#[from] DraftError),
+
+    /// Shape can't be swept along path
+    #[error("Error sweeping shape along path")]
+    PathSweep(#[from] SweepPathError),
+
+    /// Sections can't be lofted between
+    #[error("Error lofting between sections")]
+    Loft(#[from] LoftError),
+
+    /// Boolean operation between shapes is not supported
+    #[error("Error computing boolean operation")]
+    Boolean(#[from] BooleanError),
 }
@@ -2,18 +2,17 @@ use std::ops::Deref;
 
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    builder::{CycleBuilder, HalfEdgeBuilder},
+    algorithms::{approx::Tolerance, reverse::Reverse},
+    builder::{CycleBuilder, FaceBuilder, HalfEdgeBuilder},
     insert::Insert,
-    objects::{Objects, Sketch},
-    partial::{
-        Partial, PartialCycle, PartialFace, PartialHalfEdge, PartialObject,
-        PartialSketch,
-    },
+    objects::{Cycle, Face, Objects, Sketch},
+    partial::{Partial, PartialCycle, PartialFace, PartialObject},
     services::Service,
+    storage::Handle,
 };
-use fj_math::{Aabb, Point};
+use fj_math::{Aabb, CubicBezier, Point, Scalar, Winding};
 
-use super::Shape;
+use super::{shape_processor::Error, Shape};
 
 impl Shape for fj::Sketch {
     type Brep = Sketch;
@@ -21,148 +20,275 @@ impl Shape for fj::Sketch {
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        _: Tolerance,
         _: &mut DebugInfo,
-    ) -> Self::Brep {
-        let surface = objects.surfaces.xy_plane();
-
-        let face = match self.chain() {
-            fj::Chain::Circle(circle) => {
-                let surface = Partial::from(surface);
-
-                let half_edge = {
-                    let mut half_edge = PartialHalfEdge::default();
-                    half_edge.update_as_circle_from_radius(circle.radius());
-
-                    Partial::from_partial(half_edge)
-                };
-                let exterior = {
-                    let mut cycle = PartialCycle::default();
-                    cycle.half_edges.push(half_edge);
-                    Partial::from_partial(cycle)
-                };
-
-                PartialFace {
-                    surface,
-                    exterior,
-                    color: Some(Color(self.color())),
-                    ..Default::default()
-                }
+    ) -> Result<Self::Brep, Error> {
+        let sketch = Sketch::new(faces(self, objects)).insert(objects);
+        Ok(sketch.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Interior chains are within the exterior ones, so they don't need to
+        // be considered.
+        let points = self
+            .regions()
+            .iter()
+            .flat_map(|region| chain_points(region.exterior()))
+            .collect::<Vec<_>>();
+
+        Aabb::<3>::from_points(points.into_iter().map(Point::to_xyz))
+    }
+}
+
+/// Compute the faces of a sketch, in the order of its regions
+pub(crate) fn faces(
+    sketch: &fj::Sketch,
+    objects: &mut Service<Objects>,
+) -> Vec<Handle<Face>> {
+    let surface = objects.surfaces.xy_plane();
+
+    sketch
+        .regions()
+        .into_iter()
+        .map(|region| {
+            let mut face = PartialFace {
+                surface: Partial::from(surface.clone()),
+                color: Some(Color(sketch.color())),
+                ..Default::default()
+            };
+            add_chain(&mut face.exterior.write(), region.exterior());
+            for chain in region.interiors() {
+                let mut interior = face.add_interior();
+                add_chain(&mut interior.write(), &chain);
             }
-            fj::Chain::PolyChain(poly_chain) => {
-                let segments = poly_chain.to_segments();
-                assert!(
-                    !segments.is_empty(),
-                    "Attempted to compute a Brep from an empty sketch"
-                );
-
-                let exterior = {
-                    let mut cycle = PartialCycle::default();
-                    let mut line_segments = vec![];
-                    let mut arcs = vec![];
-                    poly_chain.to_segments().into_iter().for_each(
-                        |fj::SketchSegment { endpoint, route }| {
-                            let endpoint = Point::from(endpoint);
-                            match route {
-                                fj::SketchSegmentRoute::Direct => {
-                                    line_segments.push(
-                                        cycle
-                                            .add_half_edge_from_point_to_start(
-                                                endpoint,
-                                            ),
-                                    );
-                                }
-                                fj::SketchSegmentRoute::Arc { angle } => {
-                                    arcs.push((
-                                        cycle
-                                            .add_half_edge_from_point_to_start(
-                                                endpoint,
-                                            ),
-                                        angle,
-                                    ));
-                                }
-                            }
-                        },
-                    );
-                    line_segments.into_iter().for_each(|mut half_edge| {
-                        half_edge.write().update_as_line_segment();
-                    });
-                    arcs.into_iter().for_each(|(mut half_edge, angle)| {
-                        half_edge.write().update_as_arc(angle.rad())
-                    });
-                    Partial::from_partial(cycle)
-                };
-
-                PartialFace {
-                    surface: Partial::from(surface),
-                    exterior,
-                    color: Some(Color(self.color())),
-                    ..Default::default()
+            let face = face.build(objects);
+
+            // Chains may be wound in either direction. Exterior cycles are
+            // made counter-clockwise, which sweeping expects, while interior
+            // cycles must be wound in the opposite direction.
+            let mut wind = |cycle: &Handle<Cycle>, winding| {
+                if cycle.winding() == winding {
+                    cycle.clone()
+                } else {
+                    cycle.clone().reverse(objects)
+                }
+            };
+            let exterior = wind(face.exterior(), Winding::Ccw);
+            let interiors = face
+                .interiors()
+                .map(|cycle| wind(cycle, Winding::Cw))
+                .collect::<Vec<_>>();
+
+            Face::new(face.surface().clone(), exterior, interiors, face.color())
+                .insert(objects)
+        })
+        .collect()
+}
+
+/// Add the half-edges of a chain to a cycle
+fn add_chain(cycle: &mut PartialCycle, chain: &fj::Chain) {
+    match chain {
+        fj::Chain::Circle(circle) => {
+            let mut half_edge = cycle.add_half_edge();
+            half_edge.write().update_as_circle_from_center_and_radius(
+                circle.center(),
+                circle.radius(),
+            );
+        }
+        fj::Chain::Ellipse(ellipse) => {
+            let mut half_edge = cycle.add_half_edge();
+            half_edge.write().update_as_ellipse_from_center_and_radii(
+                ellipse.center(),
+                ellipse.radii(),
+            );
+        }
+        fj::Chain::PolyChain(poly_chain) => {
+            let segments = poly_chain.to_segments();
+            assert!(
+                !segments.is_empty(),
+                "Attempted to compute a Brep from an empty sketch"
+            );
+            let segments = to_cubic_beziers(segments);
+
+            let half_edges = segments
+                .iter()
+                .map(|segment| {
+                    cycle.add_half_edge_from_point_to_start(Point::from(
+                        segment.endpoint,
+                    ))
+                })
+                .collect::<Vec<_>>();
+
+            // Each half-edge starts at the endpoint of its segment, but the
+            // route of a segment leads to its endpoint. It applies to the
+            // previous half-edge.
+            let mut line_segments = vec![];
+            let mut arcs = vec![];
+            let mut beziers = vec![];
+            for (i, segment) in segments.iter().enumerate() {
+                let previous = (i + half_edges.len() - 1) % half_edges.len();
+                let half_edge = half_edges[previous].clone();
+
+                match segment.route {
+                    fj::SketchSegmentRoute::Direct => {
+                        line_segments.push(half_edge);
+                    }
+                    fj::SketchSegmentRoute::Arc { angle } => {
+                        arcs.push((half_edge, angle));
+                    }
+                    fj::SketchSegmentRoute::CubicBezier { controls } => {
+                        beziers.push((half_edge, controls));
+                    }
+                    fj::SketchSegmentRoute::QuadraticBezier { .. }
+                    | fj::SketchSegmentRoute::BSpline(_) => {
+                        unreachable!("Curves have been converted to cubic")
+                    }
                 }
             }
+            line_segments.into_iter().for_each(|mut half_edge| {
+                half_edge.write().update_as_line_segment();
+            });
+            arcs.into_iter().for_each(|(mut half_edge, angle)| {
+                half_edge.write().update_as_arc(angle.rad())
+            });
+            beziers.into_iter().for_each(|(mut half_edge, controls)| {
+                half_edge.write().update_as_bezier(controls)
+            });
+        }
+    }
+}
+
+/// Convert all free-form segments of a chain into cubic Bézier curves
+///
+/// B-splines are split into multiple segments, one for each of their spans.
+fn to_cubic_beziers(
+    segments: Vec<fj::SketchSegment>,
+) -> Vec<fj::SketchSegment> {
+    let mut start = segments
+        .last()
+        .map(|segment| Point::from(segment.endpoint))
+        .unwrap_or_else(Point::origin);
+
+    let mut converted = Vec::new();
+    for segment in segments {
+        let end = Point::from(segment.endpoint);
+
+        let beziers = match &segment.route {
+            fj::SketchSegmentRoute::Direct
+            | fj::SketchSegmentRoute::Arc { .. }
+            | fj::SketchSegmentRoute::CubicBezier { .. } => {
+                converted.push(segment);
+                start = end;
+                continue;
+            }
+            fj::SketchSegmentRoute::QuadraticBezier { control } => {
+                vec![CubicBezier::from_quadratic_points([
+                    start,
+                    Point::from(*control),
+                    end,
+                ])]
+            }
+            fj::SketchSegmentRoute::BSpline(spline) => {
+                let mut points = vec![start];
+                points.extend(spline.controls().into_iter().map(Point::from));
+                points.push(end);
+
+                CubicBezier::chain_from_b_spline(&points)
+            }
         };
 
-        let sketch = PartialSketch {
-            faces: vec![Partial::from_partial(face)],
+        converted.extend(beziers.into_iter().map(|bezier| {
+            let [_, b, c, d] = bezier.points().map(|point| point.coords);
+            fj::SketchSegment::new(
+                d.components.map(Scalar::into_f64),
+                fj::SketchSegmentRoute::CubicBezier {
+                    controls: [b, c]
+                        .map(|point| point.components.map(Scalar::into_f64)),
+                },
+            )
+        }));
+
+        // Make sure the last segment ends exactly at the endpoint.
+        if let Some(last) = converted.last_mut() {
+            last.endpoint = segment.endpoint;
         }
-        .build(objects)
-        .insert(objects);
-        sketch.deref().clone()
+        start = end;
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
-        match self.chain() {
-            fj::Chain::Circle(circle) => Aabb {
-                min: Point::from([-circle.radius(), -circle.radius(), 0.0]),
-                max: Point::from([circle.radius(), circle.radius(), 0.0]),
-            },
-            fj::Chain::PolyChain(poly_chain) => {
-                let segments = poly_chain.to_segments();
-                assert!(
-                    !segments.is_empty(),
-                    "Attempted to compute a bounding box from an empty sketch"
-                );
-
-                let mut points = vec![];
-
-                let mut start_point = segments[segments.len() - 1].endpoint;
-                segments.iter().for_each(|segment| {
-                    match segment.route {
-                        fj::SketchSegmentRoute::Direct => (),
-                        fj::SketchSegmentRoute::Arc { angle } => {
-                            use std::f64::consts::PI;
-                            let arc = fj_math::Arc::from_endpoints_and_angle(
-                                start_point,
-                                segment.endpoint,
-                                fj_math::Scalar::from_f64(angle.rad()),
-                            );
-                            for circle_minmax_angle in
-                                [0., PI / 2., PI, 3. * PI / 2.]
+    converted
+}
+
+/// Compute points that bound a chain
+fn chain_points(chain: &fj::Chain) -> Vec<Point<2>> {
+    match chain {
+        fj::Chain::Circle(circle) => {
+            let center = Point::from(circle.center());
+            let radius = circle.radius();
+
+            vec![center - [radius, radius], center + [radius, radius]]
+        }
+        fj::Chain::Ellipse(ellipse) => {
+            let center = Point::from(ellipse.center());
+            let radii = ellipse.radii();
+
+            vec![center - radii, center + radii]
+        }
+        fj::Chain::PolyChain(poly_chain) => {
+            let segments = poly_chain.to_segments();
+            assert!(
+                !segments.is_empty(),
+                "Attempted to compute a bounding box from an empty sketch"
+            );
+
+            let segments = to_cubic_beziers(segments);
+            let mut points = vec![];
+
+            let mut start_point = segments[segments.len() - 1].endpoint;
+            segments.iter().for_each(|segment| {
+                match segment.route {
+                    fj::SketchSegmentRoute::Direct => (),
+                    fj::SketchSegmentRoute::CubicBezier { controls } => {
+                        // The curve is within the convex hull of its control
+                        // points.
+                        points.extend(controls.map(Point::from));
+                    }
+                    fj::SketchSegmentRoute::QuadraticBezier { .. }
+                    | fj::SketchSegmentRoute::BSpline(_) => {
+                        unreachable!("Curves have been converted to cubic")
+                    }
+                    fj::SketchSegmentRoute::Arc { angle } => {
+                        use std::f64::consts::PI;
+                        let arc = fj_math::Arc::from_endpoints_and_angle(
+                            start_point,
+                            segment.endpoint,
+                            fj_math::Scalar::from_f64(angle.rad()),
+                        );
+                        for circle_minmax_angle in
+                            [0., PI / 2., PI, 3. * PI / 2.]
+                        {
+                            let mm_angle =
+                                fj_math::Scalar::from_f64(circle_minmax_angle);
+                            if arc.start_angle < mm_angle
+                                && mm_angle < arc.end_angle
                             {
-                                let mm_angle = fj_math::Scalar::from_f64(
-                                    circle_minmax_angle,
+                                points.push(
+                                    arc.center
+                                        + [
+                                            arc.radius
+                                                * circle_minmax_angle.cos(),
+                                            arc.radius
+                                                * circle_minmax_angle.sin(),
+                                        ],
                                 );
-                                if arc.start_angle < mm_angle
-                                    && mm_angle < arc.end_angle
-                                {
-                                    points.push(
-                                        arc.center
-                                            + [
-                                                arc.radius
-                                                    * circle_minmax_angle.cos(),
-                                                arc.radius
-                                                    * circle_minmax_angle.sin(),
-                                            ],
-                                    );
-                                }
                             }
                         }
                     }
-                    points.push(Point::from(segment.endpoint));
-                    start_point = segment.endpoint;
-                });
+                }
+                points.push(Point::from(segment.endpoint));
+                start_point = segment.endpoint;
+            });
 
-                Aabb::<3>::from_points(points.into_iter().map(Point::to_xyz))
-            }
+            points
         }
     }
 }
@@ -2,14 +2,17 @@ use std::ops::Deref;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::sweep::Sweep,
+    algorithms::{
+        approx::Tolerance,
+        sweep::{Sweep, SweepWithDraft},
+    },
     insert::Insert,
     objects::{Objects, Solid},
     services::Service,
 };
 use fj_math::{Aabb, Vector};
 
-use super::Shape;
+use super::{shape_processor::Error, Shape};
 
 impl Shape for fj::Sweep {
     type Brep = Solid;
@@ -17,25 +20,42 @@ impl Shape for fj::Sweep {
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        let sketch = self.shape().compute_brep(objects, debug_info);
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(objects, tolerance, debug_info)?;
         let sketch = sketch.insert(objects);
 
         let path = Vector::from(self.path());
 
-        let solid = sketch.sweep(path, objects);
-        solid.deref().clone()
+        // Tiny draft angles move the top face by less than any distance that
+        // matters, but would still require offsetting it.
+        let draft = self.draft().rad();
+        let solid = if draft.abs() <= 1e-9 {
+            sketch.sweep(path, objects)
+        } else {
+            sketch.sweep_with_draft(path, draft, objects)?
+        };
+        Ok(solid.deref().clone())
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        self.shape()
-            .bounding_volume()
-            .merged(&Aabb::<3>::from_points(
-                self.shape()
-                    .bounding_volume()
-                    .vertices()
-                    .map(|v| v + self.path()),
-            ))
+        let top = Aabb::<3>::from_points(
+            self.shape()
+                .bounding_volume()
+                .vertices()
+                .map(|v| v + self.path()),
+        );
+
+        // A negative draft angle makes the top face larger than the bottom one.
+        let growth = (self.path()[2].abs() * -self.draft().rad().tan()).max(0.);
+        let margin = Vector::from([growth, growth, 0.]);
+        let top = Aabb {
+            min: top.min - margin,
+            max: top.max + margin,
+        };
+
+        self.shape().bounding_volume().merged(&top)
     }
 }
@@ -1,12 +1,12 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::transform::TransformObject,
+    algorithms::{approx::Tolerance, transform::TransformObject},
     objects::{FaceSet, Objects},
     services::Service,
 };
 use fj_math::{Aabb, Transform, Vector};
 
-use super::Shape;
+use super::{shape_processor::Error, Shape};
 
 impl Shape for fj::Transform {
     type Brep = FaceSet;
@@ -14,20 +14,125 @@ impl Shape for fj::Transform {
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
+        tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        self.shape
-            .compute_brep(objects, debug_info)
-            .transform(&make_transform(self), objects)
+    ) -> Result<Self::Brep, Error> {
+        Ok(self
+            .shape
+            .compute_brep(objects, tolerance, debug_info)?
+            .transform(&make_transform(self)?, objects))
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        make_transform(self).transform_aabb(&self.shape.bounding_volume())
+        transform_of(self).transform_aabb(&self.shape.bounding_volume())
     }
 }
 
-fn make_transform(transform: &fj::Transform) -> Transform {
+/// Convert the transform into a kernel transform, if the kernel supports it
+///
+/// Scaling by different factors along the axes turns circles into ellipses,
+/// which the kernel supports. Surfaces of revolution can't be scaled like that,
+/// though.
+pub fn make_transform(transform: &fj::Transform) -> Result<Transform, Error> {
+    if !is_uniform(transform.scale) && has_revolved_surfaces(&transform.shape) {
+        return Err(Error::NonUniformScale);
+    }
+
+    Ok(transform_of(transform))
+}
+
+fn transform_of(transform: &fj::Transform) -> Transform {
     let axis = Vector::from(transform.axis).normalize();
     Transform::translation(transform.offset)
         * Transform::rotation(axis * transform.angle.rad())
+        * Transform::scale_non_uniform(transform.scale)
+}
+
+fn is_uniform(scale: [f64; 3]) -> bool {
+    // A negative factor mirrors the shape, which is fine.
+    let [x, y, z] = scale.map(f64::abs);
+    x == y && y == z
+}
+
+fn has_revolved_surfaces(shape: &fj::Shape) -> bool {
+    match shape {
+        // Arcs in the path of a sweep are made by revolving the swept shape.
+        fj::Shape::PathSweep(sweep) => sweep
+            .path()
+            .iter()
+            .any(|segment| segment.route != fj::PathSegmentRoute::Direct),
+        fj::Shape::Revolve(_)
+        | fj::Shape::Cone(_)
+        | fj::Shape::Sphere(_)
+        | fj::Shape::Torus(_) => true,
+        fj::Shape::Shape2d(_)
+        | fj::Shape::Sweep(_)
+        | fj::Shape::Loft(_)
+        | fj::Shape::Cuboid(_)
+        | fj::Shape::Cylinder(_) => false,
+        shape => sub_shapes(shape).into_iter().any(has_revolved_surfaces),
+    }
+}
+
+/// Access the 3-dimensional shapes that `shape` is made of
+fn sub_shapes(shape: &fj::Shape) -> Vec<&fj::Shape> {
+    match shape {
+        fj::Shape::Group(group) => vec![&group.a, &group.b],
+        fj::Shape::Transform(transform) => vec![&transform.shape],
+        fj::Shape::Union(union) => union.shapes().iter().collect(),
+        fj::Shape::Difference3d(difference) => {
+            difference.shapes().iter().collect()
+        }
+        fj::Shape::Intersection(intersection) => {
+            intersection.shapes().iter().collect()
+        }
+        fj::Shape::Fillet(fillet) => vec![fillet.shape()],
+        fj::Shape::Chamfer(chamfer) => vec![chamfer.shape()],
+        fj::Shape::Hollow(hollow) => vec![hollow.shape()],
+        fj::Shape::LinearPattern(pattern) => vec![pattern.shape()],
+        fj::Shape::CircularPattern(pattern) => vec![pattern.shape()],
+        fj::Shape::Mirror(mirror) => vec![mirror.shape()],
+        fj::Shape::Assembly(assembly) => assembly
+            .parts()
+            .iter()
+            .map(fj::AssemblyPart::shape)
+            .collect(),
+        fj::Shape::Shape2d(_)
+        | fj::Shape::Sweep(_)
+        | fj::Shape::PathSweep(_)
+        | fj::Shape::Revolve(_)
+        | fj::Shape::Loft(_)
+        | fj::Shape::Cuboid(_)
+        | fj::Shape::Cylinder(_)
+        | fj::Shape::Cone(_)
+        | fj::Shape::Sphere(_)
+        | fj::Shape::Torus(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shape_processor::Error;
+
+    use super::make_transform;
+
+    #[test]
+    fn non_uniform_scale() {
+        let scale = |shape: fj::Shape| {
+            make_transform(
+                &fj::Transform::from_shape(shape).with_scale([1., 2., 1.]),
+            )
+        };
+
+        assert!(
+            scale(fj::Cylinder::from_radius_and_height(1., 1.).into()).is_ok()
+        );
+        for shape in [
+            fj::Cone::from_radius_and_height(1., 1.).into(),
+            fj::Sphere::from_radius(1.).into(),
+            fj::Torus::from_radii(2., 1.).into(),
+        ] {
+            assert!(matches!(scale(shape), Err(Error::NonUniformScale)));
+        }
+    }
 }
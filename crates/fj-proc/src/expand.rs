@@ -9,19 +9,11 @@ use crate::parse::{
 impl Initializer {
     fn register() -> TokenStream {
         quote! {
-            const _: () = {
-                fj::register_model!(|host| {
-                    fj::models::HostExt::register_model(host, Model);
-
-                    Ok(
-                        fj::models::Metadata::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
-                            .with_short_description(env!("CARGO_PKG_DESCRIPTION"))
-                            .with_homepage(env!("CARGO_PKG_HOMEPAGE"))
-                            .with_repository(env!("CARGO_PKG_REPOSITORY"))
-                            .with_license(env!("CARGO_PKG_LICENSE")),
-                    )
-                });
-            };
+            ::fj::abi::inventory::submit! {
+                ::fj::abi::RegisteredModel::new(|host| {
+                    ::fj::models::HostExt::register_model(host, Model);
+                })
+            }
         }
     }
 }
@@ -44,7 +36,7 @@ impl Model {
         let Self { metadata, geometry } = self;
 
         quote! {
-            impl fj::models::Model for Model {
+            impl ::fj::models::Model for Model {
                 #metadata
                 #geometry
             }
@@ -61,11 +53,20 @@ impl ToTokens for Model {
 
 impl ToTokens for Metadata {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { name, arguments } = self;
+        let Self {
+            name,
+            description,
+            arguments,
+        } = self;
+
+        let description = description.as_ref().map(|description| {
+            quote! { .with_description(#description) }
+        });
 
         tokens.extend(quote! {
-            fn metadata(&self) -> std::result::Result<fj::models::ModelMetadata, Box<dyn std::error::Error + Send + Sync +'static>> {
-                Ok(fj::models::ModelMetadata::new(#name)
+            fn metadata(&self) -> ::core::result::Result<::fj::models::ModelMetadata, ::fj::models::Error> {
+                ::core::result::Result::Ok(::fj::models::ModelMetadata::new(#name)
+                #description
                 #( .with_argument(#arguments) )*)
             }
         });
@@ -76,14 +77,45 @@ impl ToTokens for ArgumentMetadata {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self {
             name,
+            description,
             default_value,
+            ty,
+            min_value,
+            max_value,
+            step,
         } = self;
 
-        tokens.extend(quote! { fj::models::ArgumentMetadata::new(#name) });
+        tokens.extend(quote! {
+            ::fj::models::ArgumentMetadata::new(#name)
+                .with_kind({
+                    use ::fj::models::param_fallback::{ViaFromStr as _, ViaParam as _};
+                    (&&::fj::models::param_fallback::Wrap::<#ty>::new()).kind()
+                })
+        });
 
+        if let Some(description) = description {
+            tokens.extend(quote! {
+                .with_description(#description)
+            });
+        }
         if let Some(default_value) = default_value {
             tokens.extend(quote! {
-                .with_default_value(stringify!(#default_value))
+                .with_default_value(::core::stringify!(#default_value))
+            });
+        }
+        if let Some(min_value) = min_value {
+            tokens.extend(quote! {
+                .with_min_value(::core::stringify!(#min_value))
+            });
+        }
+        if let Some(max_value) = max_value {
+            tokens.extend(quote! {
+                .with_max_value(::core::stringify!(#max_value))
+            });
+        }
+        if let Some(step) = step {
+            tokens.extend(quote! {
+                .with_step(::core::stringify!(#step))
             });
         }
     }
@@ -104,16 +136,24 @@ impl ToTokens for GeometryFunction {
             #geometry_function(#( #argument_names ),*)
         };
         let invocation = if *fallible {
-            quote! { #invocation.map(fj::Shape::from).map_err(Into::into) }
+            quote! {
+                #invocation
+                    .map(::fj::Shape::from)
+                    .map_err(::core::convert::Into::into)
+            }
         } else {
-            quote! { Ok(#invocation.into()) }
+            quote! {
+                ::core::result::Result::Ok(
+                    ::core::convert::Into::into(#invocation)
+                )
+            }
         };
 
         tokens.extend(quote! {
             fn shape(
                 &self,
-                ctx: &dyn fj::models::Context,
-            ) -> Result<fj::Shape, fj::models::Error> {
+                ctx: &dyn ::fj::models::Context,
+            ) -> ::core::result::Result<::fj::Shape, ::fj::models::Error> {
                 #( #arguments )*
                 #( #constraints )*
                 #invocation
@@ -131,19 +171,32 @@ impl ToTokens for ExtractedArgument {
         } = self;
 
         let name = ident.to_string();
+        let parse_error = format!("Invalid value for {name}: {{}}");
+        let parse = quote! {
+            {
+                use ::fj::models::param_fallback::{ViaFromStr as _, ViaParam as _};
+                (&&::fj::models::param_fallback::Wrap::<#ty>::new())
+                    .parse_argument(value)
+                    .map_err(|err| ::std::format!(#parse_error, err))?
+            }
+        };
         let t = match default_value {
             Some(default) => quote! {
                 let #ident: #ty = match ctx.get_argument(#name) {
-                    Some(value) => value.parse()?,
-                    None => #default
+                    ::core::option::Option::Some(value) => #parse,
+                    ::core::option::Option::None => #default
                 };
             },
             None => {
                 let error_message = format!("Expected {name}");
                 quote! {
                     let #ident: #ty = match ctx.get_argument(#name) {
-                        Some(value) => value.parse()?,
-                        None => return Err(#error_message.into()),
+                        ::core::option::Option::Some(value) => #parse,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(
+                                ::core::convert::Into::into(#error_message),
+                            );
+                        }
                     };
                 }
             }
@@ -165,20 +218,22 @@ impl ToTokens for Constraint {
         // Note: this will cause `expr` to be evaluated twice. Predicates should
         // be pure functions, so in theory this shouldn't be an issue.
         let error_message = quote! {
-            format!(
+            ::std::format!(
                 "Expected {} {} {} (i.e. {} {} {})",
-                stringify!(#target),
-                stringify!(#operator),
-                stringify!(#expr),
+                ::core::stringify!(#target),
+                ::core::stringify!(#operator),
+                ::core::stringify!(#expr),
                 #target,
-                stringify!(#operator),
+                ::core::stringify!(#operator),
                 #expr,
             )
         };
 
         tokens.extend(quote! {
             if !(#predicate) {
-                return Err(#error_message.into());
+                return ::core::result::Result::Err(
+                    ::core::convert::Into::into(#error_message),
+                );
             }
         });
     }
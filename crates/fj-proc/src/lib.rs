@@ -1,8 +1,9 @@
 mod expand;
+mod param;
 mod parse;
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, FnArg, ItemFn};
+use syn::{parse_macro_input, DeriveInput, FnArg, ItemFn};
 
 /// Define a function-based model.
 ///
@@ -41,8 +42,11 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// fn model() { todo!() }
 /// ```
 ///
-/// The model function's arguments can be anything that implement
-/// [`std::str::FromStr`].
+/// The model function's arguments can be anything that implements
+/// `fj::models::Param`. This includes numbers, `bool`, `String`, `fj::Angle`,
+/// arrays of `f64`, and enums that use `#[derive(fj::Param)]`. Types that only
+/// implement `FromStr` are accepted too, but the model's metadata can't tell
+/// what kind of value they expect.
 ///
 /// ``` rust ignore
 /// # use fj_proc::model;
@@ -72,6 +76,39 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// }
 /// ```
 ///
+/// The model's metadata describes each argument to the host. This includes the
+/// kind of value it expects, which is derived from its type, as well as its
+/// `min` and `max` bounds. A `step` can be added, to tell editors by how much
+/// an argument should be changed at a time.
+///
+/// ``` rust ignore
+/// #[fj::model]
+/// pub fn model(
+///     #[param(default = 8, min = 3)] num_points: u64,
+///     #[param(default = 1.0, min = 0.1, step = 0.1)] radius: f64,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+///
+/// Doc comments on the model function and on its arguments become their
+/// descriptions in the model's metadata. The description of an argument can
+/// also be set explicitly, using the `description` key of `#[param]`.
+///
+/// ``` rust ignore
+/// /// A round spacer
+/// #[fj::model]
+/// pub fn spacer(
+///     /// The radius of the spacer
+///     #[param(default = 1.0)]
+///     radius: f64,
+///     #[param(default = 1.0, description = "The height of the spacer")]
+///     height: f64,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+///
 /// For more complex situations, model functions are allowed to return any
 /// error type that converts into a model error.
 ///
@@ -85,6 +122,27 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 ///
 /// fn assert_convertible(e: std::env::VarError) -> fj::models::Error { e.into() }
 /// ```
+///
+/// A crate can contain several models. Each of them is registered with the
+/// host, which selects one by the name of its function. Each model needs its
+/// own module, because the macro generates a struct named `Model` next to the
+/// function.
+///
+/// ``` rust ignore
+/// pub mod washer {
+///     #[fj::model]
+///     pub fn washer(#[param(default = 1.0)] radius: f64) -> fj::Shape {
+///         todo!()
+///     }
+/// }
+///
+/// pub mod spacer {
+///     #[fj::model]
+///     pub fn spacer(#[param(default = 1.0)] radius: f64) -> fj::Shape {
+///         todo!()
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn model(_: TokenStream, input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as syn::ItemFn);
@@ -104,14 +162,45 @@ pub fn model(_: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
-/// Strip out any of our `#[param(...)]` attributes so the item will compile.
+/// Implement `fj::models::Param` for an enum, so it can be a model argument.
+///
+/// The enum's variants must not have any fields. A value is parsed from the
+/// name of a variant, and the names of all variants are reported in the model's
+/// metadata.
+///
+/// ``` rust ignore
+/// #[derive(fj::Param)]
+/// pub enum Edge {
+///     Flat,
+///     Rounded,
+/// }
+///
+/// #[fj::model]
+/// pub fn model(#[param(default = Edge::Flat)] edge: Edge) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+#[proc_macro_derive(Param)]
+pub fn derive_param(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match param::derive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Strip out any of our `#[param(...)]` attributes, as well as doc comments on
+/// the arguments, so the item will compile.
 fn without_param_attrs(mut item: ItemFn) -> ItemFn {
     for input in &mut item.sig.inputs {
         let attrs = match input {
             FnArg::Receiver(r) => &mut r.attrs,
             FnArg::Typed(t) => &mut t.attrs,
         };
-        attrs.retain(|attr| !attr.path.is_ident("param"));
+        attrs.retain(|attr| {
+            !attr.path.is_ident("param") && !attr.path.is_ident("doc")
+        });
     }
 
     item
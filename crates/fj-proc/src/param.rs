@@ -0,0 +1,118 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+/// Generate the `fj::models::Param` implementation for an enum
+///
+/// Each variant is parsed from its name, and the names of all variants are
+/// reported as the allowed values.
+pub(crate) fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Param` can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`Param` can only be derived for enums whose variants have no \
+                fields",
+            ));
+        }
+
+        variants.push(&variant.ident);
+    }
+
+    let names: Vec<_> = variants.iter().map(ToString::to_string).collect();
+    let expected = names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let error_message = format!("Expected one of {expected}, but got `{{}}`");
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::fj::models::Param for #ident #ty_generics #where_clause {
+            fn kind() -> ::fj::models::ArgumentKind {
+                ::fj::models::ArgumentKind::Enum {
+                    variants: ::std::vec![
+                        #( ::std::string::ToString::to_string(#names) ),*
+                    ],
+                }
+            }
+
+            fn parse_argument(
+                value: &str,
+            ) -> ::core::result::Result<Self, ::fj::models::Error> {
+                match value.trim() {
+                    #( #names => ::core::result::Result::Ok(Self::#variants), )*
+                    _ => ::core::result::Result::Err(
+                        ::core::convert::Into::into(
+                            ::std::format!(#error_message, value),
+                        ),
+                    ),
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::DeriveInput;
+
+    use super::derive;
+
+    #[test]
+    fn derive_for_enum() {
+        let input: DeriveInput = syn::parse2(quote! {
+            enum Thread {
+                Metric,
+                Imperial,
+            }
+        })
+        .unwrap();
+
+        let tokens = derive(&input).unwrap().to_string();
+
+        assert!(tokens.contains(
+            "\"Metric\" => :: core :: result :: Result :: Ok (Self :: Metric)"
+        ));
+        assert!(tokens.contains(
+            "\"Imperial\" => :: core :: result :: Result :: Ok (Self :: Imperial)"
+        ));
+        assert!(tokens.contains("Expected one of `Metric`, `Imperial`"));
+    }
+
+    #[test]
+    fn reject_variants_with_fields() {
+        let input: DeriveInput = syn::parse2(quote! {
+            enum Edge {
+                Flat,
+                Rounded { radius: f64 },
+            }
+        })
+        .unwrap();
+
+        assert!(derive(&input).is_err());
+    }
+
+    #[test]
+    fn reject_structs() {
+        let input: DeriveInput = syn::parse2(quote! {
+            struct Thread;
+        })
+        .unwrap();
+
+        assert!(derive(&input).is_err());
+    }
+}
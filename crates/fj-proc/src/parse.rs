@@ -1,10 +1,11 @@
 use proc_macro2::Ident;
+use quote::ToTokens;
 use syn::{
-    bracketed, parenthesized, parse::Parse, parse_quote, Expr, ItemFn,
-    ReturnType, Type,
+    bracketed, parenthesized, parse::Parse, Attribute, Expr, ExprLit, ItemFn,
+    Lit, Meta, MetaNameValue, ReturnType, Type,
 };
 
-/// The call to `fj::register_model!()`.
+/// The registration of the model with the host.
 #[derive(Debug)]
 pub(crate) struct Initializer {
     pub(crate) model: Model,
@@ -21,6 +22,7 @@ pub(crate) struct Model {
 #[derive(Debug)]
 pub(crate) struct Metadata {
     pub(crate) name: String,
+    pub(crate) description: Option<String>,
     pub(crate) arguments: Vec<ArgumentMetadata>,
 }
 
@@ -28,7 +30,12 @@ pub(crate) struct Metadata {
 #[derive(Debug)]
 pub(crate) struct ArgumentMetadata {
     pub(crate) name: String,
+    pub(crate) description: Option<String>,
     pub(crate) default_value: Option<Expr>,
+    pub(crate) ty: Type,
+    pub(crate) min_value: Option<Expr>,
+    pub(crate) max_value: Option<Expr>,
+    pub(crate) step: Option<Expr>,
 }
 
 /// The `<_ as fj::Model>::shape()` function.
@@ -69,22 +76,30 @@ pub(crate) fn parse(f: &ItemFn) -> syn::Result<Initializer> {
 fn parse_model(item: &ItemFn) -> syn::Result<Model> {
     let geometry_function = item.sig.ident.clone();
 
-    let args: Vec<Argument> = item
+    let args = item
         .sig
         .inputs
         .iter()
-        .map(|inp| parse_quote!(#inp))
-        .collect();
+        .map(|inp| syn::parse2(inp.to_token_stream()))
+        .collect::<syn::Result<Vec<Argument>>>()?;
 
     let metadata = Metadata {
         name: geometry_function.to_string(),
+        description: doc_comment(&item.attrs),
         arguments: args
             .iter()
-            .map(|a| ArgumentMetadata {
-                name: a.ident.to_string(),
-                default_value: a.default(),
+            .map(|a| {
+                Ok(ArgumentMetadata {
+                    name: a.ident.to_string(),
+                    description: a.description()?,
+                    default_value: a.default(),
+                    ty: a.ty.clone(),
+                    min_value: a.minimum(),
+                    max_value: a.maximum(),
+                    step: a.step(),
+                })
             })
-            .collect(),
+            .collect::<syn::Result<_>>()?,
     };
 
     let geometry = GeometryFunction {
@@ -114,9 +129,35 @@ fn contains_result(ty: &Type) -> bool {
     }
 }
 
+/// Collect the text of all doc comments among the given attributes
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(line),
+                ..
+            })) => Some(line.value()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Doc comments are usually written with a space after the `///`, which
+    // ends up in the text.
+    let text = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
 fn argument_constraints(arg: &Argument) -> Vec<Constraint> {
     let Some(attr) = arg.attr.as_ref() else {
-        return Vec::new()
+        return Vec::new();
     };
 
     let mut constraints = Vec::new();
@@ -151,32 +192,85 @@ fn argument_constraints(arg: &Argument) -> Vec<Constraint> {
 #[derive(Debug, Clone)]
 struct Argument {
     attr: Option<HelperAttribute>,
+    doc: Option<String>,
     ident: Ident,
     ty: Type,
 }
 
 impl Argument {
     fn default(&self) -> Option<Expr> {
-        self.attr
-            .as_ref()
-            .and_then(|attr| attr.get_default())
-            .map(|param| param.val)
+        self.attribute_value(HelperAttribute::get_default)
+    }
+
+    /// The argument's description
+    ///
+    /// An explicit `description` in the `#[param]` attribute takes precedence
+    /// over the argument's doc comment.
+    fn description(&self) -> syn::Result<Option<String>> {
+        match self.attribute_value(HelperAttribute::get_description) {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(description),
+                ..
+            })) => Ok(Some(description.value())),
+            Some(expr) => Err(syn::Error::new_spanned(
+                expr,
+                "Expected the description to be a string literal",
+            )),
+            None => Ok(self.doc.clone()),
+        }
+    }
+
+    fn minimum(&self) -> Option<Expr> {
+        self.attribute_value(HelperAttribute::get_minimum)
+    }
+
+    fn maximum(&self) -> Option<Expr> {
+        self.attribute_value(HelperAttribute::get_maximum)
+    }
+
+    fn step(&self) -> Option<Expr> {
+        self.attribute_value(HelperAttribute::get_step)
+    }
+
+    fn attribute_value(
+        &self,
+        get: impl FnOnce(&HelperAttribute) -> Option<DefaultParam>,
+    ) -> Option<Expr> {
+        self.attr.as_ref().and_then(get).map(|param| param.val)
     }
 }
 
 impl Parse for Argument {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let attr = if input.peek(syn::token::Pound) {
-            Some(input.parse()?)
-        } else {
-            None
-        };
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        let doc = doc_comment(&attrs);
+        let mut attr = None;
+        for attribute in attrs {
+            if attribute.path.is_ident("doc") {
+                continue;
+            }
+            if attr.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "Only one \"param\" attribute is allowed per argument",
+                ));
+            }
+
+            attr = Some(syn::parse2(attribute.to_token_stream())?);
+        }
+
         let ident: Ident = input.parse()?;
 
         let _: syn::token::Colon = input.parse()?;
 
         let ty: Type = input.parse()?;
-        Ok(Self { attr, ident, ty })
+        Ok(Self {
+            attr,
+            doc,
+            ident,
+            ty,
+        })
     }
 }
 
@@ -236,6 +330,10 @@ impl HelperAttribute {
         }
     }
 
+    fn get_description(&self) -> Option<DefaultParam> {
+        self.get_parameter("description")
+    }
+
     fn get_default(&self) -> Option<DefaultParam> {
         self.get_parameter("default")
     }
@@ -247,6 +345,10 @@ impl HelperAttribute {
     fn get_maximum(&self) -> Option<DefaultParam> {
         self.get_parameter("max")
     }
+
+    fn get_step(&self) -> Option<DefaultParam> {
+        self.get_parameter("step")
+    }
 }
 
 /// Represents one argument given to the `#[param]` attribute eg:
@@ -307,7 +409,12 @@ mod tests {
         // Note: we can't #[derive(PartialEq)] on our parsed structs because
         // proc_macro2::Ident and friends don't implement PartialEq, so let's
         // manually check everything parsed correctly.
-        let Metadata { name, arguments } = metadata;
+        let Metadata {
+            name,
+            description,
+            arguments,
+        } = metadata;
+        assert_eq!(description, None);
         assert_eq!(name, "spacer");
         let expected_meta = &[
             ("outer".to_string(), Some("1.0".to_string())),
@@ -372,6 +479,109 @@ mod tests {
         assert_eq!(constraints, expected_constraints);
     }
 
+    #[test]
+    fn parse_argument_metadata() {
+        let tokens = quote! {
+            pub fn model(
+                #[param(default = 3, min = 1, max = 10)] num_points: u64,
+                #[param(default = 1.0, min = 0.5, step = 0.1)] radius: f64,
+                centered: bool,
+                angle: fj::Angle,
+                offset: [f64; 3],
+                name: String,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function).unwrap();
+
+        let to_string = |expr: &Option<Expr>| {
+            expr.as_ref().map(|expr| expr.to_token_stream().to_string())
+        };
+        let arguments: Vec<_> = init
+            .model
+            .metadata
+            .arguments
+            .iter()
+            .map(|arg| {
+                (
+                    arg.name.as_str(),
+                    arg.ty.to_token_stream().to_string(),
+                    to_string(&arg.min_value),
+                    to_string(&arg.max_value),
+                    to_string(&arg.step),
+                )
+            })
+            .collect();
+
+        let ty = |ty: &str| ty.to_string();
+        let expr = |expr: &str| Some(expr.to_string());
+        assert_eq!(
+            arguments,
+            [
+                ("num_points", ty("u64"), expr("1"), expr("10"), None),
+                ("radius", ty("f64"), expr("0.5"), None, expr("0.1")),
+                ("centered", ty("bool"), None, None, None),
+                ("angle", ty("fj :: Angle"), None, None, None),
+                ("offset", ty("[f64 ; 3]"), None, None, None),
+                ("name", ty("String"), None, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_descriptions() {
+        let tokens = quote! {
+            /// A spacer
+            ///
+            /// It is round.
+            pub fn spacer(
+                /// The outer radius
+                #[param(default = 1.0)]
+                outer: f64,
+                /// Ignored, in favor of the explicit description
+                #[param(default = 0.5, description = "The inner radius")]
+                inner: f64,
+                height: f64,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let Metadata {
+            description,
+            arguments,
+            ..
+        } = parse(&function).unwrap().model.metadata;
+
+        assert_eq!(description.as_deref(), Some("A spacer\n\nIt is round."));
+        let descriptions: Vec<_> = arguments
+            .iter()
+            .map(|arg| arg.description.as_deref())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [Some("The outer radius"), Some("The inner radius"), None]
+        );
+    }
+
+    #[test]
+    fn description_must_be_a_string_literal() {
+        let tokens = quote! {
+            pub fn spacer(
+                #[param(description = 1.0)] outer: f64,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        assert!(parse(&function).is_err());
+    }
+
     #[test]
     fn parse_fallible_function() {
         let tokens = quote! {
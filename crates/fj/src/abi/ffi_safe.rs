@@ -184,6 +184,30 @@ impl From<String> for Box<str> {
         Self::from(&*s)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::ser::Serialize for String {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Deserialize<'de> for String {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        Ok(std::string::String::deserialize(deserializer)?.into())
+    }
+}
+
 impl PartialEq<str> for String {
     fn eq(&self, other: &str) -> bool {
         **self == *other
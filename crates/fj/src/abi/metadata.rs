@@ -106,6 +106,10 @@ pub struct ArgumentMetadata {
     name: ffi_safe::String,
     description: ffi_safe::Option<ffi_safe::String>,
     default_value: ffi_safe::Option<ffi_safe::String>,
+    kind: ArgumentKind,
+    min_value: ffi_safe::Option<ffi_safe::String>,
+    max_value: ffi_safe::Option<ffi_safe::String>,
+    step: ffi_safe::Option<ffi_safe::String>,
 }
 
 impl From<crate::models::ArgumentMetadata> for ArgumentMetadata {
@@ -114,12 +118,20 @@ impl From<crate::models::ArgumentMetadata> for ArgumentMetadata {
             name,
             description,
             default_value,
+            kind,
+            min_value,
+            max_value,
+            step,
         } = meta;
 
         Self {
             name: name.into(),
             description: description.into(),
             default_value: default_value.into(),
+            kind: kind.into(),
+            min_value: min_value.into(),
+            max_value: max_value.into(),
+            step: step.into(),
         }
     }
 }
@@ -130,12 +142,70 @@ impl From<ArgumentMetadata> for crate::models::ArgumentMetadata {
             name,
             description,
             default_value,
+            kind,
+            min_value,
+            max_value,
+            step,
         } = meta;
 
         Self {
             name: name.into(),
             description: description.map(Into::into).into(),
             default_value: default_value.map(Into::into).into(),
+            kind: kind.into(),
+            min_value: min_value.map(Into::into).into(),
+            max_value: max_value.map(Into::into).into(),
+            step: step.map(Into::into).into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub(crate) enum ArgumentKind {
+    Integer,
+    Float,
+    Bool,
+    Enum {
+        variants: ffi_safe::Vec<ffi_safe::String>,
+    },
+    Angle,
+    Vector {
+        dimensions: usize,
+    },
+    Other,
+}
+
+impl From<crate::models::ArgumentKind> for ArgumentKind {
+    fn from(kind: crate::models::ArgumentKind) -> Self {
+        use crate::models::ArgumentKind as Kind;
+
+        match kind {
+            Kind::Integer => Self::Integer,
+            Kind::Float => Self::Float,
+            Kind::Bool => Self::Bool,
+            Kind::Enum { variants } => Self::Enum {
+                variants: variants.into_iter().map(Into::into).collect(),
+            },
+            Kind::Angle => Self::Angle,
+            Kind::Vector { dimensions } => Self::Vector { dimensions },
+            Kind::Other => Self::Other,
+        }
+    }
+}
+
+impl From<ArgumentKind> for crate::models::ArgumentKind {
+    fn from(kind: ArgumentKind) -> Self {
+        match kind {
+            ArgumentKind::Integer => Self::Integer,
+            ArgumentKind::Float => Self::Float,
+            ArgumentKind::Bool => Self::Bool,
+            ArgumentKind::Enum { variants } => Self::Enum {
+                variants: variants.iter().cloned().map(Into::into).collect(),
+            },
+            ArgumentKind::Angle => Self::Angle,
+            ArgumentKind::Vector { dimensions } => Self::Vector { dimensions },
+            ArgumentKind::Other => Self::Other,
         }
     }
 }
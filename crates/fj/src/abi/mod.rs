@@ -42,6 +42,7 @@ pub mod ffi_safe;
 mod host;
 mod metadata;
 mod model;
+mod registry;
 
 use std::any::Any;
 
@@ -50,8 +51,14 @@ pub use self::{
     host::Host,
     metadata::{Metadata, ModelMetadata},
     model::Model,
+    registry::{
+        fj_register_models, RegisterModelsFunction, RegisteredModel,
+        REGISTER_MODELS_FUNCTION_NAME,
+    },
 };
 
+pub use inventory;
+
 /// Define the initialization routine used when registering models.
 ///
 /// See the [`crate::model`] macro if your model can be implemented as a pure
@@ -88,14 +95,18 @@ macro_rules! register_model {
         ) -> $crate::abi::InitResult {
             let init: fn(
                 &mut dyn $crate::models::Host,
-            ) -> Result<
+            ) -> ::core::result::Result<
                 $crate::models::Metadata,
                 $crate::models::Error,
             > = $init;
 
             match init(&mut *host) {
-                Ok(meta) => $crate::abi::InitResult::Ok(meta.into()),
-                Err(e) => $crate::abi::InitResult::Err(e.into()),
+                ::core::result::Result::Ok(meta) => {
+                    $crate::abi::InitResult::Ok(meta.into())
+                }
+                ::core::result::Result::Err(e) => {
+                    $crate::abi::InitResult::Err(e.into())
+                }
             }
         }
     };
@@ -0,0 +1,77 @@
+use super::Host;
+
+/// A model that was defined using [`crate::model`]
+///
+/// Each model submits one of these to a registry that is collected when the
+/// model's library is loaded. That way, a crate can define any number of
+/// models, without having to register them manually.
+pub struct RegisteredModel {
+    register: fn(&mut dyn crate::models::Host),
+}
+
+impl RegisteredModel {
+    /// Create a `RegisteredModel` from the function that registers the model
+    pub const fn new(register: fn(&mut dyn crate::models::Host)) -> Self {
+        Self { register }
+    }
+}
+
+inventory::collect!(RegisteredModel);
+
+/// Register all models that were defined using [`crate::model`]
+///
+/// The models of a crate are registered in the order in which the linker
+/// places them, which is usually the order in which they appear in the source
+/// code.
+///
+/// ```rust
+/// mod washer {
+///     #[fj::model]
+///     pub fn washer(#[param(default = 1.0)] radius: f64) -> fj::Shape {
+///         fj::Sketch::from_circle(fj::Circle::from_radius(radius)).into()
+///     }
+/// }
+///
+/// mod spacer {
+///     #[fj::model]
+///     pub fn spacer(#[param(default = 2.0)] radius: f64) -> fj::Shape {
+///         fj::Sketch::from_circle(fj::Circle::from_radius(radius)).into()
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Host(Vec<String>);
+///
+/// impl fj::models::Host for Host {
+///     fn register_boxed_model(&mut self, model: Box<dyn fj::models::Model>) {
+///         self.0.push(model.metadata().unwrap().name);
+///     }
+/// }
+///
+/// let mut host = Host::default();
+/// unsafe {
+///     fj::abi::fj_register_models(&mut fj::abi::Host::from(&mut host));
+/// }
+///
+/// let mut names = host.0;
+/// names.sort();
+/// assert_eq!(names, ["spacer", "washer"]);
+/// ```
+///
+/// # Safety
+///
+/// `host` must point to a valid [`Host`].
+#[no_mangle]
+pub unsafe extern "C" fn fj_register_models(host: *mut Host<'_>) {
+    let host = &mut *host;
+
+    for model in inventory::iter::<RegisteredModel> {
+        (model.register)(host);
+    }
+}
+
+/// The signature of [`fj_register_models`]
+pub type RegisterModelsFunction = unsafe extern "C" fn(*mut Host<'_>);
+
+/// The name of [`fj_register_models`]
+pub const REGISTER_MODELS_FUNCTION_NAME: &str = "fj_register_models";
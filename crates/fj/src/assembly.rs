@@ -0,0 +1,97 @@
+use crate::{abi::ffi_safe, Shape};
+
+/// An assembly of named parts
+///
+/// Like a [`crate::Group`], an assembly is a collection of disjoint shapes.
+/// Unlike a group, it keeps track of which part is which, so each part can end
+/// up as a separate object in an exported file.
+///
+/// # Examples
+///
+/// ``` rust
+/// # let base = fj::Cuboid::from_size([4., 4., 1.]);
+/// # let pin = fj::Cylinder::from_radius_and_height(0.5, 2.);
+/// use fj::syntax::*;
+///
+/// let assembly = fj::Assembly::from_parts(vec![
+///     fj::AssemblyPart::new("base", base),
+///     fj::AssemblyPart::new("pin", fj::Shape::from(pin).translate([0., 0., 1.])),
+/// ]);
+/// ```
+///
+/// # Limitations
+///
+/// Like the shapes in a group, the parts are not allowed to touch or overlap.
+/// This is not currently checked.
+///
+/// The parts are only kept separate, if the assembly is the model's top-level
+/// shape, or if the top-level shape transforms, mirrors, or repeats it in a
+/// pattern. The parts of each instance of a pattern are numbered, like `pin-1`
+/// and `pin-2`. Everywhere else, the assembly is treated like a group. This
+/// includes assemblies that are parts of another assembly.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Assembly {
+    parts: ffi_safe::Vec<AssemblyPart>,
+}
+
+impl Assembly {
+    /// Create an `Assembly` from the given parts
+    pub fn from_parts(parts: Vec<AssemblyPart>) -> Self {
+        Self {
+            parts: parts.into(),
+        }
+    }
+
+    /// Add a part to the assembly
+    pub fn with_part(
+        self,
+        name: impl Into<String>,
+        shape: impl Into<Shape>,
+    ) -> Self {
+        let mut parts = self.parts().to_vec();
+        parts.push(AssemblyPart::new(name, shape));
+        Self::from_parts(parts)
+    }
+
+    /// Access the parts of the assembly
+    pub fn parts(&self) -> &[AssemblyPart] {
+        &self.parts
+    }
+}
+
+impl From<Assembly> for Shape {
+    fn from(shape: Assembly) -> Self {
+        Self::Assembly(shape)
+    }
+}
+
+/// A named part of an [`Assembly`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct AssemblyPart {
+    name: ffi_safe::String,
+    shape: Shape,
+}
+
+impl AssemblyPart {
+    /// Create a part from a name and a shape
+    pub fn new(name: impl Into<String>, shape: impl Into<Shape>) -> Self {
+        Self {
+            name: name.into().into(),
+            shape: shape.into(),
+        }
+    }
+
+    /// Access the name of the part
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Access the shape of the part
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+}
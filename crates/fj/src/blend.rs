@@ -0,0 +1,142 @@
+use crate::{EdgeSelection, Shape};
+
+/// A 3-dimensional shape, with some of its edges rounded off
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]).unwrap();
+/// # let shape = fj::Sweep::from_path(shape.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let rounded = shape.fillet(
+///     fj::EdgeSelection::Parallel {
+///         direction: [0., 0., 1.],
+///     },
+///     0.1,
+/// );
+/// ```
+///
+/// # Limitations
+///
+/// Only convex, straight edges between two planar faces can be rounded off.
+/// Rounded edges must not meet each other, and they must end at faces that are
+/// perpendicular to them. Selecting any other edges makes processing the
+/// model fail with an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Fillet {
+    /// The shape whose edges are rounded off
+    shape: Shape,
+
+    /// The edges that are rounded off
+    edges: EdgeSelection,
+
+    /// The radius of the rounded edges
+    radius: f64,
+}
+
+impl Fillet {
+    /// Create a `Fillet` that rounds off the selected edges of a shape
+    pub fn from_shape(shape: Shape, edges: EdgeSelection, radius: f64) -> Self {
+        Self {
+            shape,
+            edges,
+            radius,
+        }
+    }
+
+    /// Access the shape whose edges are rounded off
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the selection of edges that are rounded off
+    pub fn edges(&self) -> &EdgeSelection {
+        &self.edges
+    }
+
+    /// Access the radius of the rounded edges
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl From<Fillet> for Shape {
+    fn from(shape: Fillet) -> Self {
+        Self::Fillet(Box::new(shape))
+    }
+}
+
+/// A 3-dimensional shape, with some of its edges cut off
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]).unwrap();
+/// # let shape = fj::Sweep::from_path(shape.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let chamfered = shape.chamfer(fj::EdgeSelection::All, 0.1);
+/// ```
+///
+/// # Limitations
+///
+/// Only straight edges between two planar faces can be cut off. Selecting any
+/// other edges makes processing the model fail with an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Chamfer {
+    /// The shape whose edges are cut off
+    shape: Shape,
+
+    /// The edges that are cut off
+    edges: EdgeSelection,
+
+    /// The distance of the cut from the edges, along their faces
+    distance: f64,
+}
+
+impl Chamfer {
+    /// Create a `Chamfer` that cuts off the selected edges of a shape
+    pub fn from_shape(
+        shape: Shape,
+        edges: EdgeSelection,
+        distance: f64,
+    ) -> Self {
+        Self {
+            shape,
+            edges,
+            distance,
+        }
+    }
+
+    /// Access the shape whose edges are cut off
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the selection of edges that are cut off
+    pub fn edges(&self) -> &EdgeSelection {
+        &self.edges
+    }
+
+    /// Access the distance of the cut from the edges, along their faces
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+}
+
+impl From<Chamfer> for Shape {
+    fn from(shape: Chamfer) -> Self {
+        Self::Chamfer(Box::new(shape))
+    }
+}
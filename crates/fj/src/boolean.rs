@@ -0,0 +1,164 @@
+use crate::Shape;
+
+/// The union of two 3-dimensional shapes
+///
+/// Unlike a [`crate::Group`], the shapes may overlap. The result is a single
+/// solid that covers the space of both shapes.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]).unwrap();
+/// # let a = fj::Sweep::from_path(a.into(), [0., 0., 1.]);
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]).unwrap();
+/// # let b = fj::Sweep::from_path(b.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let union = a.union(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved faces, like those created by sweeping a circle, are only supported
+/// where they intersect planes that are either parallel to the circle, or
+/// parallel to the direction of the sweep.
+///
+/// Shapes with ellipses, Bézier curves, or B-splines, and the faces that are
+/// created by sweeping them, are not supported. Neither are the faces of
+/// lofts, revolves, cones, spheres, and tori. Processing the union results
+/// in an error. Non-uniform scaling turns circles into ellipses.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Union {
+    shapes: [Shape; 2],
+}
+
+impl Union {
+    /// Create a `Union` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Union> for Shape {
+    fn from(shape: Union) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
+/// A difference between two 3-dimensional shapes
+///
+/// The second shape is subtracted from the first one.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]).unwrap();
+/// # let a = fj::Sweep::from_path(a.into(), [0., 0., 1.]);
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]).unwrap();
+/// # let b = fj::Sweep::from_path(b.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let difference = a.difference_3d(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved faces, like those created by sweeping a circle, are only supported
+/// where they intersect planes that are either parallel to the circle, or
+/// parallel to the direction of the sweep.
+///
+/// Shapes with ellipses, Bézier curves, or B-splines, and the faces that are
+/// created by sweeping them, are not supported. Neither are the faces of
+/// lofts, revolves, cones, spheres, and tori. Processing the difference results
+/// in an error. Non-uniform scaling turns circles into ellipses.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Difference3d {
+    shapes: [Shape; 2],
+}
+
+impl Difference3d {
+    /// Create a `Difference3d` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the difference
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Difference3d> for Shape {
+    fn from(shape: Difference3d) -> Self {
+        Self::Difference3d(Box::new(shape))
+    }
+}
+
+/// The intersection of two 3-dimensional shapes
+///
+/// The result covers the space that is covered by both shapes.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]).unwrap();
+/// # let a = fj::Sweep::from_path(a.into(), [0., 0., 1.]);
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]).unwrap();
+/// # let b = fj::Sweep::from_path(b.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let intersection = a.intersection(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved faces, like those created by sweeping a circle, are only supported
+/// where they intersect planes that are either parallel to the circle, or
+/// parallel to the direction of the sweep.
+///
+/// Shapes with ellipses, Bézier curves, or B-splines, and the faces that are
+/// created by sweeping them, are not supported. Neither are the faces of
+/// lofts, revolves, cones, spheres, and tori. Processing the intersection results
+/// in an error. Non-uniform scaling turns circles into ellipses.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Intersection {
+    shapes: [Shape; 2],
+}
+
+impl Intersection {
+    /// Create an `Intersection` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Intersection> for Shape {
+    fn from(shape: Intersection) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{FaceSelection, Shape};
+
+/// A 3-dimensional shape, hollowed out to leave walls of a given thickness
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]).unwrap();
+/// # let shape = fj::Sweep::from_path(shape.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let enclosure = shape
+///     .hollow(0.1)
+///     .with_open_faces(fj::FaceSelection::Facing {
+///         normal: [0., 0., 1.],
+///     });
+/// ```
+///
+/// # Limitations
+///
+/// Only prisms can be hollowed out, like shapes created by sweeping a sketch
+/// along a straight path. Their bottom faces must all be at the same height,
+/// as must their top faces. Only bottom and top faces can be left open.
+///
+/// The inside of the walls has sharp corners. Arcs that curve around the
+/// inside of the shape must have a radius larger than the thickness.
+///
+/// Processing the model fails with an error, if the shape doesn't meet these
+/// requirements, or if the walls are too thick to leave any space inside.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hollow {
+    /// The shape that is hollowed out
+    shape: Shape,
+
+    /// The thickness of the walls
+    thickness: f64,
+
+    /// The faces that are left open
+    open_faces: FaceSelection,
+}
+
+impl Hollow {
+    /// Create a `Hollow` with walls of the given thickness
+    ///
+    /// All faces are closed, unless [`Hollow::with_open_faces`] is used.
+    pub fn from_shape(shape: Shape, thickness: f64) -> Self {
+        Self {
+            shape,
+            thickness,
+            open_faces: FaceSelection::None,
+        }
+    }
+
+    /// Leave the selected faces open
+    pub fn with_open_faces(mut self, open_faces: FaceSelection) -> Self {
+        self.open_faces = open_faces;
+        self
+    }
+
+    /// Access the shape that is hollowed out
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the thickness of the walls
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    /// Access the selection of faces that are left open
+    pub fn open_faces(&self) -> &FaceSelection {
+        &self.open_faces
+    }
+}
+
+impl From<Hollow> for Shape {
+    fn from(shape: Hollow) -> Self {
+        Self::Hollow(Box::new(shape))
+    }
+}
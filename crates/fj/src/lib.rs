@@ -23,15 +23,41 @@ pub mod syntax;
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod assembly;
+mod blend;
+mod boolean;
 mod group;
+mod hollow;
+mod loft;
+mod mirror;
 pub mod models;
+mod path_sweep;
+mod pattern;
+mod primitive;
+mod revolve;
+mod selection;
 mod shape_2d;
 mod sweep;
 mod transform;
 pub mod version;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*,
+    assembly::{Assembly, AssemblyPart},
+    blend::{Chamfer, Fillet},
+    boolean::{Difference3d, Intersection, Union},
+    group::Group,
+    hollow::Hollow,
+    loft::{Loft, LoftSection},
+    mirror::Mirror,
+    path_sweep::{PathSegment, PathSegmentRoute, PathSweep},
+    pattern::{CircularPattern, LinearPattern},
+    primitive::{Cone, Cuboid, Cylinder, Sphere, Torus},
+    revolve::Revolve,
+    selection::{EdgeSelection, FaceSelection},
+    shape_2d::*,
+    sweep::Sweep,
+    transform::Transform,
 };
 pub use fj_proc::*;
 
@@ -49,6 +75,60 @@ pub enum Shape {
     /// A sweep of 2-dimensional shape along the z-axis
     Sweep(Sweep),
 
+    /// A sweep of a 2-dimensional shape along a path made of lines and arcs
+    PathSweep(PathSweep),
+
+    /// A revolution of a 2-dimensional shape around an axis
+    Revolve(Revolve),
+
+    /// A loft between 2-dimensional shapes
+    Loft(Loft),
+
     /// A transformed 3-dimensional shape
     Transform(Box<Transform>),
+
+    /// The union of two 3-dimensional shapes
+    Union(Box<Union>),
+
+    /// A difference between two 3-dimensional shapes
+    Difference3d(Box<Difference3d>),
+
+    /// The intersection of two 3-dimensional shapes
+    Intersection(Box<Intersection>),
+
+    /// A 3-dimensional shape with rounded edges
+    Fillet(Box<Fillet>),
+
+    /// A 3-dimensional shape with cut off edges
+    Chamfer(Box<Chamfer>),
+
+    /// A 3-dimensional shape, hollowed out
+    Hollow(Box<Hollow>),
+
+    /// A cuboid
+    Cuboid(Cuboid),
+
+    /// A cylinder
+    Cylinder(Cylinder),
+
+    /// A cone
+    Cone(Cone),
+
+    /// A sphere
+    Sphere(Sphere),
+
+    /// A torus
+    Torus(Torus),
+
+    /// A shape that is repeated along a vector
+    LinearPattern(Box<LinearPattern>),
+
+    /// A shape that is repeated around an axis
+    CircularPattern(Box<CircularPattern>),
+
+    /// A mirrored shape
+    Mirror(Box<Mirror>),
+
+    /// An assembly of named parts
+    Assembly(Assembly),
 }
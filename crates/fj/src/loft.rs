@@ -0,0 +1,82 @@
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A loft between 2-dimensional shapes
+///
+/// Each section is placed parallel to the xy-plane, at its offset along the
+/// z-axis. Neighboring sections are connected by ruled faces.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_circle(fj::Circle::from_radius(1.));
+/// # let other = fj::Sketch::from_circle(fj::Circle::from_radius(0.5));
+/// use fj::syntax::*;
+///
+/// // `shape` and `other` can be anything that converts to `fj::Shape2d`
+/// let frustum = shape.loft(vec![fj::LoftSection::new(other, 2.)]);
+/// ```
+///
+/// # Limitations
+///
+/// All sections must consist of the same number of regions, and matching
+/// regions must have the same number of holes. Regions of sketches are matched
+/// by their order. Matching cycles of the sections are connected by splitting
+/// them at the same fractions of their lengths, starting at their first
+/// vertex. Circles start on their positive x-axis.
+///
+/// Two arcs can only be connected, if they cover the same angles. Otherwise,
+/// processing the loft results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Loft {
+    /// The sections of the loft
+    sections: ffi_safe::Vec<LoftSection>,
+}
+
+impl Loft {
+    /// Create a `Loft` between the given sections
+    ///
+    /// The sections are connected in the order they are provided in. There
+    /// must be at least two of them, and their offsets must increase or
+    /// decrease from one section to the next.
+    pub fn from_sections(sections: Vec<LoftSection>) -> Self {
+        Self {
+            sections: sections.into(),
+        }
+    }
+
+    /// Access the sections of the loft
+    pub fn sections(&self) -> Vec<LoftSection> {
+        self.sections.clone().into()
+    }
+}
+
+impl From<Loft> for Shape {
+    fn from(shape: Loft) -> Self {
+        Self::Loft(shape)
+    }
+}
+
+/// A section of a [`Loft`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct LoftSection {
+    /// The 2-dimensional shape of the section
+    pub shape: Shape2d,
+    /// The offset of the section along the z-axis
+    pub offset: f64,
+}
+
+impl LoftSection {
+    /// Create a section from a shape, placed at the given offset
+    pub fn new(shape: impl Into<Shape2d>, offset: f64) -> Self {
+        Self {
+            shape: shape.into(),
+            offset,
+        }
+    }
+}
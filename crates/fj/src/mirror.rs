@@ -0,0 +1,63 @@
+use crate::Shape;
+
+/// A shape that is mirrored across a plane
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[1., 0.], [2., 0.], [1., 1.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let mirrored = shape.sweep([0., 0., 1.]).mirror([0., 0., 0.], [1., 0., 0.]);
+/// ```
+///
+/// # Limitations
+///
+/// Only the mirrored shape is created. To get a symmetric part, put the shape
+/// and its mirror image into a [`crate::Group`]. Like any shapes in a group,
+/// they are not allowed to touch or overlap.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mirror {
+    shape: Shape,
+    point: [f64; 3],
+    normal: [f64; 3],
+}
+
+impl Mirror {
+    /// Create a `Mirror` from a shape and the mirror plane
+    ///
+    /// The plane is defined by a point on it and its normal.
+    pub fn from_plane(shape: Shape, point: [f64; 3], normal: [f64; 3]) -> Self {
+        Self {
+            shape,
+            point,
+            normal,
+        }
+    }
+
+    /// Access the shape being mirrored
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the point on the mirror plane
+    pub fn point(&self) -> [f64; 3] {
+        self.point
+    }
+
+    /// Access the normal of the mirror plane
+    pub fn normal(&self) -> [f64; 3] {
+        self.normal
+    }
+}
+
+impl From<Mirror> for Shape {
+    fn from(shape: Mirror) -> Self {
+        Self::Mirror(Box::new(shape))
+    }
+}
@@ -186,6 +186,24 @@ pub struct ArgumentMetadata {
 
     /// Something that could be used as a default if no value was provided.
     pub default_value: Option<String>,
+
+    /// The kind of value that this argument expects
+    pub kind: ArgumentKind,
+
+    /// The smallest value that this argument accepts
+    ///
+    /// This is the expression that was used to define the bound. It can refer
+    /// to other arguments of the model.
+    pub min_value: Option<String>,
+
+    /// The largest value that this argument accepts
+    ///
+    /// This is the expression that was used to define the bound. It can refer
+    /// to other arguments of the model.
+    pub max_value: Option<String>,
+
+    /// The increment that an editor could use when changing this argument
+    pub step: Option<String>,
 }
 
 impl ArgumentMetadata {
@@ -201,6 +219,10 @@ impl ArgumentMetadata {
             name,
             description: None,
             default_value: None,
+            kind: ArgumentKind::default(),
+            min_value: None,
+            max_value: None,
+            step: None,
         }
     }
 
@@ -223,6 +245,30 @@ impl ArgumentMetadata {
         self.default_value = Some(default_value.into());
         self
     }
+
+    /// Set the [`ArgumentMetadata::kind`].
+    pub fn with_kind(mut self, kind: ArgumentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::min_value`].
+    pub fn with_min_value(mut self, min_value: impl Into<String>) -> Self {
+        self.min_value = Some(min_value.into());
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::max_value`].
+    pub fn with_max_value(mut self, max_value: impl Into<String>) -> Self {
+        self.max_value = Some(max_value.into());
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::step`].
+    pub fn with_step(mut self, step: impl Into<String>) -> Self {
+        self.step = Some(step.into());
+        self
+    }
 }
 
 impl From<&str> for ArgumentMetadata {
@@ -230,3 +276,95 @@ impl From<&str> for ArgumentMetadata {
         Self::new(name)
     }
 }
+
+/// The kind of value that a model's argument expects
+///
+/// The kind is derived from the type of the argument, and the argument is
+/// parsed accordingly.
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use fj::{
+///     models::{ArgumentKind, Context, Model as _},
+///     syntax::*,
+///     Angle,
+/// };
+///
+/// #[fj::model]
+/// pub fn model(
+///     #[param(default = Angle::from_deg(0.))] angle: Angle,
+///     #[param(default = [0., 0., 0.])] offset: [f64; 3],
+/// ) -> fj::Shape {
+///     shape(angle, offset)
+/// }
+///
+/// fn shape(angle: Angle, offset: [f64; 3]) -> fj::Shape {
+///     fj::Sketch::from_circle(fj::Circle::from_radius(1.))
+///         .sweep([0., 0., 1.])
+///         .rotate([1., 0., 0.], angle)
+///         .translate(offset)
+///         .into()
+/// }
+///
+/// struct Arguments(HashMap<&'static str, &'static str>);
+///
+/// impl Context for Arguments {
+///     fn get_argument(&self, name: &str) -> Option<&str> {
+///         self.0.get(name).copied()
+///     }
+/// }
+///
+/// let metadata = Model.metadata().unwrap();
+/// let kinds = metadata
+///     .arguments
+///     .into_iter()
+///     .map(|argument| argument.kind)
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     kinds,
+///     [ArgumentKind::Angle, ArgumentKind::Vector { dimensions: 3 }],
+/// );
+///
+/// let arguments = Arguments(HashMap::from([
+///     ("angle", "0.25rev"),
+///     ("offset", "[1, 2, 3]"),
+/// ]));
+/// assert_eq!(
+///     Model.shape(&arguments).unwrap(),
+///     shape(Angle::from_rev(0.25), [1., 2., 3.]),
+/// );
+///
+/// let arguments = Arguments(HashMap::from([("offset", "1, 2")]));
+/// assert!(Model.shape(&arguments).is_err());
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub enum ArgumentKind {
+    /// An integer number
+    Integer,
+
+    /// A floating-point number
+    Float,
+
+    /// Either `true` or `false`
+    Bool,
+
+    /// One of a fixed set of named variants
+    Enum {
+        /// The names of the variants that the argument accepts
+        variants: Vec<String>,
+    },
+
+    /// An angle
+    Angle,
+
+    /// A vector of floating-point numbers
+    Vector {
+        /// The number of components of the vector
+        dimensions: usize,
+    },
+
+    /// Any other value that the model parses from a string
+    #[default]
+    Other,
+}
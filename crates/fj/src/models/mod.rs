@@ -4,13 +4,18 @@ mod context;
 mod host;
 mod metadata;
 mod model;
+mod param;
 
 pub use self::{
     context::Context,
     host::{Host, HostExt},
-    metadata::{ArgumentMetadata, Metadata, ModelMetadata},
+    metadata::{ArgumentKind, ArgumentMetadata, Metadata, ModelMetadata},
     model::Model,
+    param::Param,
 };
 
+#[doc(hidden)]
+pub use self::param::fallback as param_fallback;
+
 /// A generic error used when defining a model.
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
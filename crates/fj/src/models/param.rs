@@ -0,0 +1,333 @@
+use crate::{
+    models::{ArgumentKind, Error},
+    Angle,
+};
+
+/// A value that can be passed to a model as an argument
+///
+/// This is implemented for numbers, `bool`, `String`, [`Angle`], and arrays of
+/// `f64` (which are treated as vectors). Enums that only have unit variants can
+/// implement it using `#[derive(fj::Param)]`.
+///
+/// ```rust
+/// use fj::models::{ArgumentKind, Param};
+///
+/// #[derive(fj::Param)]
+/// enum Thread {
+///     Metric,
+///     Imperial,
+/// }
+///
+/// assert!(matches!(Thread::parse_argument("Imperial"), Ok(Thread::Imperial)));
+/// assert!(Thread::parse_argument("imperial").is_err());
+/// assert_eq!(
+///     Thread::kind(),
+///     ArgumentKind::Enum {
+///         variants: vec!["Metric".to_string(), "Imperial".to_string()],
+///     },
+/// );
+/// ```
+///
+/// `#[fj::model]` also accepts arguments whose type only implements
+/// [`FromStr`], as long as its error converts into [`Error`]. Those arguments
+/// are of the kind [`ArgumentKind::Other`].
+///
+/// ```rust
+/// use std::{collections::HashMap, net::Ipv4Addr};
+///
+/// use fj::models::{ArgumentKind, Context, Model as _};
+///
+/// // The generated code doesn't depend on the names that are in scope.
+/// #[allow(dead_code)]
+/// type Result = ();
+///
+/// #[fj::model]
+/// pub fn model(address: Ipv4Addr) -> fj::Shape {
+///     assert_eq!(address, Ipv4Addr::LOCALHOST);
+///     fj::Sketch::from_circle(fj::Circle::from_radius(1.)).into()
+/// }
+///
+/// struct Arguments(HashMap<&'static str, &'static str>);
+///
+/// impl Context for Arguments {
+///     fn get_argument(&self, name: &str) -> Option<&str> {
+///         self.0.get(name).copied()
+///     }
+/// }
+///
+/// let metadata = Model.metadata().unwrap();
+/// assert_eq!(metadata.arguments[0].kind, ArgumentKind::Other);
+///
+/// let arguments = Arguments(HashMap::from([("address", "127.0.0.1")]));
+/// assert!(Model.shape(&arguments).is_ok());
+/// ```
+///
+/// [`FromStr`]: std::str::FromStr
+pub trait Param: Sized {
+    /// The kind of value, as reported in the model's metadata
+    fn kind() -> ArgumentKind;
+
+    /// Parse a value from the text that was passed as an argument
+    fn parse_argument(value: &str) -> Result<Self, Error>;
+}
+
+macro_rules! impl_param_from_str {
+    ($kind:ident: $($ty:ty),*) => {
+        $(
+            impl Param for $ty {
+                fn kind() -> ArgumentKind {
+                    ArgumentKind::$kind
+                }
+
+                fn parse_argument(value: &str) -> Result<Self, Error> {
+                    value.trim().parse().map_err(|err| {
+                        format!(
+                            "Expected {}, but got `{value}` ({err})",
+                            stringify!($ty),
+                        )
+                        .into()
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_param_from_str!(
+    Integer: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_param_from_str!(Float: f32, f64);
+impl_param_from_str!(Bool: bool);
+
+impl Param for String {
+    fn kind() -> ArgumentKind {
+        ArgumentKind::Other
+    }
+
+    fn parse_argument(value: &str) -> Result<Self, Error> {
+        Ok(value.to_string())
+    }
+}
+
+/// Parses a number, followed by an optional unit
+///
+/// The supported units are `deg` (or `°`), `rad`, `rev`, and `gon`. Numbers
+/// without a unit are interpreted as degrees.
+impl Param for Angle {
+    fn kind() -> ArgumentKind {
+        ArgumentKind::Angle
+    }
+
+    fn parse_argument(value: &str) -> Result<Self, Error> {
+        let trimmed = value.trim();
+        let unit = ["deg", "°", "rad", "rev", "gon"]
+            .into_iter()
+            .find(|unit| trimmed.ends_with(unit));
+        let number = match unit {
+            Some(unit) => &trimmed[..trimmed.len() - unit.len()],
+            None => trimmed,
+        };
+        let from_number = match unit {
+            Some("rad") => Self::from_rad,
+            Some("rev") => Self::from_rev,
+            Some("gon") => Self::from_gon,
+            _ => Self::from_deg,
+        };
+
+        let number: f64 = number.trim().parse().map_err(|_| {
+            format!(
+                "Expected an angle (like `45deg`, `0.5rad`, `0.25rev`, or \
+                `50gon`), but got `{value}`"
+            )
+        })?;
+
+        Ok(from_number(number))
+    }
+}
+
+/// Parses a comma-separated list of components, optionally within brackets
+impl<const D: usize> Param for [f64; D] {
+    fn kind() -> ArgumentKind {
+        ArgumentKind::Vector { dimensions: D }
+    }
+
+    fn parse_argument(value: &str) -> Result<Self, Error> {
+        let trimmed = value.trim();
+        let components = trimmed
+            .strip_prefix('[')
+            .and_then(|inner| inner.strip_suffix(']'))
+            .unwrap_or(trimmed);
+
+        let components = components
+            .split(',')
+            .map(|component| component.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                format!(
+                    "Expected {D} comma-separated numbers, but got `{value}` \
+                    ({err})"
+                )
+            })?;
+
+        components.try_into().map_err(|components: Vec<f64>| {
+            format!(
+                "Expected {D} components, but got {} in `{value}`",
+                components.len()
+            )
+            .into()
+        })
+    }
+}
+
+/// Parsing of model arguments, as used by `#[fj::model]`
+///
+/// Model arguments were parsed using [`FromStr`], before [`Param`] existed.
+/// This module keeps argument types working that only implement `FromStr`.
+/// Method resolution prefers [`ViaParam`], which is implemented for a
+/// reference to [`Wrap`], over [`ViaFromStr`], which is implemented for `Wrap`
+/// itself:
+///
+/// ``` rust ignore
+/// use fj::models::param_fallback::{ViaFromStr as _, ViaParam as _, Wrap};
+///
+/// let kind = (&&Wrap::<T>::new()).kind();
+/// ```
+#[doc(hidden)]
+pub mod fallback {
+    use std::{marker::PhantomData, str::FromStr};
+
+    use crate::models::{ArgumentKind, Error};
+
+    use super::Param;
+
+    /// Stands in for the type of a model argument
+    pub struct Wrap<T>(PhantomData<T>);
+
+    impl<T> Wrap<T> {
+        /// Create an instance of `Wrap`
+        pub fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<T> Default for Wrap<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Parse an argument using its [`Param`] implementation
+    pub trait ViaParam {
+        /// The type of the argument
+        type Target;
+
+        /// See [`Param::kind`]
+        fn kind(&self) -> ArgumentKind;
+
+        /// See [`Param::parse_argument`]
+        fn parse_argument(&self, value: &str) -> Result<Self::Target, Error>;
+    }
+
+    impl<T> ViaParam for &Wrap<T>
+    where
+        T: Param,
+    {
+        type Target = T;
+
+        fn kind(&self) -> ArgumentKind {
+            T::kind()
+        }
+
+        fn parse_argument(&self, value: &str) -> Result<T, Error> {
+            T::parse_argument(value)
+        }
+    }
+
+    /// Parse an argument using its [`FromStr`] implementation
+    pub trait ViaFromStr {
+        /// The type of the argument
+        type Target;
+
+        /// Always [`ArgumentKind::Other`]
+        fn kind(&self) -> ArgumentKind;
+
+        /// See [`FromStr::from_str`]
+        fn parse_argument(&self, value: &str) -> Result<Self::Target, Error>;
+    }
+
+    impl<T> ViaFromStr for Wrap<T>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        type Target = T;
+
+        fn kind(&self) -> ArgumentKind {
+            ArgumentKind::Other
+        }
+
+        fn parse_argument(&self, value: &str) -> Result<T, Error> {
+            value.parse().map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{models::ArgumentKind, Angle};
+
+    use super::Param;
+
+    #[test]
+    fn parse_angle() {
+        let degrees = |value| Angle::parse_argument(value).unwrap().deg();
+
+        assert!((degrees("90") - 90.).abs() < 1e-12);
+        assert!((degrees("90deg") - 90.).abs() < 1e-12);
+        assert!((degrees(" 90 ° ") - 90.).abs() < 1e-12);
+        assert!((degrees("0.25rev") - 90.).abs() < 1e-12);
+        assert!((degrees("100gon") - 90.).abs() < 1e-12);
+        assert!(Angle::parse_argument("90 degrees").is_err());
+    }
+
+    #[test]
+    fn parse_vector() {
+        assert_eq!(
+            <[f64; 3]>::parse_argument("1, 2, 3").unwrap(),
+            [1., 2., 3.]
+        );
+        assert_eq!(<[f64; 2]>::parse_argument("[1.5,-2]").unwrap(), [1.5, -2.]);
+        assert!(<[f64; 3]>::parse_argument("1, 2").is_err());
+        assert!(<[f64; 2]>::parse_argument("1, x").is_err());
+        assert_eq!(<[f64; 3]>::kind(), ArgumentKind::Vector { dimensions: 3 });
+    }
+
+    #[test]
+    // The borrows are what selects the implementation.
+    #[allow(clippy::needless_borrow)]
+    fn fallback_to_from_str() {
+        use std::net::Ipv4Addr;
+
+        use super::fallback::{ViaFromStr as _, ViaParam as _, Wrap};
+
+        // `f64` implements both traits, and `Param` takes precedence.
+        assert_eq!((&&Wrap::<f64>::new()).kind(), ArgumentKind::Float);
+        assert!((&&Wrap::<f64>::new()).parse_argument(" 1.5 ").is_ok());
+
+        assert_eq!((&&Wrap::<Ipv4Addr>::new()).kind(), ArgumentKind::Other);
+        assert_eq!(
+            (&&Wrap::<Ipv4Addr>::new())
+                .parse_argument("127.0.0.1")
+                .unwrap(),
+            Ipv4Addr::LOCALHOST
+        );
+        assert!((&&Wrap::<Ipv4Addr>::new()).parse_argument("x").is_err());
+    }
+
+    #[test]
+    fn parse_bool() {
+        assert!(bool::parse_argument("true").unwrap());
+        assert!(!bool::parse_argument("false").unwrap());
+        assert!(bool::parse_argument("yes").is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A sweep of a 2-dimensional shape along a path made of lines and arcs
+///
+/// The path starts at the origin, and each segment starts where the previous
+/// one ends. The shape is carried along the path, which means it is rotated
+/// with the path wherever the path changes direction. Two consecutive lines
+/// are connected by a mitered joint.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let channel = shape.sweep_along_path(vec![
+///     fj::PathSegment::line_to([0., 0., 2.]),
+///     fj::PathSegment::arc_to([1., 0., 3.]),
+///     fj::PathSegment::line_to([3., 0., 3.]),
+/// ]);
+/// ```
+///
+/// # Limitations
+///
+/// The path must not start out parallel to the shape, and it must not turn
+/// back on itself. Its lines must be long enough, and its arcs wide enough, for
+/// the shape to fit through the joints between them. Otherwise, processing the
+/// shape results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct PathSweep {
+    /// The 2-dimensional shape being swept
+    shape: Shape2d,
+
+    /// The segments of the path
+    path: ffi_safe::Vec<PathSegment>,
+}
+
+impl PathSweep {
+    /// Create a `PathSweep` along the given path
+    pub fn from_segments(shape: Shape2d, path: Vec<PathSegment>) -> Self {
+        Self {
+            shape,
+            path: path.into(),
+        }
+    }
+
+    /// Access the shape being swept
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the segments of the path
+    pub fn path(&self) -> Vec<PathSegment> {
+        self.path.clone().into()
+    }
+}
+
+impl From<PathSweep> for Shape {
+    fn from(shape: PathSweep) -> Self {
+        Self::PathSweep(shape)
+    }
+}
+
+/// A segment of the path of a [`PathSweep`]
+///
+/// Each segment starts at the end of the previous segment.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct PathSegment {
+    /// The destination point of the segment
+    pub endpoint: [f64; 3],
+    /// The path taken by the segment to get to the endpoint
+    pub route: PathSegmentRoute,
+}
+
+impl PathSegment {
+    /// Create a straight segment to the given point
+    pub fn line_to(endpoint: [f64; 3]) -> Self {
+        Self {
+            endpoint,
+            route: PathSegmentRoute::Direct,
+        }
+    }
+
+    /// Create an arc to the given point
+    pub fn arc_to(endpoint: [f64; 3]) -> Self {
+        Self {
+            endpoint,
+            route: PathSegmentRoute::Arc,
+        }
+    }
+}
+
+/// Possible paths that a [`PathSegment`] can take to the next endpoint
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum PathSegmentRoute {
+    /// A straight line to the endpoint
+    Direct,
+    /// An arc to the endpoint, that continues the path tangentially
+    ///
+    /// If the arc is the first segment of the path, it starts in the direction
+    /// of the normal of the swept shape.
+    Arc,
+}
@@ -0,0 +1,166 @@
+use crate::{Angle, Shape};
+
+/// A shape that is repeated along a vector
+///
+/// The first instance is the original shape. Each further instance is offset
+/// from the previous one by the same vector.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let fin = fj::Sketch::from_points(vec![[0., 0.], [0.2, 0.], [0.2, 2.], [0., 2.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `fin` can be anything that converts to `fj::Shape`
+/// let fins = fin.sweep([0., 0., 1.]).linear_pattern(8, [0.5, 0., 0.]);
+/// ```
+///
+/// # Limitations
+///
+/// Like the shapes in a [`crate::Group`], the instances are not allowed to
+/// touch or overlap. This is not currently checked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct LinearPattern {
+    shape: Shape,
+    count: u32,
+    offset: [f64; 3],
+}
+
+impl LinearPattern {
+    /// Create a `LinearPattern` with the given number of instances
+    ///
+    /// `offset` is the vector between neighboring instances.
+    pub fn from_offset(shape: Shape, count: u32, offset: [f64; 3]) -> Self {
+        Self {
+            shape,
+            count,
+            offset,
+        }
+    }
+
+    /// Access the shape being repeated
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the number of instances
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Access the vector between neighboring instances
+    pub fn offset(&self) -> [f64; 3] {
+        self.offset
+    }
+}
+
+impl From<LinearPattern> for Shape {
+    fn from(shape: LinearPattern) -> Self {
+        Self::LinearPattern(Box::new(shape))
+    }
+}
+
+/// A shape that is repeated around an axis
+///
+/// The first instance is the original shape. Each further instance is rotated
+/// around the axis from the previous one by the same angle. Positive angles
+/// rotate counter-clockwise, when looking against the direction of the axis.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let hole = fj::Sketch::from_circle(fj::Circle::from_center_and_radius([2., 0.], 0.25));
+/// use fj::syntax::*;
+///
+/// // `hole` can be anything that converts to `fj::Shape`
+/// let bolt_circle = hole
+///     .sweep([0., 0., 1.])
+///     .circular_pattern(6, [0., 0., 0.], [0., 0., 1.]);
+///
+/// // Instances can also be placed on only a part of the circle.
+/// let half_circle = hole
+///     .sweep([0., 0., 1.])
+///     .circular_pattern(3, [0., 0., 0.], [0., 0., 1.])
+///     .with_angle(fj::Angle::from_deg(90.));
+/// ```
+///
+/// # Limitations
+///
+/// Like the shapes in a [`crate::Group`], the instances are not allowed to
+/// touch or overlap. This is not currently checked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct CircularPattern {
+    shape: Shape,
+    count: u32,
+    axis_origin: [f64; 3],
+    axis_direction: [f64; 3],
+    angle: Angle,
+}
+
+impl CircularPattern {
+    /// Create a `CircularPattern` with the given number of instances
+    ///
+    /// The instances are evenly distributed around the full circle. Use
+    /// [`CircularPattern::with_angle`] to change that.
+    pub fn from_axis(
+        shape: Shape,
+        count: u32,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+    ) -> Self {
+        let angle = Angle::from_rev(1. / f64::from(count.max(1)));
+
+        Self {
+            shape,
+            count,
+            axis_origin,
+            axis_direction,
+            angle,
+        }
+    }
+
+    /// Set the angle between neighboring instances
+    pub fn with_angle(mut self, angle: Angle) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Access the shape being repeated
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the number of instances
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Access a point on the axis of the pattern
+    pub fn axis_origin(&self) -> [f64; 3] {
+        self.axis_origin
+    }
+
+    /// Access the direction of the axis of the pattern
+    pub fn axis_direction(&self) -> [f64; 3] {
+        self.axis_direction
+    }
+
+    /// Access the angle between neighboring instances
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+}
+
+impl From<CircularPattern> for Shape {
+    fn from(shape: CircularPattern) -> Self {
+        Self::CircularPattern(Box::new(shape))
+    }
+}
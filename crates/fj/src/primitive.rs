@@ -0,0 +1,275 @@
+use crate::Shape;
+
+/// A cuboid, centered around the origin
+///
+/// # Examples
+///
+/// ``` rust
+/// let cuboid = fj::Cuboid::from_size([3., 2., 1.]).with_color([0, 255, 0, 255]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cuboid {
+    size: [f64; 3],
+    color: [u8; 4],
+}
+
+impl Cuboid {
+    /// Create a cuboid with the given size along the x-, y-, and z-axes
+    pub fn from_size(size: [f64; 3]) -> Self {
+        Self {
+            size,
+            color: DEFAULT_COLOR,
+        }
+    }
+
+    /// Set the rendering color of the cuboid in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the size of the cuboid
+    pub fn size(&self) -> [f64; 3] {
+        self.size
+    }
+
+    /// Access the rendering color of the cuboid in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cuboid> for Shape {
+    fn from(shape: Cuboid) -> Self {
+        Self::Cuboid(shape)
+    }
+}
+
+/// A cylinder
+///
+/// The bottom of the cylinder is centered around the origin of the xy-plane.
+/// The cylinder extends along the positive z-axis.
+///
+/// # Examples
+///
+/// ``` rust
+/// let cylinder = fj::Cylinder::from_radius_and_height(1., 2.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cylinder {
+    radius: f64,
+    height: f64,
+    color: [u8; 4],
+}
+
+impl Cylinder {
+    /// Create a cylinder with the given radius and height
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            height,
+            color: DEFAULT_COLOR,
+        }
+    }
+
+    /// Set the rendering color of the cylinder in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the cylinder
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height of the cylinder
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Access the rendering color of the cylinder in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cylinder> for Shape {
+    fn from(shape: Cylinder) -> Self {
+        Self::Cylinder(shape)
+    }
+}
+
+/// A cone
+///
+/// The base of the cone is centered around the origin of the xy-plane. Its
+/// apex is located on the positive z-axis.
+///
+/// # Examples
+///
+/// ``` rust
+/// let cone = fj::Cone::from_radius_and_height(1., 2.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cone {
+    radius: f64,
+    height: f64,
+    color: [u8; 4],
+}
+
+impl Cone {
+    /// Create a cone with the given radius of its base and height
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            height,
+            color: DEFAULT_COLOR,
+        }
+    }
+
+    /// Set the rendering color of the cone in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the cone's base
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height of the cone
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Access the rendering color of the cone in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cone> for Shape {
+    fn from(shape: Cone) -> Self {
+        Self::Cone(shape)
+    }
+}
+
+/// A sphere, centered around the origin
+///
+/// # Examples
+///
+/// ``` rust
+/// let sphere = fj::Sphere::from_radius(1.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Sphere {
+    radius: f64,
+    color: [u8; 4],
+}
+
+impl Sphere {
+    /// Create a sphere with the given radius
+    pub fn from_radius(radius: f64) -> Self {
+        Self {
+            radius,
+            color: DEFAULT_COLOR,
+        }
+    }
+
+    /// Set the rendering color of the sphere in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the rendering color of the sphere in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Sphere> for Shape {
+    fn from(shape: Sphere) -> Self {
+        Self::Sphere(shape)
+    }
+}
+
+/// A torus, centered around the origin
+///
+/// The torus revolves around the z-axis.
+///
+/// # Examples
+///
+/// ``` rust
+/// // A ring with an outer radius of 3 and an inner radius of 1
+/// let torus = fj::Torus::from_radii(2., 1.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Torus {
+    major_radius: f64,
+    minor_radius: f64,
+    color: [u8; 4],
+}
+
+impl Torus {
+    /// Create a torus from its major and minor radius
+    ///
+    /// The major radius is the distance from the z-axis to the center of the
+    /// torus' tube. The minor radius is the radius of the tube. It must be
+    /// smaller than the major radius.
+    pub fn from_radii(major_radius: f64, minor_radius: f64) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+            color: DEFAULT_COLOR,
+        }
+    }
+
+    /// Set the rendering color of the torus in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the major radius of the torus
+    pub fn major_radius(&self) -> f64 {
+        self.major_radius
+    }
+
+    /// Access the minor radius of the torus
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_radius
+    }
+
+    /// Access the rendering color of the torus in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Torus> for Shape {
+    fn from(shape: Torus) -> Self {
+        Self::Torus(shape)
+    }
+}
+
+/// The color of primitives, unless another color is set
+///
+/// This matches the default color of sketches.
+const DEFAULT_COLOR: [u8; 4] = [255, 0, 0, 255];
@@ -0,0 +1,88 @@
+use crate::{Angle, Shape, Shape2d};
+
+/// A revolution of a 2-dimensional shape around an axis
+///
+/// The axis is defined in the coordinates of the 2-dimensional shape, which is
+/// located in the xy-plane.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[1., 0.], [2., 0.], [1., 1.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let ring = shape.revolve([0., 0.], [0., 1.], fj::Angle::from_rev(1.));
+/// let half_ring = shape.revolve([0., 0.], [0., 1.], fj::Angle::from_deg(180.));
+/// ```
+///
+/// # Limitations
+///
+/// The shape must be located on one side of the axis. It may touch the axis.
+/// Shapes that cross the axis, axes without a direction, and angles of zero
+/// make processing the model fail with an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Revolve {
+    /// The 2-dimensional shape being revolved
+    shape: Shape2d,
+
+    /// A point on the axis of the revolution
+    axis_origin: [f64; 2],
+
+    /// The direction of the axis of the revolution
+    axis_direction: [f64; 2],
+
+    /// The angle of the revolution
+    angle: Angle,
+}
+
+impl Revolve {
+    /// Create a `Revolve` around the given axis
+    ///
+    /// Positive angles revolve the shape counter-clockwise, when looking
+    /// against the direction of the axis. Angles of at least one full
+    /// revolution result in a closed ring.
+    pub fn from_axis(
+        shape: Shape2d,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: Angle,
+    ) -> Self {
+        Self {
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+        }
+    }
+
+    /// Access the shape being revolved
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the point on the axis of the revolution
+    pub fn axis_origin(&self) -> [f64; 2] {
+        self.axis_origin
+    }
+
+    /// Access the direction of the axis of the revolution
+    pub fn axis_direction(&self) -> [f64; 2] {
+        self.axis_direction
+    }
+
+    /// Access the angle of the revolution
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+}
+
+impl From<Revolve> for Shape {
+    fn from(shape: Revolve) -> Self {
+        Self::Revolve(shape)
+    }
+}
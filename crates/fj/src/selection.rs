@@ -0,0 +1,88 @@
+/// A selection of the faces of a shape
+///
+/// Only planar faces are selected.
+///
+/// Faces can be selected by their geometry, or by the operation that created
+/// them. The latter keeps selecting the same faces, if the parameters of a
+/// model change. A face that is selected as the top of a sweep stays the top,
+/// even if the sweep is transformed, for example.
+///
+/// # Examples
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.], [0., 1.]]).unwrap();
+/// # let shape = fj::Sweep::from_path(shape.into(), [0., 0., 1.]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let cup = shape.hollow(0.1).with_open_faces(fj::FaceSelection::SweepTop);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum FaceSelection {
+    /// No faces
+    None,
+
+    /// All faces of the shape
+    All,
+
+    /// The faces that face a direction
+    ///
+    /// Selects the top faces of a shape, for example, if the direction is the
+    /// positive z-axis.
+    Facing {
+        /// The direction of the faces' normals
+        normal: [f64; 3],
+    },
+
+    /// The faces that a sweep created at the end of its path
+    SweepTop,
+
+    /// The faces that a sweep created from the swept sketch
+    SweepBottom,
+
+    /// The faces that a sweep created from the edges of the swept sketch
+    SweepSides,
+}
+
+/// A selection of the edges of a shape
+///
+/// Only edges of planar faces are selected.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum EdgeSelection {
+    /// All edges of the shape
+    All,
+
+    /// The straight edges that are parallel to a direction
+    Parallel {
+        /// The direction the edges are parallel to
+        direction: [f64; 3],
+    },
+
+    /// The edges of the planar faces that face a direction
+    ///
+    /// Selects the edges of the top faces of a shape, for example, if the
+    /// direction is the positive z-axis.
+    OfFaces {
+        /// The direction of the faces' normals
+        normal: [f64; 3],
+    },
+
+    /// The edges of the selected faces
+    OfSelectedFaces {
+        /// The faces whose edges are selected
+        faces: FaceSelection,
+    },
+
+    /// The edges of the holes in the selected faces
+    ///
+    /// Selects the rim of a hole through a sweep, for example, if the faces
+    /// are [`FaceSelection::SweepTop`].
+    InteriorOf {
+        /// The faces whose interior edges are selected
+        faces: FaceSelection,
+    },
+}
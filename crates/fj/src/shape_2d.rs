@@ -1,3 +1,5 @@
+use std::{f64::consts::TAU, fmt};
+
 use crate::{abi::ffi_safe, Angle, Shape};
 
 /// A 2-dimensional shape
@@ -8,8 +10,17 @@ pub enum Shape2d {
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
+    /// An intersection of two shapes
+    Intersection(Box<Intersection2d>),
+
+    /// A shape, grown or shrunk by a distance
+    Offset(Box<Offset2d>),
+
     /// A sketch
     Sketch(Sketch),
+
+    /// A union of two shapes
+    Union(Box<Union2d>),
 }
 
 impl Shape2d {
@@ -18,12 +29,18 @@ impl Shape2d {
         match &self {
             Self::Sketch(s) => s.color(),
             Self::Difference(d) => d.color(),
+            Self::Intersection(i) => i.color(),
+            Self::Offset(o) => o.color(),
+            Self::Union(u) => u.color(),
         }
     }
 }
 
 /// A difference between two shapes
 ///
+/// The second shape is subtracted from the first one. The shapes may overlap
+/// in any way, and the result may consist of multiple faces.
+///
 /// # Examples
 ///
 /// Convenient syntax for this operation is available through [`crate::syntax`].
@@ -36,6 +53,12 @@ impl Shape2d {
 /// // `a` and `b` can be anything that converts to `fj::Shape2d`
 /// let difference = a.difference(&b);
 /// ```
+///
+/// # Limitations
+///
+/// Ellipses, Bézier curves, and B-splines are approximated by straight lines,
+/// where the shapes are combined. This applies to all 2-dimensional boolean
+/// operations.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -72,11 +95,224 @@ impl From<Difference2d> for Shape2d {
     }
 }
 
+/// An intersection of two shapes
+///
+/// The result covers the area that is covered by both shapes.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]).unwrap();
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let intersection = a.intersection_2d(&b);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Intersection2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Intersection2d {
+    /// Create an `Intersection2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Intersection2d> for Shape {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Intersection2d> for Shape2d {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
+
+/// A union of two shapes
+///
+/// The shapes may overlap. Where they do, they are merged into a single face.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]).unwrap();
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let union = a.union_2d(&b);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Union2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Union2d {
+    /// Create a `Union2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Union2d> for Shape {
+    fn from(shape: Union2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Union2d> for Shape2d {
+    fn from(shape: Union2d) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
+/// A shape, grown or shrunk by a distance
+///
+/// Positive distances grow the shape, negative distances shrink it. Holes in
+/// the shape shrink as it grows, and vice versa. Parts of the shape that are
+/// narrower than twice the distance vanish, when it shrinks, as do holes that
+/// are too small, when it grows.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]).unwrap();
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let gasket = shape.offset_2d(0.1).with_join(fj::Join::Miter);
+/// ```
+///
+/// # Limitations
+///
+/// Only shapes whose edges are lines and arcs can be offset. No edge may vanish,
+/// unless the whole cycle it belongs to does. Parts of the shape that grow into
+/// each other are not merged.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    /// The shape that is offset
+    shape: Shape2d,
+
+    /// The distance by which the shape is offset
+    distance: f64,
+
+    /// How the edges of the shape are joined, where they move apart
+    join: Join,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` that offsets a shape by a distance
+    ///
+    /// Edges are joined with arcs, unless [`Offset2d::with_join`] is used.
+    pub fn from_shape(shape: Shape2d, distance: f64) -> Self {
+        Self {
+            shape,
+            distance,
+            join: Join::Round,
+        }
+    }
+
+    /// Join the edges of the shape in the given way
+    pub fn with_join(mut self, join: Join) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the shape that is offset
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the distance by which the shape is offset
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Access the way the edges of the shape are joined
+    pub fn join(&self) -> Join {
+        self.join
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// How the edges of an [`Offset2d`] are joined, where they move apart
+///
+/// Edges move apart at the corners of a shape that grows, and at the corners
+/// of holes in a shape that shrinks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum Join {
+    /// Extend the edges until they meet, keeping the corners sharp
+    Miter,
+
+    /// Connect the edges with arcs, rounding off the corners
+    Round,
+}
+
 /// A sketch
 ///
-/// Sketches are currently limited to a single cycle of straight lines,
-/// represented by a number of points. For example, if the points a, b, and c
-/// are provided, the edges ab, bc, and ca are assumed.
+/// A sketch is made up of regions, each bounded by an exterior chain, and
+/// optionally containing holes, which are bounded by interior chains. Regions
+/// must not overlap each other, and the interior chains of a region must be
+/// within its exterior chain, without overlapping each other.
+///
+/// Chains are either circles, or polygonal chains that are represented by a
+/// number of points. For example, if the points a, b, and c are provided, the
+/// edges ab, bc, and ca are assumed.
 ///
 /// Nothing about these edges is checked right now, but algorithms might assume
 /// that the edges are non-overlapping. If you create a `Sketch` with
@@ -92,11 +328,32 @@ impl From<Difference2d> for Shape2d {
 /// // `a` and `b` can be anything that converts to `fj::Shape`
 /// let sketch = [[0., 0.], [1., 0.], [0., 1.]].sketch();
 /// ```
+///
+/// Sketches with multiple regions and holes are created from
+/// [`SketchRegion`]s:
+///
+/// ``` rust
+/// let square = fj::PolyChain::from_points(vec![
+///     [-1., -1.],
+///     [1., -1.],
+///     [1., 1.],
+///     [-1., 1.],
+/// ]);
+/// let hole = fj::Circle::from_center_and_radius([0., 0.], 0.5);
+///
+/// let washer = fj::SketchRegion::from_exterior(fj::Chain::PolyChain(square))
+///     .with_interiors(vec![fj::Chain::Circle(hole)]);
+/// let pin = fj::SketchRegion::from_exterior(fj::Chain::Circle(
+///     fj::Circle::from_center_and_radius([3., 0.], 0.5),
+/// ));
+///
+/// let sketch = fj::Sketch::from_regions(vec![washer, pin]);
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Sketch {
-    chain: Chain,
+    regions: ffi_safe::Vec<SketchRegion>,
     color: [u8; 4],
 }
 
@@ -107,10 +364,9 @@ impl Sketch {
         if segments.is_empty() {
             None
         } else {
-            Some(Self {
-                chain: Chain::PolyChain(PolyChain::from_segments(segments)),
-                color: [255, 0, 0, 255],
-            })
+            Some(Self::from_chain(Chain::PolyChain(
+                PolyChain::from_segments(segments),
+            )))
         }
     }
 
@@ -119,18 +375,190 @@ impl Sketch {
         if points.is_empty() {
             // TODO Returning an option is just a temporary solution, see: https://github.com/hannobraun/Fornjot/issues/1507
             None
+        } else {
+            Some(Self::from_chain(Chain::PolyChain(PolyChain::from_points(
+                points,
+            ))))
+        }
+    }
+
+    /// Create a sketch from a circle
+    pub fn from_circle(circle: Circle) -> Self {
+        Self::from_chain(Chain::Circle(circle))
+    }
+
+    /// Create a sketch from an ellipse
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// let ellipse = fj::Ellipse::from_radii([2., 1.]);
+    /// let sketch = fj::Sketch::from_ellipse(ellipse);
+    /// ```
+    pub fn from_ellipse(ellipse: Ellipse) -> Self {
+        Self::from_chain(Chain::Ellipse(ellipse))
+    }
+
+    /// Create a sketch of a rectangle, centered around the origin
+    ///
+    /// Returns an error, if the size isn't positive.
+    pub fn from_rectangle(size: [f64; 2]) -> Result<Self, SketchError> {
+        Self::from_rounded_rectangle(size, 0.)
+    }
+
+    /// Create a sketch of a rectangle with rounded corners
+    ///
+    /// The rectangle is centered around the origin.
+    ///
+    /// Returns an error, if the size isn't positive, or if the radius of the
+    /// corners is negative or larger than half the width or height of the
+    /// rectangle.
+    ///
+    /// ``` rust
+    /// assert!(fj::Sketch::from_rounded_rectangle([2., 1.], 0.5).is_ok());
+    /// assert_eq!(
+    ///     fj::Sketch::from_rounded_rectangle([2., 1.], 0.6),
+    ///     Err(fj::SketchError::InvalidCornerRadius),
+    /// );
+    /// ```
+    pub fn from_rounded_rectangle(
+        size: [f64; 2],
+        radius: f64,
+    ) -> Result<Self, SketchError> {
+        let [width, height] = size;
+        if !(width > 0. && height > 0.) {
+            return Err(SketchError::InvalidSize);
+        }
+        if !(radius >= 0. && radius * 2. <= width.min(height)) {
+            return Err(SketchError::InvalidCornerRadius);
+        }
+
+        let [x, y] = [width / 2., height / 2.];
+        let [a, b] = [x - radius, y - radius];
+        let line = SketchSegmentRoute::Direct;
+        let corner = SketchSegmentRoute::Arc {
+            angle: Angle::from_deg(90.),
+        };
+
+        // Where the corners meet, the straight segments are empty, as are the
+        // corners, if their radius is zero.
+        Ok(Self::from_chain(Chain::PolyChain(
+            PolyChain::from_segments(without_empty_segments(vec![
+                SketchSegment::new([a, -y], line.clone()),
+                SketchSegment::new([x, -b], corner.clone()),
+                SketchSegment::new([x, b], line.clone()),
+                SketchSegment::new([a, y], corner.clone()),
+                SketchSegment::new([-a, y], line.clone()),
+                SketchSegment::new([-x, b], corner.clone()),
+                SketchSegment::new([-x, -b], line),
+                SketchSegment::new([-a, -y], corner),
+            ])),
+        )))
+    }
+
+    /// Create a sketch of a stadium, centered around the origin
+    ///
+    /// A stadium is a rectangle, whose shorter sides are replaced by
+    /// semicircles.
+    ///
+    /// Returns an error, if the size isn't positive.
+    pub fn from_stadium(size: [f64; 2]) -> Result<Self, SketchError> {
+        let [width, height] = size;
+        Self::from_rounded_rectangle(size, width.min(height) / 2.)
+    }
+
+    /// Create a sketch of a regular polygon, centered around the origin
+    ///
+    /// The radius is the distance of the polygon's corners from its center.
+    /// The first corner is on the positive x-axis.
+    ///
+    /// Returns an error, if the polygon has less than 3 sides, or if the radius
+    /// isn't positive.
+    pub fn from_regular_polygon(
+        num_sides: u32,
+        radius: f64,
+    ) -> Result<Self, SketchError> {
+        if num_sides < 3 {
+            return Err(SketchError::TooFewSides);
+        }
+        if radius.is_nan() || radius <= 0. {
+            return Err(SketchError::InvalidRadius);
+        }
+
+        let points = (0..num_sides)
+            .map(|i| {
+                let angle = TAU / num_sides as f64 * i as f64;
+                let (sin, cos) = angle.sin_cos();
+                [radius * cos, radius * sin]
+            })
+            .collect();
+
+        Ok(Self::from_chain(Chain::PolyChain(PolyChain::from_points(
+            points,
+        ))))
+    }
+
+    /// Create a sketch of a slot along an arc around the origin
+    ///
+    /// The center line of the slot starts on the positive x-axis, at the given
+    /// radius, and spans the given angle counter-clockwise. The ends of the
+    /// slot are semicircles.
+    ///
+    /// Returns an error, if the width isn't positive or not smaller than the
+    /// diameter of the center line, or if the angle isn't between zero and a
+    /// full turn.
+    pub fn from_slot(
+        radius: f64,
+        angle: Angle,
+        width: f64,
+    ) -> Result<Self, SketchError> {
+        if !(width > 0. && width < radius * 2.) {
+            return Err(SketchError::InvalidSlotWidth);
+        }
+        if !(angle.rad() > 0. && angle.rad() < TAU) {
+            return Err(SketchError::InvalidSlotAngle);
+        }
+
+        let [inner, outer] = [radius - width / 2., radius + width / 2.];
+        let (sin, cos) = angle.rad().sin_cos();
+        let end = SketchSegmentRoute::Arc {
+            angle: Angle::from_deg(180.),
+        };
+
+        Ok(Self::from_chain(Chain::PolyChain(
+            PolyChain::from_segments(vec![
+                SketchSegment::new(
+                    [outer * cos, outer * sin],
+                    SketchSegmentRoute::Arc { angle },
+                ),
+                SketchSegment::new([inner * cos, inner * sin], end.clone()),
+                SketchSegment::new(
+                    [inner, 0.],
+                    SketchSegmentRoute::Arc {
+                        angle: Angle::from_rad(-angle.rad()),
+                    },
+                ),
+                SketchSegment::new([outer, 0.], end),
+            ]),
+        )))
+    }
+
+    /// Create a sketch from any number of regions
+    pub fn from_regions(regions: Vec<SketchRegion>) -> Option<Self> {
+        // TODO Returning an option is just a temporary solution, see: https://github.com/hannobraun/Fornjot/issues/1507
+        if regions.is_empty() {
+            None
         } else {
             Some(Self {
-                chain: Chain::PolyChain(PolyChain::from_points(points)),
+                regions: regions.into(),
                 color: [255, 0, 0, 255],
             })
         }
     }
 
-    /// Create a sketch from a circle
-    pub fn from_circle(circle: Circle) -> Self {
+    fn from_chain(chain: Chain) -> Self {
         Self {
-            chain: Chain::Circle(circle),
+            regions: vec![SketchRegion::from_exterior(chain)].into(),
             color: [255, 0, 0, 255],
         }
     }
@@ -141,9 +569,9 @@ impl Sketch {
         self
     }
 
-    /// Access the chain of the sketch
-    pub fn chain(&self) -> &Chain {
-        &self.chain
+    /// Access the regions of the sketch
+    pub fn regions(&self) -> Vec<SketchRegion> {
+        self.regions.clone().into()
     }
 
     /// Get the rendering color of the sketch in RGBA
@@ -164,6 +592,47 @@ impl From<Sketch> for Shape2d {
     }
 }
 
+/// A region of a [`Sketch`]
+///
+/// The region is bounded by an exterior chain. It may contain holes, which
+/// are bounded by interior chains. The chains may be wound in either
+/// direction.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SketchRegion {
+    exterior: Chain,
+    interiors: ffi_safe::Vec<Chain>,
+}
+
+impl SketchRegion {
+    /// Create a region without holes, bounded by the given chain
+    pub fn from_exterior(exterior: Chain) -> Self {
+        Self {
+            exterior,
+            interiors: ffi_safe::Vec::default(),
+        }
+    }
+
+    /// Add holes to the region, bounded by the given chains
+    pub fn with_interiors(mut self, interiors: Vec<Chain>) -> Self {
+        let mut all_interiors = self.interiors();
+        all_interiors.extend(interiors);
+        self.interiors = all_interiors.into();
+        self
+    }
+
+    /// Access the chain that bounds the region
+    pub fn exterior(&self) -> &Chain {
+        &self.exterior
+    }
+
+    /// Access the chains that bound the holes in the region
+    pub fn interiors(&self) -> Vec<Chain> {
+        self.interiors.clone().into()
+    }
+}
+
 /// A chain of elements that is part of a [`Sketch`]
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -174,6 +643,9 @@ pub enum Chain {
 
     /// The chain is a polygonal chain
     PolyChain(PolyChain),
+
+    /// The chain is an ellipse
+    Ellipse(Ellipse),
 }
 
 /// A circle that is part of a [`Sketch`]
@@ -181,14 +653,27 @@ pub enum Chain {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Circle {
+    /// The center of the circle
+    center: [f64; 2],
+
     /// The radius of the circle
     radius: f64,
 }
 
 impl Circle {
-    /// Construct a new circle with a specific radius
+    /// Construct a new circle with a specific radius, around the origin
     pub fn from_radius(radius: f64) -> Self {
-        Self { radius }
+        Self::from_center_and_radius([0., 0.], radius)
+    }
+
+    /// Construct a new circle with a specific center and radius
+    pub fn from_center_and_radius(center: [f64; 2], radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Access the circle's center
+    pub fn center(&self) -> [f64; 2] {
+        self.center
     }
 
     /// Access the circle's radius
@@ -197,6 +682,45 @@ impl Circle {
     }
 }
 
+/// An ellipse that is part of a [`Sketch`]
+///
+/// The axes of the ellipse are aligned with the x- and y-axis. Use a transform
+/// to rotate it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Ellipse {
+    /// The center of the ellipse
+    center: [f64; 2],
+
+    /// The radii of the ellipse, along the x- and y-axis
+    radii: [f64; 2],
+}
+
+impl Ellipse {
+    /// Construct a new ellipse with specific radii, around the origin
+    pub fn from_radii(radii: [f64; 2]) -> Self {
+        Self::from_center_and_radii([0., 0.], radii)
+    }
+
+    /// Construct a new ellipse with a specific center and radii
+    ///
+    /// The radii are along the x- and y-axis, respectively.
+    pub fn from_center_and_radii(center: [f64; 2], radii: [f64; 2]) -> Self {
+        Self { center, radii }
+    }
+
+    /// Access the ellipse's center
+    pub fn center(&self) -> [f64; 2] {
+        self.center
+    }
+
+    /// Access the ellipse's radii
+    pub fn radii(&self) -> [f64; 2] {
+        self.radii
+    }
+}
+
 /// A polygonal chain that is part of a [`Sketch`]
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -233,7 +757,30 @@ impl PolyChain {
 
 /// A segment of a sketch
 ///
-/// Each segment starts at the previous point of the sketch.
+/// Each segment starts at the previous point of the sketch, and its route
+/// describes the path from there to its endpoint. The first segment starts at
+/// the endpoint of the last one, which closes the sketch.
+///
+/// Sketches made from segments with different routes depend on this. In
+/// earlier versions, the route of a segment was applied to the edge that
+/// starts at its endpoint instead.
+///
+/// ``` rust
+/// use fj::{Angle, SketchSegment, SketchSegmentRoute};
+///
+/// // A semicircle: The arc leads from `[-1., 0.]` to `[1., 0.]`, the straight
+/// // line leads back.
+/// let sketch = fj::Sketch::from_segments(vec![
+///     SketchSegment::new(
+///         [1., 0.],
+///         SketchSegmentRoute::Arc {
+///             angle: Angle::from_deg(180.),
+///         },
+///     ),
+///     SketchSegment::new([-1., 0.], SketchSegmentRoute::Direct),
+/// ])
+/// .unwrap();
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -244,7 +791,75 @@ pub struct SketchSegment {
     pub route: SketchSegmentRoute,
 }
 
-/// Possible paths that a [`SketchSegment`] can take to the next endpoint
+impl SketchSegment {
+    /// Create a segment to the given endpoint, along the given route
+    pub fn new(endpoint: [f64; 2], route: SketchSegmentRoute) -> Self {
+        Self { endpoint, route }
+    }
+}
+
+/// Invalid dimensions passed to one of the constructors of [`Sketch`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SketchError {
+    /// The width or height of a rectangle isn't positive
+    InvalidSize,
+
+    /// The corner radius of a rectangle is negative, or larger than half its
+    /// width or height
+    InvalidCornerRadius,
+
+    /// A regular polygon has less than 3 sides
+    TooFewSides,
+
+    /// The radius of a regular polygon isn't positive
+    InvalidRadius,
+
+    /// The width of a slot isn't positive or not smaller than the diameter of
+    /// its center line
+    InvalidSlotWidth,
+
+    /// The angle of a slot isn't between zero and a full turn
+    InvalidSlotAngle,
+}
+
+impl fmt::Display for SketchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Self::InvalidSize => "Rectangle must have a positive size",
+            Self::InvalidCornerRadius => {
+                "Corner radius is too large for the rectangle"
+            }
+            Self::TooFewSides => "Polygon must have at least 3 sides",
+            Self::InvalidRadius => "Polygon must have a positive radius",
+            Self::InvalidSlotWidth => {
+                "Slot must have a positive width, smaller than its diameter"
+            }
+            Self::InvalidSlotAngle => {
+                "Slot must span an angle between zero and a full turn"
+            }
+        };
+
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for SketchError {}
+
+/// Remove segments that end where the previous one does
+fn without_empty_segments(segments: Vec<SketchSegment>) -> Vec<SketchSegment> {
+    let mut previous = segments.last().map(|segment| segment.endpoint);
+
+    segments
+        .into_iter()
+        .filter(|segment| {
+            let is_empty = previous == Some(segment.endpoint);
+            previous = Some(segment.endpoint);
+            !is_empty
+        })
+        .collect()
+}
+
+/// Possible paths that a [`SketchSegment`] can take to its endpoint
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -256,4 +871,43 @@ pub enum SketchSegmentRoute {
         /// The angle of the arc
         angle: Angle,
     },
+    /// A quadratic Bézier curve to the endpoint
+    QuadraticBezier {
+        /// The control point of the curve
+        control: [f64; 2],
+    },
+    /// A cubic Bézier curve to the endpoint
+    CubicBezier {
+        /// The control points of the curve, in order
+        controls: [[f64; 2]; 2],
+    },
+    /// A B-spline to the endpoint
+    BSpline(BSpline),
+}
+
+/// A cubic B-spline that is part of a [`Sketch`]
+///
+/// The spline is clamped to its ends, and its knots are spaced uniformly. It
+/// starts at the previous point of the sketch and ends at the endpoint of its
+/// segment. It is tangent to the lines from there to the first and last of its
+/// control points.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct BSpline {
+    controls: ffi_safe::Vec<[f64; 2]>,
+}
+
+impl BSpline {
+    /// Construct an instance from the control points between its ends
+    pub fn from_controls(controls: Vec<[f64; 2]>) -> Self {
+        Self {
+            controls: controls.into(),
+        }
+    }
+
+    /// Return the control points between the ends of the spline
+    pub fn controls(&self) -> Vec<[f64; 2]> {
+        self.controls.clone().into()
+    }
 }
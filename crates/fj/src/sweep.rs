@@ -1,4 +1,4 @@
-use crate::{Shape, Shape2d};
+use crate::{Angle, Shape, Shape2d};
 
 /// A sweep of a 2-dimensional shape along straight path
 ///
@@ -12,7 +12,18 @@ use crate::{Shape, Shape2d};
 ///
 /// // `shape` can be anything that converts to `fj::Shape2d`
 /// let group = shape.sweep([0., 0., 1.]);
+///
+/// // The side walls of this one lean inwards
+/// let tapered = shape
+///     .sweep([0., 0., 1.])
+///     .with_draft(fj::Angle::from_deg(10.));
 /// ```
+///
+/// # Limitations
+///
+/// A draft angle can only be applied to sketches whose edges are lines and
+/// arcs. It must be small enough, that none of the edges of the top face
+/// vanish.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -22,12 +33,29 @@ pub struct Sweep {
 
     /// The length and direction of the sweep
     path: [f64; 3],
+
+    /// The draft angle of the side walls
+    #[cfg_attr(feature = "serde", serde(default = "no_draft"))]
+    draft: Angle,
 }
 
 impl Sweep {
     /// Create a `Sweep` along a straight path
     pub fn from_path(shape: Shape2d, path: [f64; 3]) -> Self {
-        Self { shape, path }
+        Self {
+            shape,
+            path,
+            draft: no_draft(),
+        }
+    }
+
+    /// Apply a draft angle to the side walls of the sweep
+    ///
+    /// Positive angles tilt the side walls inwards, so the end of the sweep is
+    /// smaller than its start. Negative angles tilt them outwards.
+    pub fn with_draft(mut self, draft: Angle) -> Self {
+        self.draft = draft;
+        self
     }
 
     /// Access the shape being swept
@@ -39,6 +67,15 @@ impl Sweep {
     pub fn path(&self) -> [f64; 3] {
         self.path
     }
+
+    /// Access the draft angle of the sweep
+    pub fn draft(&self) -> Angle {
+        self.draft
+    }
+}
+
+fn no_draft() -> Angle {
+    Angle::from_rad(0.)
 }
 
 impl From<Sweep> for Shape {
@@ -46,3 +83,27 @@ impl From<Sweep> for Shape {
         Self::Sweep(shape)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::Shape;
+
+    #[test]
+    fn deserialize_without_draft() {
+        let shape =
+            crate::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]])
+                .unwrap();
+        let sweep = super::Sweep::from_path(shape.into(), [0., 0., 1.]);
+
+        let mut json =
+            serde_json::to_value(Shape::from(sweep.clone())).unwrap();
+        json["Sweep"]
+            .as_object_mut()
+            .unwrap()
+            .remove("draft")
+            .unwrap();
+
+        let shape: Shape = serde_json::from_value(json).unwrap();
+        assert_eq!(shape, Shape::from(sweep));
+    }
+}
@@ -3,6 +3,65 @@
 //! This model defines extension traits, which provide convenient syntax for
 //! the various operations defined in this trait.
 
+/// Convenient syntax to create an [`fj::Chamfer`]
+///
+/// [`fj::Chamfer`]: crate::Chamfer
+pub trait Chamfer {
+    /// Cut off the selected edges of `self`
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelection,
+        distance: f64,
+    ) -> crate::Chamfer;
+}
+
+impl<T> Chamfer for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelection,
+        distance: f64,
+    ) -> crate::Chamfer {
+        let shape = self.clone().into();
+        crate::Chamfer::from_shape(shape, edges, distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::CircularPattern`]
+///
+/// [`fj::CircularPattern`]: crate::CircularPattern
+pub trait CircularPattern {
+    /// Repeat `self` around an axis
+    fn circular_pattern(
+        &self,
+        count: u32,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+    ) -> crate::CircularPattern;
+}
+
+impl<T> CircularPattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn circular_pattern(
+        &self,
+        count: u32,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+    ) -> crate::CircularPattern {
+        let shape = self.clone().into();
+        crate::CircularPattern::from_axis(
+            shape,
+            count,
+            axis_origin,
+            axis_direction,
+        )
+    }
+}
+
 /// Convenient syntax to create an [`fj::Difference2d`]
 ///
 /// [`fj::Difference2d`]: crate::Difference2d
@@ -28,6 +87,54 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Difference3d`]
+///
+/// [`fj::Difference3d`]: crate::Difference3d
+pub trait Difference3d {
+    /// Create a difference between `self` and `other`
+    fn difference_3d<Other>(&self, other: &Other) -> crate::Difference3d
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Difference3d for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn difference_3d<Other>(&self, other: &Other) -> crate::Difference3d
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Difference3d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Fillet`]
+///
+/// [`fj::Fillet`]: crate::Fillet
+pub trait Fillet {
+    /// Round off the selected edges of `self`
+    fn fillet(&self, edges: crate::EdgeSelection, radius: f64)
+        -> crate::Fillet;
+}
+
+impl<T> Fillet for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn fillet(
+        &self,
+        edges: crate::EdgeSelection,
+        radius: f64,
+    ) -> crate::Fillet {
+        let shape = self.clone().into();
+        crate::Fillet::from_shape(shape, edges, radius)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Group`]
 ///
 /// [`fj::Group`]: crate::Group
@@ -53,6 +160,221 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Hollow`]
+///
+/// [`fj::Hollow`]: crate::Hollow
+pub trait Hollow {
+    /// Hollow out `self`, leaving walls of the given thickness
+    fn hollow(&self, thickness: f64) -> crate::Hollow;
+}
+
+impl<T> Hollow for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn hollow(&self, thickness: f64) -> crate::Hollow {
+        let shape = self.clone().into();
+        crate::Hollow::from_shape(shape, thickness)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Intersection`]
+///
+/// [`fj::Intersection`]: crate::Intersection
+pub trait Intersection {
+    /// Create an intersection of `self` and `other`
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Intersection for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Intersection2d`]
+///
+/// [`fj::Intersection2d`]: crate::Intersection2d
+pub trait Intersection2d {
+    /// Create an intersection of `self` and `other`
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Intersection2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection2d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::LinearPattern`]
+///
+/// [`fj::LinearPattern`]: crate::LinearPattern
+pub trait LinearPattern {
+    /// Repeat `self` along a vector
+    fn linear_pattern(
+        &self,
+        count: u32,
+        offset: [f64; 3],
+    ) -> crate::LinearPattern;
+}
+
+impl<T> LinearPattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn linear_pattern(
+        &self,
+        count: u32,
+        offset: [f64; 3],
+    ) -> crate::LinearPattern {
+        let shape = self.clone().into();
+        crate::LinearPattern::from_offset(shape, count, offset)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Mirror`]
+///
+/// [`fj::Mirror`]: crate::Mirror
+pub trait Mirror {
+    /// Mirror `self` across the plane defined by a point and a normal
+    fn mirror(&self, point: [f64; 3], normal: [f64; 3]) -> crate::Mirror;
+}
+
+impl<T> Mirror for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn mirror(&self, point: [f64; 3], normal: [f64; 3]) -> crate::Mirror {
+        let shape = self.clone().into();
+        crate::Mirror::from_plane(shape, point, normal)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset2d {
+    /// Grow or shrink `self` by the given distance
+    fn offset_2d(&self, distance: f64) -> crate::Offset2d;
+}
+
+impl<T> Offset2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn offset_2d(&self, distance: f64) -> crate::Offset2d {
+        let shape = self.clone().into();
+        crate::Offset2d::from_shape(shape, distance)
+    }
+}
+
+/// Convenient syntax to create rectangular [`fj::Sketch`]es
+///
+/// [`fj::Sketch`]: crate::Sketch
+pub trait Rectangle {
+    /// Create a rectangle of size `self`, centered around the origin
+    fn rectangle(&self) -> Result<crate::Sketch, crate::SketchError>;
+
+    /// Create a rectangle of size `self` with rounded corners
+    fn rounded_rectangle(
+        &self,
+        radius: f64,
+    ) -> Result<crate::Sketch, crate::SketchError>;
+
+    /// Create a stadium of size `self`
+    fn stadium(&self) -> Result<crate::Sketch, crate::SketchError>;
+}
+
+impl Rectangle for [f64; 2] {
+    fn rectangle(&self) -> Result<crate::Sketch, crate::SketchError> {
+        crate::Sketch::from_rectangle(*self)
+    }
+
+    fn rounded_rectangle(
+        &self,
+        radius: f64,
+    ) -> Result<crate::Sketch, crate::SketchError> {
+        crate::Sketch::from_rounded_rectangle(*self, radius)
+    }
+
+    fn stadium(&self) -> Result<crate::Sketch, crate::SketchError> {
+        crate::Sketch::from_stadium(*self)
+    }
+}
+
+/// Convenient syntax to create a regular polygon as an [`fj::Sketch`]
+///
+/// [`fj::Sketch`]: crate::Sketch
+pub trait RegularPolygon {
+    /// Create a regular polygon, whose corners are `self` from its center
+    fn regular_polygon(
+        &self,
+        num_sides: u32,
+    ) -> Result<crate::Sketch, crate::SketchError>;
+}
+
+impl RegularPolygon for f64 {
+    fn regular_polygon(
+        &self,
+        num_sides: u32,
+    ) -> Result<crate::Sketch, crate::SketchError> {
+        crate::Sketch::from_regular_polygon(num_sides, *self)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Revolve`]
+///
+/// [`fj::Revolve`]: crate::Revolve
+pub trait Revolve {
+    /// Revolve `self` around an axis
+    ///
+    /// The axis is defined by a point and a direction, in the coordinates of
+    /// `self`.
+    fn revolve(
+        &self,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: crate::Angle,
+    ) -> crate::Revolve;
+}
+
+impl<T> Revolve for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn revolve(
+        &self,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: crate::Angle,
+    ) -> crate::Revolve {
+        let shape = self.clone().into();
+        crate::Revolve::from_axis(shape, axis_origin, axis_direction, angle)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sketch`]
 ///
 /// [`fj::Sketch`]: crate::Sketch
@@ -73,6 +395,28 @@ where
     }
 }
 
+/// Convenient syntax to create a slot along an arc as an [`fj::Sketch`]
+///
+/// [`fj::Sketch`]: crate::Sketch
+pub trait Slot {
+    /// Create a slot, whose center line is a circle of radius `self`
+    fn slot(
+        &self,
+        angle: crate::Angle,
+        width: f64,
+    ) -> Result<crate::Sketch, crate::SketchError>;
+}
+
+impl Slot for f64 {
+    fn slot(
+        &self,
+        angle: crate::Angle,
+        width: f64,
+    ) -> Result<crate::Sketch, crate::SketchError> {
+        crate::Sketch::from_slot(*self, angle, width)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sweep`]
 ///
 /// [`fj::Sweep`]: crate::Sweep
@@ -91,6 +435,49 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::PathSweep`]
+///
+/// [`fj::PathSweep`]: crate::PathSweep
+pub trait SweepAlongPath {
+    /// Sweep `self` along a path made of lines and arcs
+    fn sweep_along_path(
+        &self,
+        path: Vec<crate::PathSegment>,
+    ) -> crate::PathSweep;
+}
+
+impl<T> SweepAlongPath for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn sweep_along_path(
+        &self,
+        path: Vec<crate::PathSegment>,
+    ) -> crate::PathSweep {
+        let shape = self.clone().into();
+        crate::PathSweep::from_segments(shape, path)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Loft`]
+///
+/// [`fj::Loft`]: crate::Loft
+pub trait Loft {
+    /// Loft from `self`, which is placed at the offset `0`, to the sections
+    fn loft(&self, sections: Vec<crate::LoftSection>) -> crate::Loft;
+}
+
+impl<T> Loft for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn loft(&self, sections: Vec<crate::LoftSection>) -> crate::Loft {
+        let first = crate::LoftSection::new(self.clone(), 0.);
+        let sections = [first].into_iter().chain(sections).collect();
+        crate::Loft::from_sections(sections)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Transform`]
 ///
 /// [`fj::Transform`]: crate::Transform
@@ -105,6 +492,17 @@ pub trait Transform {
     ///
     /// Create a translation that translates `shape` by `offset`.
     fn translate(&self, offset: [f64; 3]) -> crate::Transform;
+
+    /// Create a uniform scaling
+    ///
+    /// Create a scaling that scales `shape` by `factor` along all axes.
+    fn scale(&self, factor: f64) -> crate::Transform;
+
+    /// Create a non-uniform scaling
+    ///
+    /// Create a scaling that scales `shape` by a separate factor along each
+    /// axis.
+    fn scale_non_uniform(&self, factors: [f64; 3]) -> crate::Transform;
 }
 
 impl<T> Transform for T
@@ -112,22 +510,69 @@ where
     T: Clone + Into<crate::Shape>,
 {
     fn rotate(&self, axis: [f64; 3], angle: crate::Angle) -> crate::Transform {
-        let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis,
-            angle,
-            offset: [0.; 3],
-        }
+        crate::Transform::from_shape(self.clone().into())
+            .with_rotation(axis, angle)
     }
 
     fn translate(&self, offset: [f64; 3]) -> crate::Transform {
-        let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis: [1., 0., 0.],
-            angle: crate::Angle::from_rad(0.),
-            offset,
-        }
+        crate::Transform::from_shape(self.clone().into()).with_offset(offset)
+    }
+
+    fn scale(&self, factor: f64) -> crate::Transform {
+        self.scale_non_uniform([factor; 3])
+    }
+
+    fn scale_non_uniform(&self, factors: [f64; 3]) -> crate::Transform {
+        crate::Transform::from_shape(self.clone().into()).with_scale(factors)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union`]
+///
+/// [`fj::Union`]: crate::Union
+pub trait Union {
+    /// Create a union of `self` and `other`
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Union for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union2d`]
+///
+/// [`fj::Union2d`]: crate::Union2d
+pub trait Union2d {
+    /// Create a union of `self` and `other`
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Union2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union2d::from_shapes([a, b])
     }
 }
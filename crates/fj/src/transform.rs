@@ -13,12 +13,19 @@ use crate::{Angle, Shape};
 /// // `shape` can be anything that converts to `fj::Shape`
 /// let rotated = shape.rotate([0., 0., 1.], fj::Angle::from_rev(0.5));
 /// let translated = shape.translate([1., 2., 3.]);
+///
+/// // Convert a model from inches to millimeters
+/// let scaled = shape.scale(25.4);
 /// ```
 ///
 /// # Limitations
 ///
-/// Transformations are currently limited to a rotation, followed by a
-/// translation.
+/// Transformations are currently limited to a scaling, followed by a rotation,
+/// followed by a translation.
+///
+/// Scaling by different factors along the axes is not supported for shapes
+/// that are made by revolving, like [`crate::Revolve`], cones, spheres, and
+/// tori. Circles in other shapes turn into ellipses.
 ///
 /// See issue:
 /// <https://github.com/hannobraun/Fornjot/issues/101>
@@ -29,6 +36,13 @@ pub struct Transform {
     /// The shape being transformed
     pub shape: Shape,
 
+    /// The scaling factors along the x-, y-, and z-axes
+    ///
+    /// Defaults to `[1., 1., 1.]` when deserializing, so transforms that were
+    /// serialized before scaling was supported stay valid.
+    #[cfg_attr(feature = "serde", serde(default = "no_scale"))]
+    pub scale: [f64; 3],
+
     /// The axis of the rotation
     pub axis: [f64; 3],
 
@@ -39,8 +53,79 @@ pub struct Transform {
     pub offset: [f64; 3],
 }
 
+impl Transform {
+    /// Create a `Transform` that leaves the shape as it is
+    ///
+    /// Use the `with_` methods to add a scaling, rotation, or translation.
+    ///
+    /// ``` rust
+    /// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]).unwrap();
+    /// let transform = fj::Transform::from_shape(shape.into())
+    ///     .with_scale([2., 2., 1.])
+    ///     .with_rotation([0., 0., 1.], fj::Angle::from_deg(90.))
+    ///     .with_offset([1., 0., 0.]);
+    /// ```
+    pub fn from_shape(shape: Shape) -> Self {
+        Self {
+            shape,
+            scale: no_scale(),
+            axis: [1., 0., 0.],
+            angle: Angle::from_rad(0.),
+            offset: [0.; 3],
+        }
+    }
+
+    /// Set the scaling factors along the x-, y-, and z-axes
+    pub fn with_scale(mut self, scale: [f64; 3]) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the axis and angle of the rotation
+    pub fn with_rotation(mut self, axis: [f64; 3], angle: Angle) -> Self {
+        self.axis = axis;
+        self.angle = angle;
+        self
+    }
+
+    /// Set the offset of the translation
+    pub fn with_offset(mut self, offset: [f64; 3]) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+fn no_scale() -> [f64; 3] {
+    [1.; 3]
+}
+
 impl From<Transform> for Shape {
     fn from(shape: Transform) -> Self {
         Self::Transform(Box::new(shape))
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::Shape;
+
+    #[test]
+    fn deserialize_without_scale() {
+        let shape =
+            crate::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]])
+                .unwrap();
+        let transform = super::Transform::from_shape(shape.into())
+            .with_offset([1., 2., 3.]);
+
+        let mut json =
+            serde_json::to_value(Shape::from(transform.clone())).unwrap();
+        json["Transform"]
+            .as_object_mut()
+            .unwrap()
+            .remove("scale")
+            .unwrap();
+
+        let shape: Shape = serde_json::from_value(json).unwrap();
+        assert_eq!(shape, Shape::from(transform));
+    }
+}
@@ -1,10 +1,17 @@
 use fj::syntax::*;
 
+/// A ring-shaped spacer
 #[fj::model]
 pub fn model(
-    #[param(default = 1.0, min = inner * 1.01)] outer: f64,
-    #[param(default = 0.5, max = outer * 0.99)] inner: f64,
-    #[param(default = 1.0)] height: f64,
+    /// The outer radius of the spacer
+    #[param(default = 1.0, min = inner * 1.01)]
+    outer: f64,
+    /// The radius of the hole in the spacer
+    #[param(default = 0.5, max = outer * 0.99)]
+    inner: f64,
+    /// The height of the spacer
+    #[param(default = 1.0)]
+    height: f64,
 ) -> fj::Shape {
     let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer));
     let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));